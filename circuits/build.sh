@@ -8,6 +8,19 @@ if [ $? -ne 0 ]; then
   exit 1
 fi
 
+# @dev - The validation is optional: it is skipped if cargo is not installed, or if SKIP_PROVER_TOML_VALIDATION is set (i.e. SKIP_PROVER_TOML_VALIDATION=1 sh build.sh)
+if [ -n "$SKIP_PROVER_TOML_VALIDATION" ]; then
+  echo "Skip validating the Prover.toml (SKIP_PROVER_TOML_VALIDATION is set)"
+elif ! command -v cargo > /dev/null 2>&1; then
+  echo "Skip validating the Prover.toml (cargo is not installed)"
+else
+  echo "Validating the Prover.toml against the ABI of the compiled circuit..."
+  cargo run --quiet --manifest-path ../scripts/base-mainnet/calling-functions/rs/Cargo.toml --bin validate_prover_toml -- --circuit ./target/travel_history.json --prover-toml ./Prover.toml
+  if [ $? -ne 0 ]; then
+    exit 1
+  fi
+fi
+
 echo "Generate witness..."
 nargo execute
 
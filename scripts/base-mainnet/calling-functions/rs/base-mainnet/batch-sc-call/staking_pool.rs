@@ -20,6 +20,8 @@ sol! {
     "artifacts/0910/StakingPool.sol/StakingPool.json"
 } 
 
+use travel_history::{chain::BASE_MAINNET, deployment::batch_targets};
+
 use dotenv::dotenv;
 use std::env;
 
@@ -65,18 +67,8 @@ pub async fn batch_call() {
         private_key_5
     ];
 
-    // 3. Fetch an array of StakingPool contract addresses from .env file
-    let _contract_addresses_array = env::var("STAKING_POOL_ON_BASE_MAINNET_LIST").unwrap_or_default();
-    println!("✅ contract_addresses_array: {:?}", _contract_addresses_array);
-
-    let contract_addresses_array: Vec<Address> = _contract_addresses_array
-        .trim_matches(|c| c == '[' || c == ']' || c == ' ')
-        .split(',')
-        .map(|s| s.trim_matches(|c| c == '"' || c == ' '))
-        .filter(|s| !s.is_empty())
-        .map(|s| s.parse().expect("Invalid address format"))
-        .collect();
-
+    // 3. Fetch the target addresses: the labels or tags of the deployment manifest in STAKING_POOL_TARGETS (i.e. "fleet-2025-09"), or the address list in STAKING_POOL_ON_BASE_MAINNET_LIST
+    let contract_addresses_array: Vec<Address> = batch_targets(&BASE_MAINNET, "STAKING_POOL").expect("Invalid target addresses");
     println!("{:?}", contract_addresses_array);
 
     // @dev - for-loop of the 5 private keys + Call the checkpoint() function inside it.
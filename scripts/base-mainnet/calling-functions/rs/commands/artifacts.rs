@@ -0,0 +1,123 @@
+// @dev - Alloy
+use alloy::{
+    network::AnyNetwork, // @dev - icl. AnyNetwork for Base Mainnet / Celo Mainnet
+    primitives::Address,
+    providers::ProviderBuilder,
+};
+
+use travel_history::{
+    artifact_registry::{diff_abis, ArtifactRegistry, DEFAULT_ARTIFACT_REGISTRY_PATH},
+    chain::ChainProfile,
+    deployment::{to_env_name, CONTRACT_SUITE},
+};
+
+use clap::{Parser, Subcommand};
+use dotenv::dotenv;
+use std::path::PathBuf;
+
+/// Inspect the artifact registry (= artifacts/<date tag>, indexed by artifacts/registry.toml): list the artifact versions,
+/// diff the ABIs of two versions of a contract, or pick the artifact of the contract deployed at an address.
+#[derive(Parser, Debug)]
+struct Args {
+    /// The root directory of the artifact versions
+    #[arg(long, default_value = DEFAULT_ARTIFACT_REGISTRY_PATH)]
+    registry: PathBuf,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List the artifact versions and their contracts
+    List,
+
+    /// Report the ABI differences of a contract between two artifact versions (= added / removed functions, events and errors, and changed signatures)
+    Diff {
+        /// The contract (i.e. TravelHistoryManager)
+        #[arg(long)]
+        contract: String,
+
+        /// The date tag of the old version (i.e. 0910)
+        #[arg(long)]
+        from: String,
+
+        /// The date tag of the new version. Defaults to the latest version of the contract.
+        #[arg(long)]
+        to: Option<String>,
+    },
+
+    /// Pick the artifact version of the contract deployed at an address by its `version()` string (and its deployed bytecode)
+    Resolve {
+        /// The chain profile (base-mainnet, celo-mainnet or local-anvil)
+        #[arg(long, default_value = "base-mainnet")]
+        chain: String,
+
+        /// The contract (i.e. TravelHistoryManager)
+        #[arg(long)]
+        contract: String,
+
+        /// The address of the contract. Defaults to the one in the deployment manifest (or .env).
+        #[arg(long)]
+        address: Option<Address>,
+    },
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_Artifacts.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+    let registry = ArtifactRegistry::load(&args.registry)?;
+
+    match args.command {
+        Command::List => {
+            for version in registry.versions.iter() {
+                let contracts: Vec<&str> =
+                    CONTRACT_SUITE.iter().copied().filter(|contract| registry.path_of(&version.tag, contract).exists()).collect();
+                println!(
+                    "✅ {} (version: {}): {}",
+                    version.tag,
+                    version.version.as_deref().unwrap_or("not in the index"),
+                    contracts.join(", ")
+                );
+            }
+        }
+        Command::Diff { contract, from, to } => {
+            let to = match to {
+                Some(to) => to,
+                None => registry
+                    .versions_of(&contract)
+                    .last()
+                    .map(|version| version.tag.clone())
+                    .ok_or_else(|| eyre::eyre!("No artifact of {} in {}", contract, registry.root.display()))?,
+            };
+            let old = registry.artifact(&from, &contract)?;
+            let new = registry.artifact(&to, &contract)?;
+            println!("🔄 ABI differences of {}: {} -> {}", contract, from, to);
+            print!("{}", diff_abis(&old.abi, &new.abi));
+        }
+        Command::Resolve { chain, contract, address } => {
+            let chain = ChainProfile::from_name(&chain)?;
+            let address = match address {
+                Some(address) => address,
+                None => chain.contract_address(&to_env_name(&contract))?,
+            };
+            let provider = ProviderBuilder::new().network::<AnyNetwork>().connect_http(chain.rpc_url()?);
+            let resolved = registry.resolve(&provider, &contract, address).await?;
+            println!(
+                "✅ {} at {:?} on {}: {} (version: {}, bytecode: {})",
+                contract,
+                address,
+                chain,
+                resolved.artifact.path.display(),
+                resolved.version,
+                if resolved.exact_bytecode { "identical" } else { "different (i.e. immutables or another build)" }
+            );
+        }
+    }
+
+    Ok(())
+}
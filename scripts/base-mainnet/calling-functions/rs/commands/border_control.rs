@@ -0,0 +1,73 @@
+// @dev - Alloy
+use alloy::{
+    network::AnyNetwork, // @dev - icl. AnyNetwork for Base Mainnet / Celo Mainnet
+    primitives::Address,
+    providers::ProviderBuilder,
+};
+
+use travel_history::{
+    chain::ChainProfile,
+    contracts::TravelHistoryManager,
+    policy::{StayPolicy, DEFAULT_STAY_POLICY_PATH},
+    proof_store::{ProofStore, DEFAULT_PROOF_STORE_PATH},
+    travel_status::query_travel_status,
+};
+
+use clap::Parser;
+use dotenv::dotenv;
+use std::path::PathBuf;
+
+/// Query the full travel status of a traveler for border control: the registration, the current nullifier,
+/// the timeline of the recorded stays (from the proofs in the local proof store) and the verdict of the stay-policy.
+#[derive(Parser, Debug)]
+struct Args {
+    /// The wallet address of the traveler
+    #[arg(long)]
+    traveler: Address,
+
+    /// The chain profile (base-mainnet, celo-mainnet or local-anvil)
+    #[arg(long, default_value = "base-mainnet")]
+    chain: String,
+
+    /// The directory of the local proof store
+    #[arg(long, default_value = DEFAULT_PROOF_STORE_PATH)]
+    proof_store: PathBuf,
+
+    /// The stay-policy rules
+    #[arg(long, default_value = DEFAULT_STAY_POLICY_PATH)]
+    policy: PathBuf,
+
+    /// The visas that the traveler holds (i.e. --visa US-B1/B2)
+    #[arg(long)]
+    visa: Vec<String>,
+
+    /// Print the travel status as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_BorderControl.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+
+    let chain = ChainProfile::from_name(&args.chain)?;
+    let provider = ProviderBuilder::new().network::<AnyNetwork>().connect_http(chain.rpc_url()?);
+    let travel_history_manager = TravelHistoryManager::new(chain.contract_address("TRAVEL_HISTORY_MANAGER")?, &provider);
+
+    let proof_store = ProofStore::open(&args.proof_store)?;
+    let policy = StayPolicy::load(&args.policy)?;
+
+    let travel_status =
+        query_travel_status(&travel_history_manager, &proof_store, &policy, chain.name, args.traveler, &args.visa).await?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&travel_status)?);
+    } else {
+        println!("{}", travel_status);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+// @dev - Alloy
+use alloy::primitives::U256;
+
+use travel_history::{
+    artifact_registry::{ArtifactRegistry, DEFAULT_ARTIFACT_REGISTRY_PATH},
+    fixtures::{Fixture, FixtureSpec, DEFAULT_FIXTURES_PATH},
+};
+
+use clap::Parser;
+use dotenv::dotenv;
+use std::path::PathBuf;
+
+/// Build a seeded Anvil state fixture: spawn Anvil, deploy the contract suite with the always-true mock verifier,
+/// seed travelers (w/ recorded proofs), property owners (w/ listed rooms), OTAs and stakers,
+/// and dump the state with `anvil_dumpState` into fixtures/<name>.v<format version>.json.
+#[derive(Parser, Debug)]
+struct Args {
+    /// The name of the fixture
+    #[arg(long, default_value = "default")]
+    name: String,
+
+    /// The number of registered travelers
+    #[arg(long, default_value_t = 2)]
+    travelers: usize,
+
+    /// The number of proofs recorded by each traveler
+    #[arg(long, default_value_t = 2)]
+    proofs_per_traveler: usize,
+
+    /// The number of registered property owners
+    #[arg(long, default_value_t = 2)]
+    property_owners: usize,
+
+    /// The number of rooms listed by each property owner
+    #[arg(long, default_value_t = 2)]
+    rooms_per_owner: usize,
+
+    /// The price of each listed room (in wei)
+    #[arg(long, default_value = "10000000000000000")]
+    room_price_wei: U256,
+
+    /// The number of registered OTAs
+    #[arg(long, default_value_t = 1)]
+    otas: usize,
+
+    /// The number of registered stakers
+    #[arg(long, default_value_t = 2)]
+    stakers: usize,
+
+    /// The native-token stake of each staker (in wei)
+    #[arg(long, default_value = "1000000000000000000")]
+    stake_wei: U256,
+
+    /// The seed of the test vectors of the recorded proofs
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+
+    /// The root directory of the artifact versions (= the latest version of each contract is deployed)
+    #[arg(long, default_value = DEFAULT_ARTIFACT_REGISTRY_PATH)]
+    registry: PathBuf,
+
+    /// The directory of the fixture files
+    #[arg(long, default_value = DEFAULT_FIXTURES_PATH)]
+    fixtures: PathBuf,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_BuildFixture.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+
+    let spec = FixtureSpec {
+        travelers: args.travelers,
+        proofs_per_traveler: args.proofs_per_traveler,
+        property_owners: args.property_owners,
+        rooms_per_owner: args.rooms_per_owner,
+        room_price_wei: args.room_price_wei,
+        otas: args.otas,
+        stakers: args.stakers,
+        stake_wei: args.stake_wei,
+        seed: args.seed,
+    };
+    let registry = ArtifactRegistry::load(&args.registry)?;
+
+    println!("🔄 Building the fixture {} ({} development accounts) ...", args.name, spec.accounts());
+    let fixture = Fixture::build(&args.name, &spec, &registry).await?;
+    for deployed in fixture.contracts.iter() {
+        println!("✅ {}: {:?}", deployed.label, deployed.address);
+    }
+    println!("✅ {} travelers, {} recorded proofs", fixture.accounts.travelers.len(), fixture.proofs.len());
+    println!("✅ {} property owners, {} listed rooms", fixture.accounts.property_owners.len(), fixture.rooms.len());
+    println!("✅ {} OTAs, {} stakers", fixture.accounts.otas.len(), fixture.accounts.stakers.len());
+
+    let path = fixture.save(&args.fixtures)?;
+    println!("✅ The fixture ({} bytes of state) has been written into {}", fixture.state.len(), path.display());
+    Ok(())
+}
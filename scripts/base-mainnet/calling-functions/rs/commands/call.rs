@@ -0,0 +1,172 @@
+// @dev - Alloy
+use alloy::{
+    network::AnyNetwork, // @dev - icl. AnyNetwork for Base Mainnet / Celo Mainnet
+    primitives::Address,
+    providers::{Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+};
+
+use travel_history::{
+    artifact::ContractArtifact,
+    artifact_registry::{ArtifactRegistry, DEFAULT_ARTIFACT_REGISTRY_PATH},
+    chain::ChainProfile,
+    checkpoint_run::RUN_SIGNERS,
+    deployment::{DeploymentManifest, DEFAULT_DEPLOYMENTS_PATH},
+    dynamic_call::{parse_value, send, simulate, CallOutcome, DynamicCall},
+};
+
+use clap::Parser;
+use dotenv::dotenv;
+use std::env;
+use std::path::PathBuf;
+
+/// Call any function of an artifact by its name or signature w/ JSON-encoded arguments (= alloy's dyn-abi), and print its decoded return values or its revert reason.
+/// A view function is called w/ eth_call. A state-changing function is simulated w/ eth_call first, and then sent from PRIVATE_KEY_<--signer> (unless `--dry-run`).
+#[derive(Parser, Debug)]
+struct Args {
+    /// The chain profile (base-mainnet, celo-mainnet or local-anvil)
+    #[arg(long, default_value = "base-mainnet")]
+    chain: String,
+
+    /// The contract: its latest artifact of the registry is loaded, and its latest instance of the deployment manifest is called (unless `--address`)
+    #[arg(long, required_unless_present = "artifact")]
+    contract: Option<String>,
+
+    /// The artifact to load instead of the one of `--contract` (i.e. artifacts/0910/StakingPool.sol/StakingPool.json)
+    #[arg(long)]
+    artifact: Option<PathBuf>,
+
+    /// The instance to call: a label of the deployment manifest, or an address
+    #[arg(long, required_unless_present_any = ["contract", "list"])]
+    address: Option<String>,
+
+    /// The function: its name (i.e. listAvailableRooms), or its signature for an overloaded one (i.e. "listAvailableRooms(uint256,uint256)")
+    #[arg(long, required_unless_present = "list")]
+    function: Option<String>,
+
+    /// The JSON-encoded arguments: an array (i.e. '["1", "10000000000000000"]'), or an object keyed by the parameter names
+    #[arg(long, default_value = "[]")]
+    args: String,
+
+    /// The value sent w/ a payable function: wei, or w/ a unit (i.e. 0.01ether)
+    #[arg(long, default_value = "0")]
+    value: String,
+
+    /// The signer of a state-changing call (= PRIVATE_KEY_<signer>, 1..5)
+    #[arg(long, default_value_t = 1)]
+    signer: usize,
+
+    /// The sender of a view call or a `--dry-run` (= `msg.sender` of eth_call). The address of the signer by default, if its key is set.
+    /// A state-changing call which is sent is simulated from the signer, so it must not differ from it.
+    #[arg(long)]
+    from: Option<Address>,
+
+    /// Simulate a state-changing call w/ eth_call only, without sending it
+    #[arg(long)]
+    dry_run: bool,
+
+    /// List the functions of the artifact, and exit
+    #[arg(long)]
+    list: bool,
+
+    /// The root directory of the artifact versions
+    #[arg(long, default_value = DEFAULT_ARTIFACT_REGISTRY_PATH)]
+    registry: PathBuf,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_Call.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+
+    // 1. Load the artifact
+    let artifact = match (args.artifact.as_ref(), args.contract.as_deref()) {
+        (Some(path), _) => ContractArtifact::load(path)?,
+        (None, Some(contract)) => ArtifactRegistry::load(&args.registry)?.latest(contract)?,
+        (None, None) => unreachable!("clap requires --contract or --artifact"),
+    };
+    if args.list {
+        for function in artifact.abi.functions() {
+            println!("{}", function.full_signature());
+        }
+        return Ok(());
+    }
+    println!("✅ artifact: {}", artifact.path.display());
+
+    // 2. Build the call from the JSON arguments
+    let call = DynamicCall::new(&artifact.abi, args.function.as_deref().unwrap_or_default(), &args.args, parse_value(&args.value)?)?;
+    println!("✅ function: {}", call.function.full_signature());
+    println!("✅ calldata: {}", call.calldata()?);
+
+    // 3. Resolve the instance
+    let chain = ChainProfile::from_name(&args.chain)?;
+    let manifest = DeploymentManifest::load(DEFAULT_DEPLOYMENTS_PATH, &chain)?;
+    let address: Address = match (args.address.as_deref(), args.contract.as_deref()) {
+        (Some(instance), _) => match manifest.by_label(instance) {
+            Some(deployed) => deployed.address,
+            None => instance.parse().map_err(|_| eyre::eyre!("{} is neither a label of {} nor an address", instance, manifest.path().display()))?,
+        },
+        (None, Some(contract)) => {
+            manifest.latest(contract).map(|deployed| deployed.address).ok_or_else(|| eyre::eyre!("No {} in {}: set --address", contract, manifest.path().display()))?
+        }
+        (None, None) => unreachable!("clap requires --address w/o --contract"),
+    };
+    println!("✅ {}: {:?}", chain, address);
+
+    if !(1..=RUN_SIGNERS).contains(&args.signer) {
+        return Err(eyre::eyre!("--signer must be between 1 and {}", RUN_SIGNERS));
+    }
+    let private_key = env::var(format!("PRIVATE_KEY_{}", args.signer)).unwrap_or_default();
+    let signer: Option<PrivateKeySigner> = match private_key.trim() {
+        "" => None,
+        key => Some(key.parse().map_err(|e| eyre::eyre!("Invalid PRIVATE_KEY_{}: {}", args.signer, e))?),
+    };
+    // @dev - The simulation of a call which is sent must run from its actual sender (= the signer), or it may pass / revert unlike the transaction
+    if let (Some(from), Some(signer)) = (args.from, signer.as_ref()) {
+        if !call.is_view() && !args.dry_run && from != signer.address() {
+            return Err(eyre::eyre!(
+                "--from ({:?}) differs from the address of PRIVATE_KEY_{} ({:?}), which sends {}: drop --from, or add --dry-run to simulate it only",
+                from,
+                args.signer,
+                signer.address(),
+                call.function.signature()
+            ));
+        }
+    }
+    let from = args.from.or(signer.as_ref().map(|signer| signer.address()));
+
+    // 4. Simulate the call (= a view call is done then)
+    let rpc_url = chain.rpc_url()?;
+    let provider = ProviderBuilder::new().network::<AnyNetwork>().connect_http(rpc_url.clone());
+    let outcome = simulate(&provider, &artifact, address, from, &call).await?;
+    println!("{}", outcome);
+    if call.is_view() || args.dry_run {
+        return match outcome {
+            CallOutcome::Returned(_) => Ok(()),
+            CallOutcome::Reverted(reason) => Err(eyre::eyre!("The call has been reverted: {}", reason)),
+        };
+    }
+    if let CallOutcome::Reverted(reason) = outcome {
+        return Err(eyre::eyre!("The simulation has been reverted, so the transaction is not sent: {}", reason));
+    }
+
+    // 5. Send the state-changing call
+    let signer = signer.ok_or_else(|| eyre::eyre!("Set PRIVATE_KEY_{} in your .env to send {}", args.signer, call.function.signature()))?;
+    println!("🔄 Sending {} from {:?} ...", call.function.signature(), signer.address());
+    let provider = ProviderBuilder::new().with_gas_estimation().network::<AnyNetwork>().wallet(signer).connect_http(rpc_url).erased();
+    let sent = send(&provider, address, &call).await?;
+    println!(
+        "{} tx: {} (block: {:?}, gas used: {})",
+        if sent.success { "✅" } else { "❌" },
+        sent.tx_hash,
+        sent.block_number,
+        sent.gas_used
+    );
+    if !sent.success {
+        return Err(eyre::eyre!("The transaction has been reverted on-chain"));
+    }
+    Ok(())
+}
@@ -0,0 +1,82 @@
+// @dev - Alloy
+use alloy::{
+    network::AnyNetwork, // @dev - icl. AnyNetwork for Base Mainnet / Celo Mainnet
+    providers::ProviderBuilder,
+};
+
+use travel_history::{
+    artifact::ContractArtifact,
+    chain::{ChainProfile, CHAIN_PROFILES},
+    contracts::TravelHistoryManager,
+    vk::{deployed_verifier_fingerprint, VkFingerprint, DEFAULT_VK_PATH},
+};
+
+use clap::Parser;
+use dotenv::dotenv;
+use std::path::PathBuf;
+
+/// Compare the vk fingerprint of a circuit build (= circuits/target/vk) with the vk constants embedded in the deployed HonkVerifier
+/// (= TravelHistoryManager#travelHistoryProofVerifier()) of each chain profile, or in a HonkVerifier artifact.
+#[derive(Parser, Debug)]
+struct Args {
+    /// The vk of the circuit build
+    #[arg(long, default_value = DEFAULT_VK_PATH)]
+    vk: PathBuf,
+
+    /// The chain profiles to check (all the chain profiles, whose TravelHistoryManager is set in .env, by default)
+    #[arg(long)]
+    chain: Vec<String>,
+
+    /// Compare with a HonkVerifier artifact (i.e. artifacts/0910/plonk_vk.sol/HonkVerifier.json) instead of the deployed verifiers
+    #[arg(long)]
+    artifact: Option<PathBuf>,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_CheckVk.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+
+    let vk = VkFingerprint::load_vk(&args.vk)?;
+    println!("✅ vk fingerprint of {}: {} ({} commitments)", args.vk.display(), vk.hash(), vk.commitments.len());
+
+    // 1. Compare with an artifact (offline)
+    if let Some(artifact) = args.artifact.as_ref() {
+        let comparison = vk.compare(&VkFingerprint::from_artifact(&ContractArtifact::load(artifact)?)?);
+        println!("{} {}: {}", if comparison.is_match() { "✅" } else { "❌" }, artifact.display(), comparison);
+        return comparison.ensure_match();
+    }
+
+    // 2. Compare with the deployed verifier of each chain profile
+    let chains = if args.chain.is_empty() {
+        CHAIN_PROFILES.iter().filter(|chain| chain.contract_address("TRAVEL_HISTORY_MANAGER").is_ok()).copied().collect()
+    } else {
+        args.chain.iter().map(|name| ChainProfile::from_name(name)).collect::<eyre::Result<Vec<_>>>()?
+    };
+    if chains.is_empty() {
+        return Err(eyre::eyre!("No chain profile has a TravelHistoryManager in .env (i.e. TRAVEL_HISTORY_MANAGER_ON_BASE_MAINNET)"));
+    }
+
+    let mut mismatches = 0;
+    for chain in chains.iter() {
+        let provider = ProviderBuilder::new().network::<AnyNetwork>().connect_http(chain.rpc_url()?);
+        let travel_history_manager = TravelHistoryManager::new(chain.contract_address("TRAVEL_HISTORY_MANAGER")?, &provider);
+
+        let (honk_verifier, verifier) = deployed_verifier_fingerprint(&travel_history_manager).await?;
+        let comparison = vk.compare(&verifier);
+        if comparison.is_match() {
+            println!("✅ {}: HonkVerifier at {:?}: {}", chain, honk_verifier, comparison);
+        } else {
+            println!("❌ {}: HonkVerifier at {:?}: {}", chain, honk_verifier, comparison);
+            mismatches += 1;
+        }
+    }
+
+    if mismatches > 0 {
+        return Err(eyre::eyre!("The vk does not match the deployed verifier on {} chain(s)", mismatches));
+    }
+    Ok(())
+}
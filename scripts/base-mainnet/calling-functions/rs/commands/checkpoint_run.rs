@@ -0,0 +1,92 @@
+use travel_history::{
+    chain::ChainProfile,
+    checkpoint_run::{run_targets, RunReport, RunSigner, DEFAULT_RUN_REPORTS_PATH},
+    fork::{ForkSource, MainnetFork, DEFAULT_FORK_STATES_PATH},
+};
+
+use clap::Parser;
+use dotenv::dotenv;
+use std::path::PathBuf;
+
+/// Run the checkpoint plan of the mainnet batch runs (= each signer of PRIVATE_KEY_1..5 calls checkpoint() of each target), and write the run report.
+/// With `--fork`, the run is rehearsed against a local Anvil fork of the chain at a pinned block instead (= the same run report, w/ the fork recorded in it).
+#[derive(Parser, Debug)]
+struct Args {
+    /// The chain profile (base-mainnet or celo-mainnet)
+    #[arg(long, default_value = "base-mainnet")]
+    chain: String,
+
+    /// Comma-separated labels or tags of the deployment manifest (i.e. fleet-2025-09). The targets of each batch-run script (= <CONTRACT>_TARGETS or <CONTRACT>_ON_<CHAIN>_LIST) by default.
+    #[arg(long)]
+    select: Option<String>,
+
+    /// Rehearse the run against a local Anvil fork of the chain
+    #[arg(long)]
+    fork: bool,
+
+    /// The block the fork is pinned at (= the latest block of the fork source by default)
+    #[arg(long, requires = "fork")]
+    fork_block: Option<u64>,
+
+    /// The fork source: an RPC URL (i.e. a local archive node) or a cached fork state file. <CHAIN>_FORK_URL or the RPC of the chain by default.
+    #[arg(long, requires = "fork")]
+    fork_source: Option<ForkSource>,
+
+    /// Cache the state of the fork at the pinned block into this directory before the run, so that it can be rehearsed offline with `--fork-source <cached state>`
+    #[arg(long, requires = "fork", num_args = 0..=1, default_missing_value = DEFAULT_FORK_STATES_PATH)]
+    cache_state: Option<PathBuf>,
+
+    /// The directory of the run reports
+    #[arg(long, default_value = DEFAULT_RUN_REPORTS_PATH)]
+    reports: PathBuf,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_CheckpointRun.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+
+    let chain = ChainProfile::from_name(&args.chain)?;
+    let signers = RunSigner::from_env(args.fork)?;
+    let targets = run_targets(&chain, args.select.as_deref())?;
+    if targets.is_empty() {
+        return Err(eyre::eyre!("No target of the checkpoint plan on {}", chain));
+    }
+    println!("✅ chain: {}", chain);
+    println!("✅ {} signers x {} targets", signers.len(), targets.len());
+
+    let report = if args.fork {
+        // 1. Fork the chain at a pinned block, and impersonate the signers w/o a private key
+        let source = match args.fork_source {
+            Some(source) => source,
+            None => ForkSource::of_chain(&chain)?,
+        };
+        println!("🔄 Forking {} from {} ...", chain, source);
+        let mut fork = MainnetFork::start(&chain, source, args.fork_block).await?;
+        fork.impersonate(&signers).await?;
+        println!("✅ Anvil fork at block {} running at: {}", fork.block_number, fork.rpc_url);
+        for address in fork.impersonated.iter() {
+            println!("🎭 impersonated: {:?}", address);
+        }
+        if let Some(dir) = args.cache_state.as_ref() {
+            let path = fork.cache_state(dir, &signers, &targets).await?;
+            println!("✅ The fork state has been cached into {}", path.display());
+        }
+
+        // 2. Rehearse the run
+        RunReport::run(&chain, &fork.rpc_url, &signers, &targets, Some(fork.origin())).await?
+    } else {
+        RunReport::run(&chain, &chain.rpc_url()?, &signers, &targets, None).await?
+    };
+
+    println!("{}", report);
+    let path = report.write(&args.reports)?;
+    println!("✅ The run report has been written into {}", path.display());
+    if report.failures() > 0 {
+        return Err(eyre::eyre!("{} of {} checkpoint() calls have failed", report.failures(), report.results.len()));
+    }
+    Ok(())
+}
@@ -0,0 +1,159 @@
+// @dev - Alloy
+use alloy::{
+    network::AnyNetwork, // @dev - icl. AnyNetwork for Base Mainnet / Celo Mainnet
+    providers::ProviderBuilder,
+    signers::local::PrivateKeySigner,
+};
+
+use travel_history::{
+    chain::ChainProfile,
+    create2::{deploy_planned, plan_suite, Create2Deployment, Create2Salts, CREATE2_FACTORY},
+    artifact::ContractArtifact,
+    deployment::{artifact_path, deploy_fleet, deploy_suite, DeployedContract, DeploymentManifest, CONTRACT_SUITE, DEFAULT_ARTIFACTS_PATH, DEFAULT_DEPLOYMENTS_PATH},
+};
+
+use clap::Parser;
+use dotenv::dotenv;
+use std::env;
+use std::path::PathBuf;
+
+/// Deploy the contract suite from the Foundry artifacts: HonkVerifier -> TravelHistoryProofVerifier(HonkVerifier) -> TravelHistoryManager(TravelHistoryProofVerifier)
+/// -> TravelBookingManager -> StakingPool, and write the deployment manifest of the chain (= deployments/<chain>.json), which the other commands read the addresses from.
+/// With `--count N --contract <Contract>`, deploy a fleet of N instances of a contract instead, sharing the contracts of the suite in the manifest (i.e. the TravelHistoryProofVerifier).
+/// With `--create2`, deploy the contract suite through the CREATE2 factory, at the same addresses on every chain (= `--predict` prints them offline).
+#[derive(Parser, Debug)]
+struct Args {
+    /// The chain profile (base-mainnet, celo-mainnet or local-anvil)
+    #[arg(long, default_value = "base-mainnet")]
+    chain: String,
+
+    /// The directory of the Foundry artifacts (= <dir>/<Contract>.sol/<Contract>.json)
+    #[arg(long, default_value = DEFAULT_ARTIFACTS_PATH)]
+    artifacts: PathBuf,
+
+    /// The directory of the deployment manifests
+    #[arg(long, default_value = DEFAULT_DEPLOYMENTS_PATH)]
+    deployments: PathBuf,
+
+    /// The env var of the private key of the deployer
+    #[arg(long, default_value = "PRIVATE_KEY_1")]
+    private_key_env: String,
+
+    /// The number of instances of a fleet (= requires --contract)
+    #[arg(long, requires = "contract")]
+    count: Option<usize>,
+
+    /// The contract of a fleet (i.e. TravelHistoryManager)
+    #[arg(long, requires = "count")]
+    contract: Option<String>,
+
+    /// The label prefix of the instances of a fleet (= <prefix>-<NNN>). Defaults to the contract name.
+    #[arg(long)]
+    label_prefix: Option<String>,
+
+    /// A tag of the instances of a fleet, which batch runs select their targets by (= <CONTRACT>_TARGETS). Repeatable.
+    #[arg(long)]
+    tag: Vec<String>,
+
+    /// Deploy the contract suite through the CREATE2 factory (= the same addresses on Base Mainnet and Celo Mainnet)
+    #[arg(long, conflicts_with = "count")]
+    create2: bool,
+
+    /// The salt of the CREATE2 deployment (= a 32-byte hex or a string), or `<Contract>=<salt>` for a contract. Repeatable.
+    #[arg(long, requires = "create2")]
+    salt: Vec<String>,
+
+    /// Print the addresses of the CREATE2 deployment without deploying (= offline)
+    #[arg(long, requires = "create2")]
+    predict: bool,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_Deploy.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+
+    // 0. Predict the addresses of the CREATE2 deployment (= offline)
+    if args.create2 {
+        let salts = Create2Salts::parse(&args.salt)?;
+        let planned_contracts = plan_suite(&args.artifacts, &salts)?;
+        println!("✅ CREATE2 factory: {:?}", CREATE2_FACTORY);
+        for planned in planned_contracts.iter() {
+            println!("✅ {}: {:?} (salt: {})", planned.contract, planned.address, planned.salt);
+        }
+        if args.predict {
+            return Ok(());
+        }
+    }
+
+    // 1. Fetch values from env
+    let chain = ChainProfile::from_name(&args.chain)?;
+    let rpc_url = chain.rpc_url()?;
+    let private_key = env::var(&args.private_key_env).map_err(|_| eyre::eyre!("Set {} in your .env", args.private_key_env))?;
+    let signer: PrivateKeySigner = private_key.parse()?;
+    let deployer = signer.address();
+    println!("✅ chain: {}", chain);
+    println!("✅ deployer: {:?}", deployer);
+
+    let provider = ProviderBuilder::new()
+        .with_gas_estimation()
+        .network::<AnyNetwork>()
+        .wallet(signer)
+        .connect_http(rpc_url);
+
+    let mut manifest = DeploymentManifest::load(&args.deployments, &chain)?;
+    let deployed_contracts: Vec<DeployedContract> = match (args.count, &args.contract) {
+        // 2-a. Deploy a fleet of instances of a contract in parallel (= the manifest is saved once all of them are mined)
+        (Some(count), Some(contract)) => {
+            if !CONTRACT_SUITE.contains(&contract.as_str()) {
+                return Err(eyre::eyre!("{} is not a contract of the suite ({})", contract, CONTRACT_SUITE.join(", ")));
+            }
+            let artifact = ContractArtifact::load(artifact_path(&args.artifacts, contract))?;
+            let labels = manifest.next_labels(args.label_prefix.as_deref().unwrap_or(contract), count);
+            println!("🔄 Deploying {} instances of {} ({} .. {}) ...", count, contract, labels.first().cloned().unwrap_or_default(), labels.last().cloned().unwrap_or_default());
+            deploy_fleet(&provider, deployer, &artifact, contract, &labels, &args.tag, &mut manifest).await?
+        }
+        // 2-b. Deploy the contract suite through the CREATE2 factory in order, skipping the salts which have already been deployed (= the manifest is saved after each deployment)
+        _ if args.create2 => {
+            println!("🔄 Deploying the contract suite through the CREATE2 factory ...");
+            let mut deployed_contracts: Vec<DeployedContract> = Vec::new();
+            for planned in plan_suite(&args.artifacts, &Create2Salts::parse(&args.salt)?)?.iter() {
+                match deploy_planned(&provider, planned).await? {
+                    Create2Deployment::Deployed(deployed_contract) => {
+                        manifest.add((*deployed_contract).clone());
+                        deployed_contracts.push(*deployed_contract);
+                    }
+                    Create2Deployment::AlreadyDeployed(address) => {
+                        println!("⏭️ {}: {:?} has already been deployed with the salt {}", planned.contract, address, planned.salt);
+                        if !manifest.contracts.iter().any(|deployed| deployed.address == address) {
+                            manifest.add(planned.adopted()?);
+                        }
+                    }
+                }
+                manifest.save()?;
+            }
+            deployed_contracts
+        }
+        // 2-c. Deploy the contract suite in order (= the manifest is saved after each deployment)
+        _ => {
+            println!("🔄 Deploying the contract suite from {} ...", args.artifacts.display());
+            deploy_suite(&provider, &args.artifacts, &mut manifest).await?
+        }
+    };
+    for deployed in deployed_contracts.iter() {
+        println!(
+            "✅ {} ({}): {:?} (tx: {}, block: {})",
+            deployed.label,
+            deployed.contract,
+            deployed.address,
+            deployed.tx_hash,
+            deployed.block_number.map(|block_number| block_number.to_string()).unwrap_or_default()
+        );
+    }
+    println!("✅ The deployment manifest has been written into {}", manifest.path().display());
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use travel_history::test_vectors;
+
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Generate reproducible test vectors of the travel_history circuit: a Noir test module, plus a Prover.toml per test case and the expected nullifiers.
+#[derive(Parser, Debug)]
+struct Args {
+    /// The seed of the random test cases (= the same seed always generates the same test cases)
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+
+    /// The number of the random test cases (in addition to the edge cases)
+    #[arg(long, default_value_t = 8)]
+    count: usize,
+
+    /// The Noir test module to be generated (which is declared as `mod generated;` in circuits/src/tests/mod.nr)
+    #[arg(long, default_value = "../../../../circuits/src/tests/generated.nr")]
+    noir_module: PathBuf,
+
+    /// The output directory (= <out_dir>/<test case>/Prover.toml + <out_dir>/expected_nullifiers.json)
+    #[arg(long, default_value = "../../../../circuits/test-vectors")]
+    out_dir: PathBuf,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_GenerateTestVectors.sh" command at the root directory (= /rs)
+ */
+fn main() -> eyre::Result<()> {
+    let args = Args::parse();
+
+    // 1. Generate the edge cases and the random cases from the seed
+    let cases = test_vectors::generate(args.seed, args.count)?;
+    println!("✅ {} test case(s) are generated from the seed {}", cases.len(), args.seed);
+
+    // 2. Write the Noir test module
+    std::fs::write(&args.noir_module, test_vectors::to_noir_module(&cases, args.seed))
+        .map_err(|e| eyre::eyre!("Failed to write {}: {}", args.noir_module.display(), e))?;
+    println!("✅ The Noir test module has been written into {}", args.noir_module.display());
+
+    // 3. Write a Prover.toml per test case and the expected nullifiers
+    let expected_nullifiers = test_vectors::write_prover_tomls(&cases, &args.out_dir)?;
+    for entry in expected_nullifiers.iter() {
+        match (&entry.expected_nullifier, entry.should_fail_with) {
+            (Some(nullifier), _) => println!("✅ {}: nullifier = {}", entry.name, nullifier),
+            (None, message) => println!("✅ {}: should fail with \"{}\"", entry.name, message.unwrap_or_default()),
+        }
+    }
+    println!("✅ The expected nullifiers have been written into {}", args.out_dir.join("expected_nullifiers.json").display());
+
+    Ok(())
+}
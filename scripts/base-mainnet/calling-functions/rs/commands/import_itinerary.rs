@@ -0,0 +1,53 @@
+use travel_history::{
+    field::parse_field,
+    itinerary,
+};
+
+use clap::Parser;
+use dotenv::dotenv;
+use std::env;
+use std::path::PathBuf;
+
+/// Import a travel itinerary (CSV or JSON) and generate a Prover.toml per stay, plus a manifest (= manifest.json).
+#[derive(Parser, Debug)]
+struct Args {
+    /// The itinerary (*.csv or *.json) with the columns: passport_number, country, enter_date, exit_date
+    #[arg(long)]
+    input: PathBuf,
+
+    /// The output directory (= <out_dir>/stay_<row>/Prover.toml + <out_dir>/manifest.json)
+    #[arg(long, default_value = "data/itinerary")]
+    out_dir: PathBuf,
+
+    /// The env var of the secret of the traveler, from which the note secret of each stay is derived
+    #[arg(long, default_value = "TRAVELER_SECRET")]
+    secret_env: String,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_ImportItinerary.sh" command at the root directory (= /rs)
+ */
+fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+
+    // 1. Fetch the secret of the traveler from env
+    let secret = env::var(&args.secret_env).map_err(|_| eyre::eyre!("Set {} in your .env", args.secret_env))?;
+    let secret = parse_field(&secret).map_err(|e| eyre::eyre!("Invalid {}: {}", args.secret_env, e))?;
+
+    // 2. Parse and validate the itinerary
+    let stays = itinerary::load(&args.input)?;
+    println!("✅ {} stay(s) are imported from {}", stays.len(), args.input.display());
+
+    // 3. Write a Prover.toml per stay and the manifest
+    let manifest = itinerary::write_batch(&stays, secret, &args.out_dir)?;
+    for entry in manifest.iter() {
+        println!("✅ row {}: {} -> {}", entry.row, entry.country, entry.prover_toml.display());
+        for warning in entry.warnings.iter() {
+            println!("⚠️ row {}: {}", entry.row, warning);
+        }
+    }
+    println!("✅ The manifest has been written into {}", args.out_dir.join("manifest.json").display());
+
+    Ok(())
+}
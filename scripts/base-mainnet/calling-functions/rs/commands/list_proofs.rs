@@ -0,0 +1,89 @@
+// @dev - Alloy
+use alloy::{
+    network::AnyNetwork, // @dev - icl. AnyNetwork for Base Mainnet / Celo Mainnet
+    primitives::Address,
+    providers::ProviderBuilder,
+};
+
+use travel_history::{
+    chain::ChainProfile,
+    contracts::TravelHistoryManager,
+    proof_store::{ProofStore, DEFAULT_PROOF_STORE_PATH},
+    public_inputs::TravelPublicInputs,
+};
+
+use clap::Parser;
+use dotenv::dotenv;
+use std::path::PathBuf;
+
+/// List the proofs of a traveler in the local proof store, and optionally re-run the on-chain views with the stored proof bytes.
+#[derive(Parser, Debug)]
+struct Args {
+    /// The wallet address of the traveler
+    #[arg(long)]
+    traveler: Address,
+
+    /// Only the proofs submitted on this chain profile
+    #[arg(long)]
+    chain: Option<String>,
+
+    /// The directory of the local proof store
+    #[arg(long, default_value = DEFAULT_PROOF_STORE_PATH)]
+    proof_store: PathBuf,
+
+    /// Re-run the on-chain views (isTravelHistoryProofRecorded, getPublicInputsOfTravelHistoryProof, isTravelerBreachingDaysLimitOfStaying)
+    #[arg(long)]
+    on_chain: bool,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_ListProofs.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+
+    let proof_store = ProofStore::open(&args.proof_store)?;
+    let stored_proofs = proof_store.proofs_of(args.traveler, args.chain.as_deref())?;
+    println!("✅ {} proof(s) of {:?} in {}", stored_proofs.len(), args.traveler, args.proof_store.display());
+
+    for stored_proof in stored_proofs.iter() {
+        println!("\n📄 proof: {}", stored_proof.proof_hash);
+        match TravelPublicInputs::from_bytes32s(&stored_proof.public_inputs) {
+            Ok(public_inputs) => println!("   publicInputs: {}", public_inputs),
+            Err(e) => println!("   ⚠️ Invalid publicInputs: {}", e),
+        }
+
+        let submissions = stored_proof
+            .submissions_of(args.traveler)
+            .filter(|submission| args.chain.as_deref().is_none_or(|chain| submission.chain == chain));
+        for submission in submissions {
+            match submission.relayer {
+                Some(relayer) => println!("   submitted on {} via the relayer {:?} (contract: {:?}, tx: {:?})", submission.chain, relayer, submission.contract, submission.tx_hash),
+                None => println!("   submitted on {} (contract: {:?}, tx: {:?})", submission.chain, submission.contract, submission.tx_hash),
+            }
+            if !args.on_chain {
+                continue;
+            }
+
+            let chain = ChainProfile::from_name(&submission.chain)?;
+            let provider = ProviderBuilder::new().network::<AnyNetwork>().connect_http(chain.rpc_url()?);
+            let travel_history_manager = TravelHistoryManager::new(submission.contract, &provider);
+
+            let sender = submission.sender();
+            let is_recorded = travel_history_manager.isTravelHistoryProofRecorded(sender, stored_proof.proof.clone()).call().await?;
+            let public_input = travel_history_manager.getPublicInputsOfTravelHistoryProof(sender, stored_proof.proof.clone()).call().await?;
+            let is_breaching = travel_history_manager.isTravelerBreachingDaysLimitOfStaying(sender, stored_proof.proof.clone()).call().await?;
+            println!("   🔄 isTravelHistoryProofRecorded: {}", is_recorded);
+            let on_chain_public_inputs = TravelPublicInputs::try_from(public_input)?;
+            println!(
+                "   🔄 getPublicInputsOfTravelHistoryProof: {} the stored publicInputs",
+                if on_chain_public_inputs.to_bytes32s() == stored_proof.public_inputs { "matches" } else { "does NOT match" }
+            );
+            println!("   🔄 isTravelerBreachingDaysLimitOfStaying: {}", is_breaching);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+// @dev - Alloy
+use alloy::primitives::Bytes;
+
+use travel_history::{
+    artifact_registry::{ArtifactRegistry, DEFAULT_ARTIFACT_REGISTRY_PATH},
+    chain::LOCAL_ANVIL,
+    checkpoint_run::{RunReport, RunSigner, RunTarget},
+    deployment::DEFAULT_DEPLOYMENTS_PATH,
+    local_anvil::{AnvilMode, LocalAnvil, MockInjection, MockVerifier, MOCK_TAG},
+};
+
+use clap::Parser;
+use dotenv::dotenv;
+use std::path::PathBuf;
+
+/// Run the local-Anvil mode: spawn Anvil, install a mock TravelHistoryProofVerifier (always-true, always-false or allowlist),
+/// deploy the TravelHistoryManager / TravelBookingManager / StakingPool, write the deployment manifest of the local-anvil chain profile,
+/// and run the checkpoint plan of the mainnet batch runs (= each development account calls checkpoint() of each contract).
+#[derive(Parser, Debug)]
+struct Args {
+    /// The mock verifier (always-true, always-false or allowlist)
+    #[arg(long, default_value = "always-true")]
+    mock_verifier: String,
+
+    /// A proof file (or a proof bundle directory) which the allowlist verifier accepts. Repeatable.
+    #[arg(long)]
+    allow_proof: Vec<PathBuf>,
+
+    /// How the mock verifier is injected (deploy or set-code)
+    #[arg(long, default_value = "set-code")]
+    injection: MockInjection,
+
+    /// The port of the spawned Anvil (= 8545 is the RPC of the local-anvil chain profile)
+    #[arg(long, default_value_t = 8545)]
+    port: u16,
+
+    /// Attach to an Anvil which is already running (= LOCAL_ANVIL_RPC) instead of spawning one
+    #[arg(long)]
+    attach: bool,
+
+    /// The number of development accounts which run the checkpoint plan (= 5 private keys on mainnet)
+    #[arg(long, default_value_t = 5)]
+    signers: usize,
+
+    /// Do not run the checkpoint plan
+    #[arg(long)]
+    skip_plan: bool,
+
+    /// Keep the spawned Anvil running until Ctrl-C, so that the other commands can run with `--chain local-anvil`
+    #[arg(long)]
+    keep_alive: bool,
+
+    /// The root directory of the artifact versions (= the latest version of each contract is deployed)
+    #[arg(long, default_value = DEFAULT_ARTIFACT_REGISTRY_PATH)]
+    registry: PathBuf,
+
+    /// The directory of the deployment manifests
+    #[arg(long, default_value = DEFAULT_DEPLOYMENTS_PATH)]
+    deployments: PathBuf,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_LocalAnvil.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+
+    // 1. Build the mock verifier
+    let mock_verifier = match args.mock_verifier.as_str() {
+        "always-true" => MockVerifier::AlwaysTrue,
+        "always-false" => MockVerifier::AlwaysFalse,
+        "allowlist" => {
+            let mut proofs: Vec<Bytes> = Vec::new();
+            for path in args.allow_proof.iter() {
+                let proof_path = if path.is_dir() { path.join("proof") } else { path.clone() };
+                let proof = std::fs::read(&proof_path).map_err(|e| eyre::eyre!("Failed to read the proof {}: {}", proof_path.display(), e))?;
+                proofs.push(Bytes::from(proof));
+            }
+            MockVerifier::Allowlist(proofs)
+        }
+        other => return Err(eyre::eyre!("Unknown mock verifier: {} (expected one of: always-true, always-false, allowlist)", other)),
+    };
+
+    // 2. Spawn Anvil (or attach to it), and deploy the contracts
+    let mode = if args.attach { AnvilMode::Attach } else { AnvilMode::Spawn { port: Some(args.port) } };
+    let registry = ArtifactRegistry::load(&args.registry)?;
+    let local = LocalAnvil::deploy(mode, &registry, &args.deployments, &mock_verifier, args.injection).await?;
+    println!("✅ Anvil running at: {}", local.rpc_url);
+    for deployed in local.manifest.contracts.iter() {
+        println!("✅ {}: {:?}", deployed.label, deployed.address);
+    }
+    println!("✅ mock verifier: {} ({:?})", mock_verifier, args.injection);
+    println!("✅ The deployment manifest has been written into {}", local.manifest.path().display());
+
+    // 3. Run the checkpoint plan of the mainnet batch runs
+    if !args.skip_plan {
+        let targets: Vec<RunTarget> =
+            local.manifest.contracts.iter().filter(|deployed| !deployed.tags.iter().any(|tag| tag == MOCK_TAG)).map(RunTarget::from).collect();
+        let signers: Vec<RunSigner> = local.signers.iter().take(args.signers).cloned().map(RunSigner::Key).collect();
+        println!("🔄 Running the checkpoint plan ({} signers x {} contracts) ...", signers.len(), targets.len());
+        let report = RunReport::run(&LOCAL_ANVIL, &local.rpc_url, &signers, &targets, None).await?;
+        println!("{}", report);
+        if report.failures() > 0 {
+            return Err(eyre::eyre!("{} of {} checkpoint() calls have failed", report.failures(), report.results.len()));
+        }
+    }
+
+    // 4. Keep Anvil running for the other commands (i.e. `submit_proof_batch --chain local-anvil`)
+    if args.keep_alive && local.anvil.is_some() {
+        println!("🔄 Anvil keeps running: run the other commands with `--chain local-anvil` (Ctrl-C to stop) ...");
+        tokio::signal::ctrl_c().await?;
+    }
+
+    Ok(())
+}
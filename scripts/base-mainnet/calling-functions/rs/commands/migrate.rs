@@ -0,0 +1,113 @@
+// @dev - Alloy
+use alloy::{
+    network::AnyNetwork, // @dev - icl. AnyNetwork for Base Mainnet / Celo Mainnet
+    primitives::Address,
+    providers::ProviderBuilder,
+    signers::local::PrivateKeySigner,
+};
+
+use travel_history::{
+    chain::ChainProfile,
+    checkpoint_run::RUN_SIGNERS,
+    deployment::{DeploymentManifest, DEFAULT_DEPLOYMENTS_PATH},
+    migration::{MigratedContract, MigrationReport, MigrationSet, MigrationSnapshot, DEFAULT_MIGRATION_REPORTS_PATH, DEFAULT_MIGRATION_SET_PATH},
+    proof_store::{ProofStore, DEFAULT_PROOF_STORE_PATH},
+};
+
+use clap::Parser;
+use dotenv::dotenv;
+use std::env;
+use std::path::PathBuf;
+
+/// Migrate the registrations of an old TravelHistoryManager / TravelBookingManager instance to a newly deployed version:
+/// snapshot the role mappings and the room listings of a known set of addresses / room ids from the old instance, re-create them on the new instance
+/// by self-registration w/ the keys of PRIVATE_KEY_1..5, and report what could not be migrated (i.e. because it needs the key of the account owner,
+/// or the spent nullifiers and the recorded proofs of the travelers, which the new instance does not know).
+#[derive(Parser, Debug)]
+struct Args {
+    /// The chain profile (base-mainnet, celo-mainnet or local-anvil)
+    #[arg(long, default_value = "base-mainnet")]
+    chain: String,
+
+    /// The contract to migrate (TravelHistoryManager or TravelBookingManager)
+    #[arg(long)]
+    contract: MigratedContract,
+
+    /// The old instance: a label of the deployment manifest, or an address
+    #[arg(long)]
+    from: String,
+
+    /// The new instance: a label of the deployment manifest, or an address
+    #[arg(long)]
+    to: String,
+
+    /// The known set of addresses and room ids to snapshot
+    #[arg(long, default_value = DEFAULT_MIGRATION_SET_PATH)]
+    set: PathBuf,
+
+    /// Snapshot and report the migration plan without sending any transaction
+    #[arg(long)]
+    dry_run: bool,
+
+    /// The directory of the migration reports
+    #[arg(long, default_value = DEFAULT_MIGRATION_REPORTS_PATH)]
+    reports: PathBuf,
+
+    /// The directory of the local proof store (= the recorded proofs of the travelers to report)
+    #[arg(long, default_value = DEFAULT_PROOF_STORE_PATH)]
+    proof_store: PathBuf,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_Migrate.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+
+    let chain = ChainProfile::from_name(&args.chain)?;
+    let manifest = DeploymentManifest::load(DEFAULT_DEPLOYMENTS_PATH, &chain)?;
+    let resolve = |instance: &str| -> eyre::Result<Address> {
+        match manifest.by_label(instance) {
+            Some(deployed) if deployed.contract == args.contract.to_string() => Ok(deployed.address),
+            Some(deployed) => Err(eyre::eyre!("{} is a {}, not a {}", instance, deployed.contract, args.contract)),
+            None => instance.parse().map_err(|_| eyre::eyre!("{} is neither a label of {} nor an address", instance, manifest.path().display())),
+        }
+    };
+    let (from, to) = (resolve(&args.from)?, resolve(&args.to)?);
+    if from == to {
+        return Err(eyre::eyre!("The old and the new instances are the same ({:?})", from));
+    }
+
+    // @dev - The keys of the owning signers (= PRIVATE_KEY_1..5 which are set)
+    let mut signers: Vec<PrivateKeySigner> = Vec::new();
+    for i in 1..=RUN_SIGNERS {
+        let private_key = env::var(format!("PRIVATE_KEY_{}", i)).unwrap_or_default();
+        if !private_key.trim().is_empty() {
+            signers.push(private_key.trim().parse().map_err(|e| eyre::eyre!("Invalid PRIVATE_KEY_{}: {}", i, e))?);
+        }
+    }
+
+    let set = MigrationSet::load(&args.set)?;
+    println!("✅ chain: {}", chain);
+    println!("✅ {}: {:?} -> {:?}", args.contract, from, to);
+    println!("✅ known set: {} addresses, {} rooms ({} signer keys available)", set.addresses.len(), set.rooms.len(), signers.len());
+
+    // 1. Snapshot the old instance
+    let rpc_url = chain.rpc_url()?;
+    let provider = ProviderBuilder::new().network::<AnyNetwork>().connect_http(rpc_url.clone());
+    let proof_store = ProofStore::open(&args.proof_store)?;
+    let snapshot = MigrationSnapshot::take(&provider, args.contract, from, &set, Some(&proof_store)).await?;
+    println!("✅ snapshot at block {}: {} registrations, {} listed rooms", snapshot.block_number, snapshot.registrations.len(), snapshot.rooms.len());
+    if args.contract == MigratedContract::TravelHistoryManager {
+        println!("✅ {} spent nullifiers, {} recorded proofs of the travelers", snapshot.nullifiers.len(), snapshot.proofs.len());
+    }
+
+    // 2. Re-create it on the new instance
+    let report = MigrationReport::migrate(&rpc_url, snapshot, to, &signers, args.dry_run).await?;
+    println!("{}", report);
+    let path = report.write(&args.reports)?;
+    println!("✅ The migration report has been written into {}", path.display());
+    Ok(())
+}
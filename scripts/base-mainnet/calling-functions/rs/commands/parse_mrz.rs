@@ -0,0 +1,35 @@
+// @dev - Noir / ACVM
+use acvm::AcirField;
+
+use travel_history::mrz::Mrz;
+
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Parse the MRZ (ICAO 9303 TD3) of a passport and print the `passport_number` input of the travel_history circuit.
+#[derive(Parser, Debug)]
+struct Args {
+    /// The text file of the MRZ (= 2 lines of 44 characters)
+    #[arg(long)]
+    mrz: PathBuf,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_ParseMrz.sh" command at the root directory (= /rs)
+ */
+fn main() -> eyre::Result<()> {
+    let args = Args::parse();
+
+    let mrz = std::fs::read_to_string(&args.mrz).map_err(|e| eyre::eyre!("Failed to read {}: {}", args.mrz.display(), e))?;
+    let mrz = Mrz::parse(&mrz)?;
+    println!("✅ The check digits of the MRZ are valid");
+    println!("✅ document_number: {}", mrz.document_number);
+    println!("✅ nationality: {}", mrz.nationality);
+    println!("✅ birth_date: {}", mrz.birth_date);
+    println!("✅ expiry_date: {}", mrz.expiry_date);
+
+    let passport_number = mrz.passport_number()?;
+    println!("✅ passport_number (for Prover.toml): \"{}\"", passport_number.to_short_hex());
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+// @dev - Alloy
+use alloy::{
+    eips::BlockId,
+    network::{AnyNetwork, ReceiptResponse}, // @dev - icl. AnyNetwork for Base Mainnet / Celo Mainnet
+    providers::ProviderBuilder,
+    signers::local::PrivateKeySigner,
+};
+
+use travel_history::{
+    artifact::{ContractArtifact, TRAVEL_HISTORY_MANAGER_ARTIFACT_PATH},
+    chain::ChainProfile,
+    contracts::TravelHistoryManager,
+    nullifier::{check_nullifier, NullifierRecord, NullifierRegistry, DEFAULT_NULLIFIER_REGISTRY_PATH},
+    proof_bundle::ProofBundle,
+    proof_store::{ProofStore, ProofSubmission, DEFAULT_PROOF_STORE_PATH},
+    vk::{deployed_verifier_fingerprint, VkFingerprint},
+};
+
+use clap::Parser;
+use dotenv::dotenv;
+use std::env;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Record a travel history proof via TravelHistoryManager#recordTravelHistoryProof().
+/// Before submitting, the publicInputs of the proof (incl. the nullifier) are checked against the ones computed from the witness (Prover.toml),
+/// and the nullifier is checked against the on-chain `nullifiers(bytes32)`. The witness is required, unless `--skip-witness-check` is given.
+#[derive(Parser, Debug)]
+struct Args {
+    /// The chain profile (base-mainnet, celo-mainnet or local-anvil)
+    #[arg(long, default_value = "base-mainnet")]
+    chain: String,
+
+    /// The directory of the proof bundle (= `proof` + `public_inputs`, i.e. circuits/target)
+    #[arg(long)]
+    proof_bundle: PathBuf,
+
+    /// The Prover.toml of the proof (if it is not in the proof bundle directory)
+    #[arg(long)]
+    prover_toml: Option<PathBuf>,
+
+    /// Submit the proof without its witness (= the nullifier of the proof is taken as is, w/o being checked against the witness)
+    #[arg(long)]
+    skip_witness_check: bool,
+
+    /// The vk of the circuit build, which the proof is made with (if it is not in the proof bundle directory)
+    #[arg(long)]
+    vk: Option<PathBuf>,
+
+    /// The env var of the private key of the traveler
+    #[arg(long, default_value = "PRIVATE_KEY_1")]
+    private_key_env: String,
+
+    /// The JSON file of the local nullifier registry
+    #[arg(long, default_value = DEFAULT_NULLIFIER_REGISTRY_PATH)]
+    registry: PathBuf,
+
+    /// The directory of the local proof store
+    #[arg(long, default_value = DEFAULT_PROOF_STORE_PATH)]
+    proof_store: PathBuf,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_RecordTravelHistoryProof.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+
+    // 1. Fetch values from env
+    let chain = ChainProfile::from_name(&args.chain)?;
+    let rpc_url = chain.rpc_url()?;
+    let contract_address = chain.contract_address("TRAVEL_HISTORY_MANAGER")?;
+    let private_key = env::var(&args.private_key_env).map_err(|_| eyre::eyre!("Set {} in your .env", args.private_key_env))?;
+    println!("✅ chain: {}", chain);
+    println!("✅ contract_address: {:?}", contract_address);
+
+    let signer: PrivateKeySigner = private_key.parse()?;
+    let traveler = signer.address();
+    println!("✅ traveler: {:?}", traveler);
+
+    let provider = ProviderBuilder::new()
+        .with_gas_estimation()
+        .network::<AnyNetwork>()
+        .wallet(signer)
+        .connect_http(rpc_url);
+    let travel_history_manager = TravelHistoryManager::new(contract_address, &provider);
+
+    // 2. Load the proof bundle and compute the expected publicInputs (incl. the nullifier) from the witness
+    let proof_bundle = ProofBundle::load(&args.proof_bundle)?;
+    let public_inputs = proof_bundle.travel_public_inputs()?;
+    let nullifier = public_inputs.nullifier_hash;
+    println!("✅ publicInputs: {}", public_inputs);
+    match args.prover_toml.as_ref().or(proof_bundle.prover_toml.as_ref()) {
+        Some(prover_toml) => {
+            let expected_public_inputs = proof_bundle.check_against_witness(prover_toml)?;
+            println!("✅ nullifier (computed from the witness): {:?}", expected_public_inputs.nullifier_hash);
+        }
+        None if args.skip_witness_check => {
+            println!("⚠️ --skip-witness-check is given, so the nullifier of the proof is not checked against the witness: {:?}", nullifier)
+        }
+        None => {
+            return Err(eyre::eyre!(
+                "No Prover.toml is found in {} (pass it via --prover-toml, or pass --skip-witness-check to submit the proof without checking it against the witness)",
+                args.proof_bundle.display()
+            ))
+        }
+    }
+
+    // 3. Pre-submission vk check (= the proof must be made with the same vk as the deployed verifier)
+    match args.vk.as_ref().or(proof_bundle.vk.as_ref()) {
+        Some(vk) => {
+            let vk = VkFingerprint::load_vk(vk)?;
+            let (honk_verifier, verifier) = deployed_verifier_fingerprint(&travel_history_manager).await?;
+            let comparison = vk.compare(&verifier);
+            comparison.ensure_match().map_err(|e| eyre::eyre!("HonkVerifier at {:?}: {}", honk_verifier, e))?;
+            println!("✅ vk fingerprint (matches the deployed verifier): {:?}", comparison.vk_fingerprint);
+        }
+        None => println!("⚠️ No vk is given, so the vk of the proof is not checked against the deployed verifier"),
+    }
+
+    // 4. Pre-submission nullifier check (on-chain + local registry)
+    let mut registry = NullifierRegistry::load(&args.registry)?;
+    let check = check_nullifier(&travel_history_manager, &registry, chain.name, traveler, nullifier).await?;
+    check.ensure_unspent()?;
+    if let Some(overwritten_nullifier) = check.overwritten_nullifier() {
+        println!(
+            "⚠️ nullifiersByWalletAddresses({:?}) will be overwritten: {:?} -> {:?}",
+            traveler, overwritten_nullifier, nullifier
+        );
+    }
+
+    if travel_history_manager.isTravelHistoryProofRecorded(traveler, proof_bundle.proof.clone()).call().await? {
+        return Err(eyre::eyre!("A given proof is already recorded on-chain"));
+    }
+
+    // 5. Send the transaction and await receipt
+    println!("🔄 Calling the TravelHistoryManager#recordTravelHistoryProof() ...");
+    let tx = travel_history_manager.recordTravelHistoryProof(proof_bundle.proof.clone(), public_inputs.to_bytes32s());
+    let tx_receipt = tx.send().await?.get_receipt().await?;
+    if !tx_receipt.status() {
+        // @dev - The reason of the revert is decoded by replaying the call (= eth_call) on the state before its block.
+        //        Nothing is recorded locally, so that the local registry and the proof store stay in sync with the chain.
+        let artifact = ContractArtifact::load(TRAVEL_HISTORY_MANAGER_ARTIFACT_PATH)?;
+        let mut replay = travel_history_manager.recordTravelHistoryProof(proof_bundle.proof.clone(), public_inputs.to_bytes32s()).from(traveler);
+        if let Some(block_number) = tx_receipt.block_number() {
+            replay = replay.block(BlockId::number(block_number.saturating_sub(1)));
+        }
+        let reason = match replay.call().await {
+            Err(e) => match e.as_revert_data() {
+                Some(data) => artifact.decode_revert(&data),
+                None => "reverted on-chain".to_string(),
+            },
+            Ok(_) => "reverted on-chain (the replay on the state before its block succeeded)".to_string(),
+        };
+        return Err(eyre::eyre!("The transaction {} has been reverted: {}", tx_receipt.transaction_hash, reason));
+    }
+    println!("✅ Transaction receipt: {:?}", tx_receipt);
+
+    // 6. Record the nullifier into the local registry
+    let recorded_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    registry.record(
+        traveler,
+        NullifierRecord {
+            nullifier,
+            chain: chain.name.to_string(),
+            contract: contract_address,
+            tx_hash: Some(tx_receipt.transaction_hash),
+            recorded_at,
+        },
+    );
+    registry.save()?;
+    println!("✅ The nullifier has been recorded into {}", args.registry.display());
+
+    // 7. Save the proof into the local proof store
+    let proof_store = ProofStore::open(&args.proof_store)?;
+    let proof_hash = proof_store.put(&proof_bundle.proof, &public_inputs.to_bytes32s())?;
+    proof_store.add_submission(
+        proof_hash,
+        ProofSubmission {
+            chain: chain.name.to_string(),
+            contract: contract_address,
+            traveler,
+            relayer: None,
+            tx_hash: Some(tx_receipt.transaction_hash),
+            recorded_at,
+        },
+    )?;
+    println!("✅ The proof has been saved into {}", proof_store.path_of(proof_hash).display());
+
+    Ok(())
+}
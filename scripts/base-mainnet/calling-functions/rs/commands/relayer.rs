@@ -0,0 +1,105 @@
+// @dev - Alloy
+use alloy::signers::local::PrivateKeySigner;
+
+use travel_history::{
+    artifact::{ContractArtifact, HONK_VERIFIER_ARTIFACT_PATH, TRAVEL_HISTORY_MANAGER_ARTIFACT_PATH, TRAVEL_HISTORY_PROOF_VERIFIER_ARTIFACT_PATH},
+    chain::ChainProfile,
+    local_verifier::LocalVerifier,
+    proof_store::DEFAULT_PROOF_STORE_PATH,
+    relayer::{self, Relayer, RelayerConfig, Simulator},
+};
+
+use clap::Parser;
+use dotenv::dotenv;
+use std::env;
+use std::path::PathBuf;
+
+/// Run the relayer service, which submits TravelHistoryManager#recordTravelHistoryProof() on behalf of travelers from a pool of funded relayer wallets.
+/// API: `POST /relay` (= { traveler, proof, public_inputs, signature }), `GET /relay/{id}` and `GET /health`.
+/// A relayed proof is recorded on-chain under the relayer wallet (= `msg.sender`), not under the traveler.
+#[derive(Parser, Debug)]
+struct Args {
+    /// The chain profile (base-mainnet, celo-mainnet or local-anvil)
+    #[arg(long, default_value = "base-mainnet")]
+    chain: String,
+
+    /// The address to listen on
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    listen: String,
+
+    /// The env vars of the private keys of the relayer wallet pool (repeatable)
+    #[arg(long, default_values_t = ["PRIVATE_KEY_1".to_string()])]
+    private_key_env: Vec<String>,
+
+    /// Simulate each proof via the TravelHistoryProofVerifier (+ HonkVerifier) deployed on a local Anvil before queueing it
+    #[arg(long)]
+    simulate: bool,
+
+    /// The artifact of the HonkVerifier (for --simulate)
+    #[arg(long, default_value = HONK_VERIFIER_ARTIFACT_PATH)]
+    honk_verifier_artifact: PathBuf,
+
+    /// The artifact of the TravelHistoryProofVerifier (for --simulate)
+    #[arg(long, default_value = TRAVEL_HISTORY_PROOF_VERIFIER_ARTIFACT_PATH)]
+    verifier_artifact: PathBuf,
+
+    /// The directory of the local proof store, which the relayed proofs are saved into
+    #[arg(long, default_value = DEFAULT_PROOF_STORE_PATH)]
+    proof_store: PathBuf,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_Relayer.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+
+    // 1. Fetch values from env
+    let chain = ChainProfile::from_name(&args.chain)?;
+    let contract_address = chain.contract_address("TRAVEL_HISTORY_MANAGER")?;
+    let wallets = args
+        .private_key_env
+        .iter()
+        .map(|private_key_env| {
+            let private_key = env::var(private_key_env).map_err(|_| eyre::eyre!("Set {} in your .env", private_key_env))?;
+            Ok(private_key.parse::<PrivateKeySigner>()?)
+        })
+        .collect::<eyre::Result<Vec<_>>>()?;
+    println!("✅ chain: {}", chain);
+    println!("✅ contract_address: {:?}", contract_address);
+
+    // 2. Deploy the local verifier on Anvil (if the simulation is enabled)
+    let simulator = if args.simulate {
+        let (honk_verifier_artifact, verifier_artifact) = LocalVerifier::load_artifacts(&args.honk_verifier_artifact, &args.verifier_artifact)?;
+        let simulator = Simulator::spawn(honk_verifier_artifact, &verifier_artifact).await?;
+        println!("✅ The proofs are simulated via the local verifier on Anvil");
+        Some(simulator)
+    } else {
+        None
+    };
+
+    // 3. Start the relayer (= register the relayer wallets as travelers, and spawn a worker per wallet)
+    let config = RelayerConfig {
+        chain: chain.name.to_string(),
+        rpc_url: chain.rpc_url()?,
+        travel_history_manager: contract_address,
+        wallets,
+        proof_store: Some(args.proof_store),
+    };
+    let relayer = Relayer::start(config, ContractArtifact::load(TRAVEL_HISTORY_MANAGER_ARTIFACT_PATH)?, simulator).await?;
+    for wallet in relayer.relayers.iter() {
+        println!("✅ relayer wallet: {:?}", wallet);
+    }
+    for warning in relayer.warnings.iter() {
+        println!("⚠️ {}", warning);
+    }
+
+    // 4. Serve the HTTP API
+    let listener = tokio::net::TcpListener::bind(&args.listen).await?;
+    println!("✅ The relayer is listening on http://{}", listener.local_addr()?);
+    axum::serve(listener, relayer::router(relayer)).await?;
+
+    Ok(())
+}
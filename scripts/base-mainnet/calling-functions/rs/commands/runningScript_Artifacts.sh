@@ -0,0 +1,8 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Listing the artifact versions..."
+cargo run --bin artifacts -- list
+
+echo "Picking the artifact version of the TravelHistoryManager deployed on Base Mainnet..."
+cargo run --bin artifacts -- resolve --chain base-mainnet --contract TravelHistoryManager
@@ -0,0 +1,5 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Querying the travel status of a traveler..."
+cargo run --bin border_control -- --chain base-mainnet --traveler $TRAVELER_ADDRESS
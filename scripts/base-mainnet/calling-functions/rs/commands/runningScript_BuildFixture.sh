@@ -0,0 +1,5 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Building the seeded Anvil state fixture (= deploy + seed on Anvil + anvil_dumpState)..."
+cargo run --bin build_fixture -- --name default
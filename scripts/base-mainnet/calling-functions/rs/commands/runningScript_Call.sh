@@ -0,0 +1,8 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Calling a view function of the TravelBookingManager w/ JSON arguments..."
+cargo run --bin call -- --chain base-mainnet --contract TravelBookingManager --function isBooker --args '["0x70997970C51812dc3A010C7d01b50e0d17dc79C8"]'
+
+echo "Simulating a state-changing function of the TravelBookingManager (w/o sending it)..."
+cargo run --bin call -- --chain base-mainnet --contract TravelBookingManager --function listAvailableRooms --args '{"roomId": "1", "roomPrice": "10000000000000000"}' --dry-run
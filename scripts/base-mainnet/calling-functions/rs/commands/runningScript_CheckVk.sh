@@ -0,0 +1,5 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Comparing the vk fingerprint of the circuit build with the deployed verifiers..."
+cargo run --bin check_vk -- --vk ../../../../circuits/target/vk
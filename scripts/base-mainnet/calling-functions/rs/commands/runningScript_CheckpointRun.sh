@@ -0,0 +1,8 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Rehearsing the checkpoint plan on a local Anvil fork of Base Mainnet (w/ the fork state cached for offline rehearsals)..."
+cargo run --bin checkpoint_run -- --chain base-mainnet --fork --cache-state
+
+# echo "Running the checkpoint plan on Base Mainnet for real..."
+# cargo run --bin checkpoint_run -- --chain base-mainnet
@@ -0,0 +1,5 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Deploying the contract suite and writing the deployment manifest..."
+cargo run --bin deploy -- --chain base-mainnet --artifacts ./artifacts/0910
@@ -0,0 +1,9 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Predicting the addresses of the CREATE2 deployment..."
+cargo run --bin deploy -- --create2 --salt travel-history-v1 --predict
+
+echo "Deploying the contract suite through the CREATE2 factory on Base Mainnet and Celo Mainnet..."
+cargo run --bin deploy -- --chain base-mainnet --create2 --salt travel-history-v1
+cargo run --bin deploy -- --chain celo-mainnet --create2 --salt travel-history-v1
@@ -0,0 +1,2 @@
+echo "Generating the test vectors of the travel_history circuit..."
+cargo run --bin generate_test_vectors -- --seed 1 --count 8
@@ -0,0 +1,5 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Importing a travel itinerary into a Prover.toml per stay..."
+cargo run --bin import_itinerary -- --input ./config/itinerary.example.csv --out-dir data/itinerary
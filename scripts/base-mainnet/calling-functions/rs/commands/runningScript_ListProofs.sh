@@ -0,0 +1,5 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Listing the proofs of a traveler in the local proof store..."
+cargo run --bin list_proofs -- --traveler $TRAVELER_ADDRESS --on-chain
@@ -0,0 +1,5 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Spawning Anvil, deploying all the contracts with a mock verifier, and running the checkpoint plan..."
+cargo run --bin local_anvil -- --mock-verifier always-true --injection set-code --keep-alive
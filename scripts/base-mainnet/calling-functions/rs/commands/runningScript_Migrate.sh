@@ -0,0 +1,5 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Migrating the registrations of the old TravelBookingManager to the new one (dry run)..."
+cargo run --bin migrate -- --chain base-mainnet --contract TravelBookingManager --from TravelBookingManager --to TravelBookingManager-v2 --dry-run
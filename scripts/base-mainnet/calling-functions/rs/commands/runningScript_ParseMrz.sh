@@ -0,0 +1,2 @@
+echo "Parsing the MRZ of a passport into the passport_number of the circuit..."
+cargo run --bin parse_mrz -- --mrz ./config/mrz.example.txt
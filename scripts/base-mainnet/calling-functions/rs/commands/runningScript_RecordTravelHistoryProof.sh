@@ -0,0 +1,5 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Recording a travel history proof via the TravelHistoryManager contract..."
+cargo run --bin record_travel_history_proof -- --chain base-mainnet --proof-bundle ../../../../circuits/target --prover-toml ../../../../circuits/Prover.toml
@@ -0,0 +1,5 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Running the relayer service of the travel history proofs..."
+cargo run --bin relayer -- --chain base-mainnet --listen 127.0.0.1:8080 --private-key-env PRIVATE_KEY_1 --private-key-env PRIVATE_KEY_2
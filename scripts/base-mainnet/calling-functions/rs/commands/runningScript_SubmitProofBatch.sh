@@ -0,0 +1,5 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Submitting a batch of travel history proofs via the TravelHistoryManager contract..."
+cargo run --bin submit_proof_batch -- --chain base-mainnet --proof-bundles ./data/proof-bundles --vk ../../../../circuits/target/vk
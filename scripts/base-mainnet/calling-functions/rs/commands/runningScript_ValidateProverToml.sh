@@ -0,0 +1,2 @@
+echo "Validating the Prover.toml against the ABI of the compiled circuit..."
+cargo run --bin validate_prover_toml -- --circuit ../../../../circuits/target/travel_history.json --prover-toml ../../../../circuits/Prover.toml
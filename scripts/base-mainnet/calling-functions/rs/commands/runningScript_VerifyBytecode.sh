@@ -0,0 +1,5 @@
+echo "Load the environment variables from the .env file..."
+source .env
+
+echo "Verifying the deployed bytecode of the contracts of the deployment manifest against the artifacts..."
+cargo run --bin verify_bytecode -- --chain base-mainnet --artifacts ./artifacts/0910
@@ -0,0 +1,2 @@
+echo "Verifying a travel history proof via the TravelHistoryProofVerifier deployed on a local Anvil..."
+cargo run --bin verify_proof -- --proof-bundle ../../../../circuits/target
@@ -0,0 +1,187 @@
+// @dev - Alloy
+use alloy::{
+    network::AnyNetwork, // @dev - icl. AnyNetwork for Base Mainnet / Celo Mainnet
+    providers::ProviderBuilder,
+    signers::local::PrivateKeySigner,
+};
+
+use travel_history::{
+    artifact::{ContractArtifact, TRAVEL_HISTORY_MANAGER_ARTIFACT_PATH},
+    batch::{self, BatchOutcome, BatchResult, DEFAULT_BATCH_RESULTS_PATH},
+    chain::ChainProfile,
+    contracts::TravelHistoryManager,
+    nullifier::{NullifierRecord, NullifierRegistry, DEFAULT_NULLIFIER_REGISTRY_PATH},
+    proof_store::{ProofStore, ProofSubmission, DEFAULT_PROOF_STORE_PATH},
+    vk::{deployed_verifier_fingerprint, VkFingerprint},
+};
+
+use clap::Parser;
+use dotenv::dotenv;
+use std::collections::HashSet;
+use std::env;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Submit a batch of travel history proofs via TravelHistoryManager#recordTravelHistoryProof() from a single signer.
+/// Each proof bundle is validated before the submission, and the result of each proof (= tx hash or decoded revert) is written into a result file.
+#[derive(Parser, Debug)]
+struct Args {
+    /// The chain profile (base-mainnet, celo-mainnet or local-anvil)
+    #[arg(long, default_value = "base-mainnet")]
+    chain: String,
+
+    /// The directory of the proof bundles (= a sub-directory with `proof` + `public_inputs` (+ Prover.toml) per proof)
+    #[arg(long)]
+    proof_bundles: PathBuf,
+
+    /// The vk of the circuit build, which the proofs are made with (checked once against the deployed verifier)
+    #[arg(long)]
+    vk: Option<PathBuf>,
+
+    /// Send all the transactions first, then await their receipts (instead of awaiting each receipt before sending the next one)
+    #[arg(long)]
+    pipelined: bool,
+
+    /// Only validate the proof bundles without submitting them
+    #[arg(long)]
+    dry_run: bool,
+
+    /// The env var of the private key of the traveler
+    #[arg(long, default_value = "PRIVATE_KEY_1")]
+    private_key_env: String,
+
+    /// The directory of the per-proof result files (= <results_dir>/<bundle>.json)
+    #[arg(long, default_value = DEFAULT_BATCH_RESULTS_PATH)]
+    results_dir: PathBuf,
+
+    /// The JSON file of the local nullifier registry
+    #[arg(long, default_value = DEFAULT_NULLIFIER_REGISTRY_PATH)]
+    registry: PathBuf,
+
+    /// The directory of the local proof store
+    #[arg(long, default_value = DEFAULT_PROOF_STORE_PATH)]
+    proof_store: PathBuf,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_SubmitProofBatch.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+
+    // 1. Fetch values from env
+    let chain = ChainProfile::from_name(&args.chain)?;
+    let rpc_url = chain.rpc_url()?;
+    let contract_address = chain.contract_address("TRAVEL_HISTORY_MANAGER")?;
+    let private_key = env::var(&args.private_key_env).map_err(|_| eyre::eyre!("Set {} in your .env", args.private_key_env))?;
+    println!("✅ chain: {}", chain);
+    println!("✅ contract_address: {:?}", contract_address);
+
+    let signer: PrivateKeySigner = private_key.parse()?;
+    let traveler = signer.address();
+    println!("✅ traveler: {:?}", traveler);
+
+    let provider = ProviderBuilder::new()
+        .with_gas_estimation()
+        .network::<AnyNetwork>()
+        .wallet(signer)
+        .connect_http(rpc_url);
+    let travel_history_manager = TravelHistoryManager::new(contract_address, &provider);
+    let artifact = ContractArtifact::load(TRAVEL_HISTORY_MANAGER_ARTIFACT_PATH)?;
+
+    // 2. Pre-submission vk check (= the proofs must be made with the same vk as the deployed verifier)
+    match args.vk.as_ref() {
+        Some(vk) => {
+            let vk = VkFingerprint::load_vk(vk)?;
+            let (honk_verifier, verifier) = deployed_verifier_fingerprint(&travel_history_manager).await?;
+            let comparison = vk.compare(&verifier);
+            comparison.ensure_match().map_err(|e| eyre::eyre!("HonkVerifier at {:?}: {}", honk_verifier, e))?;
+            println!("✅ vk fingerprint (matches the deployed verifier): {:?}", comparison.vk_fingerprint);
+        }
+        None => println!("⚠️ No vk is given, so the vk of the proofs is not checked against the deployed verifier"),
+    }
+
+    // 3. Validate each proof bundle (= publicInputs, nullifier unused, not already recorded, dry-run)
+    let mut registry = NullifierRegistry::load(&args.registry)?;
+    let bundle_dirs = batch::discover_bundles(&args.proof_bundles)?;
+    println!("✅ {} proof bundle(s) are found in {}", bundle_dirs.len(), args.proof_bundles.display());
+
+    let mut results = Vec::new();
+    let mut validated_bundles = Vec::new();
+    let mut batch_nullifiers = HashSet::new();
+    for dir in bundle_dirs.iter() {
+        match batch::validate_bundle(&travel_history_manager, &artifact, &registry, chain.name, traveler, dir, &mut batch_nullifiers).await {
+            Ok(validated) => {
+                println!("✅ {}: {}", dir.display(), validated.public_inputs);
+                validated_bundles.push(validated);
+            }
+            Err(e) => {
+                println!("❌ {}: {}", dir.display(), e);
+                results.push(BatchResult::invalid(dir, e.to_string()));
+            }
+        }
+    }
+    if args.dry_run {
+        println!("✅ Dry-run: {} of {} proof bundle(s) are valid", validated_bundles.len(), bundle_dirs.len());
+        return Ok(());
+    }
+
+    // 4. Submit the valid proofs from the traveler
+    println!(
+        "🔄 Calling the TravelHistoryManager#recordTravelHistoryProof() for {} proof(s) ({}) ...",
+        validated_bundles.len(),
+        if args.pipelined { "pipelined" } else { "sequential" }
+    );
+    results.extend(batch::submit_batch(&travel_history_manager, &artifact, traveler, &validated_bundles, args.pipelined).await?);
+    results.sort_by(|a, b| a.bundle.cmp(&b.bundle));
+
+    // 5. Record the nullifiers into the local registry and the proofs into the local proof store, and write the result files
+    let proof_store = ProofStore::open(&args.proof_store)?;
+    let recorded_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    for result in results.iter() {
+        if let (BatchOutcome::Recorded { tx_hash, .. }, Some(public_inputs)) = (&result.outcome, result.public_inputs.as_ref()) {
+            let validated = validated_bundles.iter().find(|validated| validated.dir == result.bundle).expect("a recorded proof is validated");
+            registry.record(
+                traveler,
+                NullifierRecord {
+                    nullifier: public_inputs.nullifier_hash,
+                    chain: chain.name.to_string(),
+                    contract: contract_address,
+                    tx_hash: Some(*tx_hash),
+                    recorded_at,
+                },
+            );
+            let proof_hash = proof_store.put(&validated.bundle.proof, &public_inputs.to_bytes32s())?;
+            proof_store.add_submission(
+                proof_hash,
+                ProofSubmission {
+                    chain: chain.name.to_string(),
+                    contract: contract_address,
+                    traveler,
+                    relayer: None,
+                    tx_hash: Some(*tx_hash),
+                    recorded_at,
+                },
+            )?;
+        }
+
+        let path = result.write(&args.results_dir)?;
+        match &result.outcome {
+            BatchOutcome::Recorded { tx_hash, nonce, .. } => println!("✅ {}: recorded (tx: {}, nonce: {}) -> {}", result.bundle.display(), tx_hash, nonce, path.display()),
+            BatchOutcome::Reverted { reason, .. } => println!("❌ {}: reverted ({}) -> {}", result.bundle.display(), reason, path.display()),
+            BatchOutcome::Invalid { reason } => println!("❌ {}: not submitted ({}) -> {}", result.bundle.display(), reason, path.display()),
+            BatchOutcome::Failed { error, .. } => println!("❌ {}: failed ({}) -> {}", result.bundle.display(), error, path.display()),
+        }
+    }
+    registry.save()?;
+
+    let recorded = results.iter().filter(|result| result.is_recorded()).count();
+    println!("✅ {} of {} proof(s) have been recorded", recorded, results.len());
+    if recorded < results.len() {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use travel_history::{
+    circuit::{CompiledCircuit, DEFAULT_CIRCUIT_PATH},
+    field::field_to_bytes32,
+    inputs::TravelHistoryInputs,
+    prover_toml,
+};
+
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Validate a Prover.toml against the ABI of the compiled travel_history circuit (= target/travel_history.json),
+/// and execute it against the circuit's constraints (i.e. the Merkle root, `country_code < 2000`),
+/// before the external prover (= `nargo execute` / `bb prove`) is invoked.
+#[derive(Parser, Debug)]
+struct Args {
+    /// The compiled circuit (= the output of `nargo compile`)
+    #[arg(long, default_value = DEFAULT_CIRCUIT_PATH)]
+    circuit: PathBuf,
+
+    /// The Prover.toml to be validated
+    #[arg(long, default_value = "../../../../circuits/Prover.toml")]
+    prover_toml: PathBuf,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_ValidateProverToml.sh" command at the root directory (= /rs)
+ */
+fn main() -> eyre::Result<()> {
+    let args = Args::parse();
+
+    let circuit = CompiledCircuit::load(&args.circuit)?;
+    let report = prover_toml::validate_file(&circuit.abi, &args.prover_toml)?;
+    if !report.is_valid() {
+        println!("❌ {} does not match the ABI of {}:\n{}", args.prover_toml.display(), args.circuit.display(), report);
+        std::process::exit(1);
+    }
+    println!("✅ {} matches the ABI of {} ({} parameters)", args.prover_toml.display(), args.circuit.display(), circuit.abi.parameters.len());
+
+    match circuit.execute(&TravelHistoryInputs::load(&args.prover_toml)?) {
+        Ok(execution) => println!("✅ {} satisfies the circuit (nullifier: {})", args.prover_toml.display(), field_to_bytes32(execution.nullifier)),
+        Err(e) => {
+            println!("❌ {} does not satisfy the circuit: {}", args.prover_toml.display(), e);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+// @dev - Alloy
+use alloy::{
+    network::AnyNetwork, // @dev - icl. AnyNetwork for Base Mainnet / Celo Mainnet
+    providers::ProviderBuilder,
+};
+
+use travel_history::{
+    artifact::ContractArtifact,
+    bytecode::{verify_deployed, BytecodeVerdict},
+    chain::ChainProfile,
+    deployment::{artifact_path, ensure_artifacts, DeployedContract, DeploymentManifest, DEFAULT_DEPLOYMENTS_PATH},
+    local_anvil::MOCK_TAG,
+};
+
+use clap::Parser;
+use dotenv::dotenv;
+use std::path::PathBuf;
+
+/// Verify the runtime code (= eth_getCode) of each contract of the deployment manifest against the deployedBytecode of its artifact,
+/// with the metadata hash stripped and the immutables masked, and report match, metadata-only difference or mismatch per address.
+#[derive(Parser, Debug)]
+struct Args {
+    /// The chain profile (base-mainnet, celo-mainnet or local-anvil)
+    #[arg(long, default_value = "base-mainnet")]
+    chain: String,
+
+    /// The directory of the deployment manifests
+    #[arg(long, default_value = DEFAULT_DEPLOYMENTS_PATH)]
+    deployments: PathBuf,
+
+    /// Verify against the artifacts of a directory (i.e. artifacts/0910) instead of the artifact recorded for each contract in the manifest
+    #[arg(long)]
+    artifacts: Option<PathBuf>,
+
+    /// Comma-separated labels or tags of the contracts to verify (i.e. fleet-2025-09). All the contracts of the manifest by default.
+    #[arg(long)]
+    select: Option<String>,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_VerifyBytecode.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    dotenv().ok();  // Loads .env file
+    let args = Args::parse();
+
+    let chain = ChainProfile::from_name(&args.chain)?;
+    let manifest = DeploymentManifest::load(&args.deployments, &chain)?;
+    let contracts: Vec<&DeployedContract> = match args.select.as_deref() {
+        Some(selectors) => {
+            let mut contracts: Vec<&DeployedContract> = Vec::new();
+            for deployed in selectors.split(',').map(str::trim).filter(|selector| !selector.is_empty()).flat_map(|selector| manifest.select(selector)) {
+                if !contracts.iter().any(|contract| contract.label == deployed.label) {
+                    contracts.push(deployed);
+                }
+            }
+            contracts
+        }
+        None => manifest.contracts.iter().collect(),
+    };
+    if contracts.is_empty() {
+        return Err(eyre::eyre!("No contract to verify in {}", manifest.path().display()));
+    }
+    println!("✅ chain: {}", chain);
+
+    // @dev - Check that every artifact exists before querying the chain
+    let artifact_of = |deployed: &DeployedContract| match args.artifacts.as_ref() {
+        Some(artifacts_dir) => artifact_path(artifacts_dir, &deployed.contract),
+        None => deployed.artifact.clone(),
+    };
+    let artifacts: Vec<(&str, PathBuf)> = contracts
+        .iter()
+        .filter(|deployed| !deployed.tags.iter().any(|tag| tag == MOCK_TAG))
+        .map(|deployed| (deployed.contract.as_str(), artifact_of(deployed)))
+        .collect();
+    ensure_artifacts(&artifacts)?;
+
+    let provider = ProviderBuilder::new().network::<AnyNetwork>().connect_http(chain.rpc_url()?);
+    let mut unverified = 0;
+    for deployed in contracts {
+        if deployed.tags.iter().any(|tag| tag == MOCK_TAG) {
+            println!("⏭️ {} ({}) at {:?}: a mock of the local-Anvil mode (= no artifact)", deployed.label, deployed.contract, deployed.address);
+            continue;
+        }
+        let artifact_path = artifact_of(deployed);
+        let artifact = ContractArtifact::load(&artifact_path)?;
+        let verification = verify_deployed(&provider, deployed, &artifact).await?;
+        let icon = match verification.verdict {
+            BytecodeVerdict::Match => "✅",
+            BytecodeVerdict::MetadataOnly => "🟡",
+            BytecodeVerdict::Mismatch { .. } | BytecodeVerdict::NoCode => "❌",
+        };
+        println!("{} {} ({}) at {:?}: {} ({})", icon, verification.label, verification.contract, verification.address, verification.verdict, artifact_path.display());
+        if !verification.verdict.is_verified() {
+            unverified += 1;
+        }
+    }
+
+    if unverified > 0 {
+        return Err(eyre::eyre!("{} contract(s) do not match their artifacts", unverified));
+    }
+    Ok(())
+}
@@ -0,0 +1,83 @@
+// @dev - Alloy
+use alloy::{
+    providers::ProviderBuilder,
+    signers::local::PrivateKeySigner,
+};
+use alloy_node_bindings::Anvil;
+
+use travel_history::{
+    artifact::{HONK_VERIFIER_ARTIFACT_PATH, TRAVEL_HISTORY_PROOF_VERIFIER_ARTIFACT_PATH},
+    local_verifier::LocalVerifier,
+    proof_bundle::ProofBundle,
+};
+
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Verify a travel history proof offline: spawn Anvil, deploy the TravelHistoryProofVerifier (HonkVerifier) from the compiled artifacts
+/// and call verifyTravelHistoryProof(proof, publicInputs), before broadcasting the proof to a mainnet.
+#[derive(Parser, Debug)]
+struct Args {
+    /// The directory of the proof bundle (= `proof` + `public_inputs`, i.e. circuits/target)
+    #[arg(long)]
+    proof_bundle: PathBuf,
+
+    /// The artifact of the HonkVerifier
+    #[arg(long, default_value = HONK_VERIFIER_ARTIFACT_PATH)]
+    honk_verifier_artifact: PathBuf,
+
+    /// The artifact of the TravelHistoryProofVerifier
+    #[arg(long, default_value = TRAVEL_HISTORY_PROOF_VERIFIER_ARTIFACT_PATH)]
+    verifier_artifact: PathBuf,
+
+    /// Print the result as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+/**
+ * @dev - Run this script with the "sh ./commands/runningScript_VerifyProof.sh" command at the root directory (= /rs)
+ */
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let args = Args::parse();
+
+    // 1. Load the proof bundle and the artifacts
+    let proof_bundle = ProofBundle::load(&args.proof_bundle)?;
+    let (honk_verifier_artifact, verifier_artifact) = LocalVerifier::load_artifacts(&args.honk_verifier_artifact, &args.verifier_artifact)?;
+
+    // 2. Start Anvil (local test network). The HonkVerifier exceeds the contract size limit (EIP-170), so the limit is disabled.
+    let anvil = Anvil::new()
+        .arg("--disable-code-size-limit")
+        .try_spawn()
+        .map_err(|e| eyre::eyre!("Failed to spawn Anvil (install it via `foundryup`): {}", e))?;
+    println!("✅ Anvil running at: {}", anvil.endpoint());
+
+    // Create a signer using one of Anvil's default private keys
+    let signer: PrivateKeySigner = anvil.keys()[0].clone().into();
+    let provider = ProviderBuilder::new()
+        .with_gas_estimation()
+        .wallet(signer)
+        .connect_http(anvil.endpoint_url());
+
+    // 3. Deploy the HonkVerifier and the TravelHistoryProofVerifier(HonkVerifier)
+    let local_verifier = LocalVerifier::deploy(&provider, honk_verifier_artifact, &verifier_artifact).await?;
+    println!("✅ HonkVerifier deployed at: {:?}", local_verifier.honk_verifier);
+    println!("✅ TravelHistoryProofVerifier deployed at: {:?}", local_verifier.travel_history_proof_verifier);
+
+    // 4. Call the TravelHistoryProofVerifier#verifyTravelHistoryProof()
+    println!("🔄 Calling the TravelHistoryProofVerifier#verifyTravelHistoryProof() ...");
+    let report = local_verifier.verify(&provider, proof_bundle.proof, proof_bundle.public_inputs).await?;
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else if report.is_valid {
+        println!("✅ The proof is {}", report);
+    } else {
+        println!("❌ The proof is {}", report);
+    }
+
+    if !report.is_valid {
+        std::process::exit(1);
+    }
+    Ok(())
+}
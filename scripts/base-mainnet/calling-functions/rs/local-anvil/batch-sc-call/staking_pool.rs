@@ -18,6 +18,8 @@ sol! {
     "artifacts/0910/StakingPool.sol/StakingPool.json"
 }
 
+use travel_history::artifact_registry::{ArtifactRegistry, DEFAULT_ARTIFACT_REGISTRY_PATH};
+
 use dotenv::dotenv;
 use std::env;
 
@@ -36,22 +38,18 @@ async fn main() -> eyre::Result<()> {
     let provider = ProviderBuilder::new()
         .with_gas_estimation()
         .wallet(signer.clone())
-        .on_http(anvil.endpoint_url());
+        .connect_http(anvil.endpoint_url());
 
     // 3. Deploy ZkJwtProofVerifier first using helper function
     //let zk_jwt_proof_verifier_address = deploy_zk_jwt_proof_verifier(&provider).await?;
     //let zk_jwt_proof_verifier = ZkJwtProofVerifier::new(zk_jwt_proof_verifier_address, &provider);
 
     // 4. Deploy StakingPool with HonkVerifier address as constructor parameter
-    let staking_pool_json = std::fs::read_to_string("artifacts/0910/StakingPool.sol/StakingPool.json")?;
-    //let staking_pool_json = std::fs::read_to_string("artifacts/0901/ReInsurancePool.json")?;
-    let staking_pool_artifact: serde_json::Value = serde_json::from_str(&staking_pool_json)?;
-    let bytecode_hex = staking_pool_artifact["bytecode"]["object"]
-        .as_str()
-        .ok_or_else(|| eyre::eyre!("Failed to get StakingPool bytecode"))?;
+    // @dev - The artifact of the latest version in the artifact registry (= artifacts/registry.toml)
+    let staking_pool_artifact = ArtifactRegistry::load(DEFAULT_ARTIFACT_REGISTRY_PATH)?.latest("StakingPool")?;
 
     // Append constructor parameter (HonkVerifier address) to bytecode
-    let mut deploy_bytecode = Bytes::from_hex(bytecode_hex)?.to_vec();
+    let mut deploy_bytecode = staking_pool_artifact.bytecode.to_vec();
     //let mut constructor_arg = [0u8; 32];
     //constructor_arg[12..].copy_from_slice(zk_jwt_proof_verifier_address.as_slice());
     //zk_deploy_bytecode.extend_from_slice(&constructor_arg);
@@ -66,8 +64,8 @@ async fn main() -> eyre::Result<()> {
     // 7. Call the StakingPool contract (expecting it to fail gracefully)
     println!("🔄 Calling the StakingPool#checkpoint() ...");
     let method_name: String = "checkpoint".to_string();
-    let result = staking_pool.checkpoint(method_name);
-    println!("🔄 Result: {:?}", result);
+    let receipt = staking_pool.checkpoint(method_name).send().await?.get_receipt().await?;
+    println!("✅ Transaction receipt: {:?}", receipt);
 
     Ok(())
 }
@@ -0,0 +1,105 @@
+// @dev - Alloy
+use alloy::{
+    hex::FromHex,
+    json_abi::JsonAbi,
+    primitives::{keccak256, Bytes, B256},
+    sol_types::decode_revert_reason,
+};
+
+use std::path::{Path, PathBuf};
+
+/// @dev - The path of the artifact of the TravelHistoryManager (= out/TravelHistoryManager.sol of `forge build`)
+pub const TRAVEL_HISTORY_MANAGER_ARTIFACT_PATH: &str = "artifacts/0910/TravelHistoryManager.sol/TravelHistoryManager.json";
+
+/// @dev - The default path of the artifact of the TravelHistoryProofVerifier (= out/TravelHistoryProofVerifier.sol of `forge build`)
+pub const TRAVEL_HISTORY_PROOF_VERIFIER_ARTIFACT_PATH: &str = "artifacts/0910/TravelHistoryProofVerifier.sol/TravelHistoryProofVerifier.json";
+
+/// @dev - The default path of the artifact of the HonkVerifier (= out/plonk_vk.sol of `forge build`)
+pub const HONK_VERIFIER_ARTIFACT_PATH: &str = "artifacts/0910/plonk_vk.sol/HonkVerifier.json";
+
+/**
+ * @notice - A compiled contract artifact of Foundry (= out/<File>.sol/<Contract>.json)
+ */
+#[derive(Debug, Clone)]
+pub struct ContractArtifact {
+    pub path: PathBuf,
+    pub abi: JsonAbi,
+    pub bytecode: Bytes,
+    pub deployed_bytecode: Bytes,
+    pub immutable_references: Vec<(usize, usize)>, // @dev - The (start, length) of the immutables in the deployed bytecode, which are filled in by the constructor
+}
+
+impl ContractArtifact {
+    pub fn load<P: AsRef<Path>>(path: P) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| eyre::eyre!("Failed to read the artifact {} (run `forge build` and copy it from ./out): {}", path.display(), e))?;
+        let artifact: serde_json::Value = serde_json::from_str(&json)?;
+
+        let abi: JsonAbi = serde_json::from_value(artifact["abi"].clone())
+            .map_err(|e| eyre::eyre!("Invalid ABI in {}: {}", path.display(), e))?;
+        let bytecode = bytecode_of(&artifact["bytecode"], path, "bytecode")?;
+        let deployed_bytecode = bytecode_of(&artifact["deployedBytecode"], path, "deployedBytecode")?;
+        let immutable_references = immutable_references_of(&artifact["deployedBytecode"]["immutableReferences"]);
+
+        Ok(Self { path: path.to_path_buf(), abi, bytecode, deployed_bytecode, immutable_references })
+    }
+
+    /**
+     * @dev - The creation code with the ABI-encoded constructor arguments appended (= the input of a deployment transaction)
+     */
+    pub fn deploy_code(&self, constructor_args: &[u8]) -> Bytes {
+        let mut deploy_code = self.bytecode.to_vec();
+        deploy_code.extend_from_slice(constructor_args);
+        Bytes::from(deploy_code)
+    }
+
+    /**
+     * @dev - The keccak256 of the creation bytecode, which identifies the build of this artifact
+     */
+    pub fn bytecode_hash(&self) -> B256 {
+        keccak256(&self.bytecode)
+    }
+
+    /**
+     * @notice - Decode the revert data of a call (= Error(string), Panic(uint256) or a custom error of this ABI)
+     */
+    pub fn decode_revert(&self, data: &[u8]) -> String {
+        if let Some(reason) = decode_revert_reason(data) {
+            return reason;
+        }
+        if data.len() >= 4 {
+            if let Some(error) = self.abi.errors().find(|error| error.selector().as_slice() == &data[..4]) {
+                return error.signature();
+            }
+        }
+        format!("unknown revert data: {}", Bytes::copy_from_slice(data))
+    }
+}
+
+fn bytecode_of(value: &serde_json::Value, path: &Path, name: &str) -> eyre::Result<Bytes> {
+    let object = value["object"]
+        .as_str()
+        .or_else(|| value.as_str())
+        .ok_or_else(|| eyre::eyre!("Failed to get the {} of {}", name, path.display()))?;
+    if object.contains("__$") {
+        return Err(eyre::eyre!("The {} of {} has unlinked libraries, which are not supported", name, path.display()));
+    }
+    Ok(Bytes::from_hex(object)?)
+}
+
+/**
+ * @dev - The immutable references of Foundry (= {"<AST id>": [{"start": ..., "length": ...}]}), sorted by their start
+ */
+fn immutable_references_of(value: &serde_json::Value) -> Vec<(usize, usize)> {
+    let mut references: Vec<(usize, usize)> = value
+        .as_object()
+        .into_iter()
+        .flat_map(|references| references.values())
+        .filter_map(|references| references.as_array())
+        .flatten()
+        .filter_map(|reference| Some((reference["start"].as_u64()? as usize, reference["length"].as_u64()? as usize)))
+        .collect();
+    references.sort();
+    references
+}
@@ -0,0 +1,311 @@
+// @dev - Alloy
+use alloy::{
+    json_abi::{Function, JsonAbi, StateMutability},
+    network::{Network, TransactionBuilder},
+    primitives::{keccak256, Address, Bytes},
+    providers::Provider,
+    sol_types::SolValue,
+};
+
+use crate::artifact::ContractArtifact;
+use crate::deployment::artifact_path;
+
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+// @notice - The registry of the artifact versions (= artifacts/<date tag>/<Contract>.sol/<Contract>.json), which are indexed by their date tag (i.e. "0910") and the `version()` string of their contracts (i.e. "0.90.120")
+// @dev - The `sol!` bindings are still generated from a single artifact version at compile time. The registry is for the commands which load an ABI at runtime.
+
+/// @dev - The default root directory of the artifact versions, relative to the root directory (= /rs)
+pub const DEFAULT_ARTIFACT_REGISTRY_PATH: &str = "artifacts";
+
+/// @dev - The index of the artifact versions in the root directory, which maps a date tag to the `version()` string of its contracts
+pub const ARTIFACT_REGISTRY_INDEX: &str = "registry.toml";
+
+/**
+ * @notice - An artifact version (= a directory of the registry)
+ * @dev - `version` is None for a directory which is not in the index yet.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ArtifactVersion {
+    pub tag: String,
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RegistryIndex {
+    #[serde(default)]
+    versions: Vec<ArtifactVersion>,
+}
+
+/**
+ * @notice - The artifact registry
+ * @dev - The versions are in the order of the index (= oldest first), followed by the directories which are not in the index (= in the order of their date tags).
+ */
+#[derive(Debug, Clone)]
+pub struct ArtifactRegistry {
+    pub root: PathBuf,
+    pub versions: Vec<ArtifactVersion>,
+}
+
+impl ArtifactRegistry {
+    pub fn load<P: AsRef<Path>>(root: P) -> eyre::Result<Self> {
+        let root = root.as_ref();
+        let index_path = root.join(ARTIFACT_REGISTRY_INDEX);
+        let index: RegistryIndex = if index_path.exists() {
+            let toml = std::fs::read_to_string(&index_path).map_err(|e| eyre::eyre!("Failed to read {}: {}", index_path.display(), e))?;
+            toml::from_str(&toml).map_err(|e| eyre::eyre!("Invalid artifact registry {}: {}", index_path.display(), e))?
+        } else {
+            RegistryIndex::default()
+        };
+
+        let mut versions = index.versions;
+        let mut unindexed_tags: Vec<String> = std::fs::read_dir(root)
+            .map_err(|e| eyre::eyre!("Failed to read the artifact registry {}: {}", root.display(), e))?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .filter(|tag| !versions.iter().any(|version| &version.tag == tag))
+            .collect();
+        unindexed_tags.sort();
+        versions.extend(unindexed_tags.into_iter().map(|tag| ArtifactVersion { tag, version: None }));
+        Ok(Self { root: root.to_path_buf(), versions })
+    }
+
+    pub fn path_of(&self, tag: &str, contract: &str) -> PathBuf {
+        artifact_path(self.root.join(tag), contract)
+    }
+
+    /**
+     * @notice - The artifact versions which contain a contract, oldest first
+     */
+    pub fn versions_of(&self, contract: &str) -> Vec<&ArtifactVersion> {
+        self.versions.iter().filter(|version| self.path_of(&version.tag, contract).exists()).collect()
+    }
+
+    pub fn artifact(&self, tag: &str, contract: &str) -> eyre::Result<ContractArtifact> {
+        if !self.versions.iter().any(|version| version.tag == tag) {
+            return Err(eyre::eyre!("No artifact version is tagged with \"{}\" in {}", tag, self.root.display()));
+        }
+        ContractArtifact::load(self.path_of(tag, contract))
+    }
+
+    /**
+     * @notice - The artifact of the latest version which contains a contract
+     */
+    pub fn latest(&self, contract: &str) -> eyre::Result<ContractArtifact> {
+        let version = self
+            .versions_of(contract)
+            .pop()
+            .ok_or_else(|| eyre::eyre!("No artifact of {} in {}", contract, self.root.display()))?;
+        self.artifact(&version.tag, contract)
+    }
+
+    /**
+     * @notice - Pick the artifact of the contract deployed at an address: the versions whose `version()` string is the one of the contract,
+     *           preferring the one whose deployed bytecode is the code at the address (= several builds can share a `version()` string)
+     */
+    pub async fn resolve<P: Provider<N>, N: Network>(&self, provider: &P, contract: &str, address: Address) -> eyre::Result<ResolvedArtifact> {
+        let deployed_version = deployed_version(provider, address).await?;
+        let candidates: Vec<&ArtifactVersion> = self
+            .versions_of(contract)
+            .into_iter()
+            .filter(|version| version.version.as_deref() == Some(deployed_version.as_str()))
+            .collect();
+        if candidates.is_empty() {
+            return Err(eyre::eyre!(
+                "No artifact of {} has the version \"{}\" of {} (add it to {})",
+                contract,
+                deployed_version,
+                address,
+                self.root.join(ARTIFACT_REGISTRY_INDEX).display()
+            ));
+        }
+
+        let code_hash = keccak256(provider.get_code_at(address).await?);
+        let mut resolved: Option<ResolvedArtifact> = None;
+        for version in candidates.iter().rev() {
+            let artifact = self.artifact(&version.tag, contract)?;
+            let exact_bytecode = keccak256(&artifact.deployed_bytecode) == code_hash;
+            if exact_bytecode || resolved.is_none() {
+                resolved = Some(ResolvedArtifact { tag: version.tag.clone(), version: deployed_version.clone(), exact_bytecode, artifact });
+            }
+            if exact_bytecode {
+                break;
+            }
+        }
+        Ok(resolved.expect("the candidates are not empty"))
+    }
+}
+
+/**
+ * @notice - The artifact of a deployed contract
+ * @dev - `exact_bytecode` is false if the code at the address is not the deployed bytecode of the artifact (i.e. immutables or a different build of the same version).
+ */
+#[derive(Debug, Clone)]
+pub struct ResolvedArtifact {
+    pub tag: String,
+    pub version: String,
+    pub exact_bytecode: bool,
+    pub artifact: ContractArtifact,
+}
+
+/**
+ * @dev - The `version()` string of a deployed contract
+ */
+pub async fn deployed_version<P: Provider<N>, N: Network>(provider: &P, address: Address) -> eyre::Result<String> {
+    let call = N::TransactionRequest::default().with_to(address).with_input(Bytes::copy_from_slice(&keccak256("version()")[..4]));
+    let output = provider.call(call).await.map_err(|e| eyre::eyre!("Failed to call version() of {}: {}", address, e))?;
+    String::abi_decode(&output).map_err(|e| eyre::eyre!("Invalid version() of {}: {}", address, e))
+}
+
+/**
+ * @notice - The differences of the ABIs of two versions of a contract (= functions, events and errors)
+ * @dev - An item whose name has a single signature in both ABIs, which differ, is a changed signature. Otherwise, the signatures are added or removed (= overloads).
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AbiDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<(String, String)>,
+}
+
+impl AbiDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for AbiDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "  (no differences)");
+        }
+        for signature in self.added.iter() {
+            writeln!(f, "  + {}", signature)?;
+        }
+        for signature in self.removed.iter() {
+            writeln!(f, "  - {}", signature)?;
+        }
+        for (old, new) in self.changed.iter() {
+            writeln!(f, "  ~ {} -> {}", old, new)?;
+        }
+        Ok(())
+    }
+}
+
+/**
+ * @notice - Diff the ABIs of two versions of a contract
+ */
+pub fn diff_abis(old: &JsonAbi, new: &JsonAbi) -> AbiDiff {
+    let mut diff = AbiDiff::default();
+    diff_items(&mut diff, signatures(old), signatures(new));
+    diff
+}
+
+/**
+ * @dev - The full signatures of the items of an ABI by their kind and name (i.e. ("function", "version") -> {"function version() view returns (string)"})
+ */
+fn signatures(abi: &JsonAbi) -> BTreeMap<(&'static str, String), BTreeSet<String>> {
+    let mut signatures: BTreeMap<(&'static str, String), BTreeSet<String>> = BTreeMap::new();
+    for function in abi.functions() {
+        signatures.entry(("function", function.name.clone())).or_default().insert(function_signature(function));
+    }
+    for event in abi.events() {
+        signatures.entry(("event", event.name.clone())).or_default().insert(format!("event {}", event.signature()));
+    }
+    for error in abi.errors() {
+        signatures.entry(("error", error.name.clone())).or_default().insert(format!("error {}", error.signature()));
+    }
+    if let Some(constructor) = &abi.constructor {
+        let inputs: Vec<String> = constructor.inputs.iter().map(|input| input.ty.clone()).collect();
+        signatures.entry(("constructor", String::new())).or_default().insert(format!("constructor({})", inputs.join(",")));
+    }
+    signatures
+}
+
+/**
+ * @dev - The signature of a function with its state mutability and its output types, without the parameter names (= a renamed parameter is not a change)
+ */
+fn function_signature(function: &Function) -> String {
+    let mut signature = format!("function {}", function.signature());
+    match function.state_mutability {
+        StateMutability::NonPayable => {}
+        state_mutability => signature.push_str(&format!(" {}", state_mutability.as_str().unwrap_or_default())),
+    }
+    if !function.outputs.is_empty() {
+        let outputs: Vec<String> = function.outputs.iter().map(|output| output.selector_type().into_owned()).collect();
+        signature.push_str(&format!(" returns ({})", outputs.join(",")));
+    }
+    signature
+}
+
+fn diff_items(
+    diff: &mut AbiDiff,
+    old: BTreeMap<(&'static str, String), BTreeSet<String>>,
+    new: BTreeMap<(&'static str, String), BTreeSet<String>>,
+) {
+    let empty = BTreeSet::new();
+    let keys: BTreeSet<&(&'static str, String)> = old.keys().chain(new.keys()).collect();
+    for key in keys {
+        let old_signatures = old.get(key).unwrap_or(&empty);
+        let new_signatures = new.get(key).unwrap_or(&empty);
+        let removed: Vec<&String> = old_signatures.difference(new_signatures).collect();
+        let added: Vec<&String> = new_signatures.difference(old_signatures).collect();
+        if old_signatures.len() == 1 && new_signatures.len() == 1 && removed.len() == 1 {
+            diff.changed.push((removed[0].clone(), added[0].clone()));
+        } else {
+            diff.removed.extend(removed.into_iter().cloned());
+            diff.added.extend(added.into_iter().cloned());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn abi(items: &[&str]) -> JsonAbi {
+        JsonAbi::parse(items.iter().copied()).unwrap()
+    }
+
+    #[test]
+    fn diffs_the_abis_of_two_versions() {
+        let old = abi(&[
+            "function version() view returns (string)",
+            "function checkpoint(string methodName) returns (bool)",
+            "function travelers(address) view returns (bool)",
+            "event TravelHistoryProofRecorded(address indexed traveler)",
+        ]);
+        let new = abi(&[
+            "function version() view returns (string)",
+            "function checkpoint(string methodName, uint256 timestamp) returns (bool)",
+            "function registerAsTraveler()",
+            "event TravelHistoryProofRecorded(address indexed traveler)",
+            "error NotATraveler(address traveler)",
+        ]);
+
+        let diff = diff_abis(&old, &new);
+        assert_eq!(diff.added, vec!["error NotATraveler(address)", "function registerAsTraveler()"]);
+        assert_eq!(diff.removed, vec!["function travelers(address) view returns (bool)"]);
+        assert!(diff_abis(&old, &abi(&["function version() view returns (string v)"])).changed.is_empty());
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(
+            diff.changed[0],
+            ("function checkpoint(string) returns (bool)".to_string(), "function checkpoint(string,uint256) returns (bool)".to_string())
+        );
+        assert!(diff_abis(&new, &new).is_empty());
+    }
+
+    #[test]
+    fn loads_the_registry_of_the_artifacts() {
+        let registry = ArtifactRegistry::load(DEFAULT_ARTIFACT_REGISTRY_PATH).unwrap();
+        let version = registry.versions.iter().find(|version| version.tag == "0910").unwrap();
+        assert_eq!(version.version.as_deref(), Some("0.90.120"));
+        assert_eq!(registry.versions_of("TravelHistoryManager").len(), registry.versions.len());
+        assert!(registry.latest("TravelHistoryManager").unwrap().abi.function("version").is_some());
+        assert!(registry.artifact("0101", "TravelHistoryManager").is_err());
+    }
+}
@@ -0,0 +1,322 @@
+// @dev - Alloy
+use alloy::{
+    eips::BlockId,
+    network::{Network, ReceiptResponse},
+    primitives::{keccak256, Address, FixedBytes, TxHash, B256},
+    providers::{PendingTransactionBuilder, Provider},
+};
+
+use crate::artifact::ContractArtifact;
+use crate::contracts::TravelHistoryManager::TravelHistoryManagerInstance;
+use crate::nullifier::{check_nullifier, NullifierRegistry};
+use crate::proof_bundle::ProofBundle;
+use crate::public_inputs::TravelPublicInputs;
+
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// @dev - The default directory of the per-proof result files of a batch submission (relative to the root directory (= /rs))
+pub const DEFAULT_BATCH_RESULTS_PATH: &str = "data/batch-results";
+
+/**
+ * @notice - A proof bundle which has passed the pre-submission validation of a batch
+ */
+#[derive(Debug, Clone)]
+pub struct ValidatedBundle {
+    pub dir: PathBuf,
+    pub bundle: ProofBundle,
+    pub public_inputs: TravelPublicInputs,
+}
+
+/**
+ * @notice - The outcome of a proof in a batch submission
+ * @dev - `invalid` means that the proof was rejected by the validation and was not submitted (= no nonce was consumed).
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum BatchOutcome {
+    Recorded { tx_hash: TxHash, nonce: u64, block_number: Option<u64>, gas_used: u64 },
+    Reverted { tx_hash: Option<TxHash>, nonce: Option<u64>, reason: String },
+    Invalid { reason: String },
+    Failed { nonce: Option<u64>, error: String },
+}
+
+/**
+ * @notice - The result of a proof in a batch submission, which is written into <results_dir>/<bundle>.json
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchResult {
+    pub bundle: PathBuf,
+    pub proof_hash: Option<B256>,
+    pub public_inputs: Option<TravelPublicInputs>,
+    #[serde(flatten)]
+    pub outcome: BatchOutcome,
+}
+
+impl BatchResult {
+    /**
+     * @dev - The result of a proof bundle which was rejected by the validation. The proof hash and the publicInputs are filled if the bundle can be loaded.
+     */
+    pub fn invalid(dir: &Path, reason: String) -> Self {
+        let bundle = ProofBundle::load(dir).ok();
+        Self {
+            bundle: dir.to_path_buf(),
+            proof_hash: bundle.as_ref().map(|bundle| keccak256(&bundle.proof)),
+            public_inputs: bundle.and_then(|bundle| bundle.travel_public_inputs().ok()),
+            outcome: BatchOutcome::Invalid { reason },
+        }
+    }
+
+    fn of(validated: &ValidatedBundle, outcome: BatchOutcome) -> Self {
+        Self {
+            bundle: validated.dir.clone(),
+            proof_hash: Some(keccak256(&validated.bundle.proof)),
+            public_inputs: Some(validated.public_inputs),
+            outcome,
+        }
+    }
+
+    pub fn is_recorded(&self) -> bool {
+        matches!(self.outcome, BatchOutcome::Recorded { .. })
+    }
+
+    /**
+     * @dev - Write the result into <results_dir>/<the directory name of the bundle>.json
+     */
+    pub fn write<P: AsRef<Path>>(&self, results_dir: P) -> eyre::Result<PathBuf> {
+        let results_dir = results_dir.as_ref();
+        std::fs::create_dir_all(results_dir)?;
+        let name = self.bundle.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_else(|| "bundle".to_string());
+        let path = results_dir.join(format!("{}.json", name));
+        std::fs::write(&path, serde_json::to_string_pretty(self)?).map_err(|e| eyre::eyre!("Failed to write {}: {}", path.display(), e))?;
+        Ok(path)
+    }
+}
+
+/**
+ * @notice - The proof bundles in a directory (= the sub-directories which contain a `proof` file, sorted by name)
+ * @dev - If the directory itself is a proof bundle, it is the only bundle of the batch.
+ */
+pub fn discover_bundles<P: AsRef<Path>>(dir: P) -> eyre::Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+    if dir.join("proof").is_file() {
+        return Ok(vec![dir.to_path_buf()]);
+    }
+    let mut bundles: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map_err(|e| eyre::eyre!("Failed to read the directory of the proof bundles {}: {}", dir.display(), e))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.join("proof").is_file())
+        .collect();
+    bundles.sort();
+    Ok(bundles)
+}
+
+/**
+ * @notice - The offline validation of a proof bundle: the number of the publicInputs, their ranges,
+ *           and the publicInputs against the witness (if the bundle contains a Prover.toml)
+ */
+pub fn check_bundle(bundle: &ProofBundle) -> eyre::Result<TravelPublicInputs> {
+    match bundle.prover_toml.as_ref() {
+        Some(prover_toml) => bundle.check_against_witness(prover_toml),
+        None => bundle.travel_public_inputs(),
+    }
+}
+
+/**
+ * @notice - Validate a proof bundle before it is submitted in a batch: the offline checks, the nullifier is unused (on-chain, in the local registry and in the batch),
+ *           the proof is not already recorded, and a dry-run (= eth_call) of recordTravelHistoryProof() does not revert.
+ * @param batch_nullifiers - The nullifiers of the bundles of the batch which have already been validated
+ */
+pub async fn validate_bundle<P: Provider<N>, N: Network>(
+    travel_history_manager: &TravelHistoryManagerInstance<P, N>,
+    artifact: &ContractArtifact,
+    registry: &NullifierRegistry,
+    chain: &str,
+    traveler: Address,
+    dir: &Path,
+    batch_nullifiers: &mut HashSet<FixedBytes<32>>,
+) -> eyre::Result<ValidatedBundle> {
+    let bundle = ProofBundle::load(dir)?;
+    let public_inputs = check_bundle(&bundle)?;
+
+    let nullifier = public_inputs.nullifier_hash;
+    if batch_nullifiers.contains(&nullifier) {
+        return Err(eyre::eyre!("The nullifier {} is used by another proof of the batch", nullifier));
+    }
+    check_nullifier(travel_history_manager, registry, chain, traveler, nullifier).await?.ensure_unspent()?;
+
+    if travel_history_manager.isTravelHistoryProofRecorded(traveler, bundle.proof.clone()).call().await? {
+        return Err(eyre::eyre!("The proof is already recorded on-chain"));
+    }
+
+    let call = travel_history_manager.recordTravelHistoryProof(bundle.proof.clone(), public_inputs.to_bytes32s()).from(traveler);
+    if let Err(e) = call.call().await {
+        return Err(match e.as_revert_data() {
+            Some(data) => eyre::eyre!("The dry-run reverted: {}", artifact.decode_revert(&data)),
+            None => e.into(),
+        });
+    }
+
+    batch_nullifiers.insert(nullifier);
+    Ok(ValidatedBundle { dir: dir.to_path_buf(), bundle, public_inputs })
+}
+
+/**
+ * @notice - Submit the validated proofs via recordTravelHistoryProof() from a single signer (= the traveler), with an explicit nonce per transaction.
+ * @dev - Sequential: each transaction is sent after the receipt of the previous one. Pipelined: all the transactions are sent first, then their receipts are awaited.
+ * @dev - The nonce starts from the pending nonce of the traveler, and is only consumed by a transaction which has been accepted by the node,
+ *        so a proof which fails to be sent (i.e. the gas estimation reverts) does not leave a nonce gap behind.
+ */
+pub async fn submit_batch<P: Provider<N>, N: Network>(
+    travel_history_manager: &TravelHistoryManagerInstance<P, N>,
+    artifact: &ContractArtifact,
+    traveler: Address,
+    validated_bundles: &[ValidatedBundle],
+    pipelined: bool,
+) -> eyre::Result<Vec<BatchResult>> {
+    let provider = travel_history_manager.provider();
+    let mut nonce = provider.get_transaction_count(traveler).pending().await?;
+
+    let mut results = Vec::new();
+    let mut pending_transactions = Vec::new();
+    for validated in validated_bundles.iter() {
+        let call = travel_history_manager
+            .recordTravelHistoryProof(validated.bundle.proof.clone(), validated.public_inputs.to_bytes32s())
+            .from(traveler)
+            .nonce(nonce);
+        let pending_transaction = match call.send().await {
+            Ok(pending_transaction) => pending_transaction,
+            Err(e) => {
+                let outcome = match e.as_revert_data() {
+                    Some(data) => BatchOutcome::Reverted { tx_hash: None, nonce: None, reason: artifact.decode_revert(&data) },
+                    None => BatchOutcome::Failed { nonce: None, error: e.to_string() },
+                };
+                results.push((validated, outcome));
+                continue;
+            }
+        };
+        let tx_nonce = nonce;
+        nonce += 1;
+
+        if pipelined {
+            pending_transactions.push((validated, tx_nonce, pending_transaction));
+        } else {
+            let outcome = await_outcome(travel_history_manager, artifact, traveler, validated, tx_nonce, pending_transaction).await;
+            results.push((validated, outcome));
+        }
+    }
+    for (validated, tx_nonce, pending_transaction) in pending_transactions {
+        let outcome = await_outcome(travel_history_manager, artifact, traveler, validated, tx_nonce, pending_transaction).await;
+        results.push((validated, outcome));
+    }
+
+    // @dev - In the order of the bundles (= the pipelined results are appended after the failed sends)
+    let mut results: Vec<BatchResult> = results.into_iter().map(|(validated, outcome)| BatchResult::of(validated, outcome)).collect();
+    results.sort_by(|a, b| a.bundle.cmp(&b.bundle));
+    Ok(results)
+}
+
+/**
+ * @dev - Await the receipt of a transaction. The reason of a reverted transaction is decoded by replaying it (= eth_call) on the state before its block.
+ */
+async fn await_outcome<P: Provider<N>, N: Network>(
+    travel_history_manager: &TravelHistoryManagerInstance<P, N>,
+    artifact: &ContractArtifact,
+    traveler: Address,
+    validated: &ValidatedBundle,
+    nonce: u64,
+    pending_transaction: PendingTransactionBuilder<N>,
+) -> BatchOutcome {
+    let tx_hash = *pending_transaction.tx_hash();
+    let receipt = match pending_transaction.get_receipt().await {
+        Ok(receipt) => receipt,
+        Err(e) => return BatchOutcome::Failed { nonce: Some(nonce), error: format!("{} (tx: {})", e, tx_hash) },
+    };
+    if receipt.status() {
+        return BatchOutcome::Recorded { tx_hash, nonce, block_number: receipt.block_number(), gas_used: receipt.gas_used() };
+    }
+
+    let mut replay = travel_history_manager
+        .recordTravelHistoryProof(validated.bundle.proof.clone(), validated.public_inputs.to_bytes32s())
+        .from(traveler);
+    if let Some(block_number) = receipt.block_number() {
+        replay = replay.block(BlockId::number(block_number.saturating_sub(1)));
+    }
+    let reason = match replay.call().await {
+        Err(e) => match e.as_revert_data() {
+            Some(data) => artifact.decode_revert(&data),
+            None => "reverted on-chain".to_string(),
+        },
+        Ok(_) => "reverted on-chain (the replay on the state before its block succeeded)".to_string(),
+    };
+    BatchOutcome::Reverted { tx_hash: Some(tx_hash), nonce: Some(nonce), reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_vectors;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("travel_history_batch_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_bundle(dir: &Path, public_inputs: &TravelPublicInputs, prover_toml: Option<String>) {
+        std::fs::create_dir_all(dir).unwrap();
+        std::fs::write(dir.join("proof"), [7u8; 64]).unwrap();
+        std::fs::write(dir.join("public_inputs"), public_inputs.to_bytes32s().concat()).unwrap();
+        if let Some(prover_toml) = prover_toml {
+            std::fs::write(dir.join("Prover.toml"), prover_toml).unwrap();
+        }
+    }
+
+    #[test]
+    fn discovers_the_bundles_of_a_directory() {
+        let dir = temp_dir("discover");
+        let inputs = &test_vectors::generate(1, 0).unwrap()[0].inputs;
+        write_bundle(&dir.join("stay_002"), &TravelPublicInputs::from_inputs(inputs), None);
+        write_bundle(&dir.join("stay_001"), &TravelPublicInputs::from_inputs(inputs), None);
+        std::fs::create_dir_all(dir.join("not_a_bundle")).unwrap();
+
+        assert_eq!(discover_bundles(&dir).unwrap(), vec![dir.join("stay_001"), dir.join("stay_002")]);
+        assert_eq!(discover_bundles(dir.join("stay_001")).unwrap(), vec![dir.join("stay_001")]);
+    }
+
+    #[test]
+    fn checks_a_bundle_offline() {
+        let dir = temp_dir("check");
+        let cases = test_vectors::generate(1, 0).unwrap();
+        let public_inputs = TravelPublicInputs::from_inputs(&cases[0].inputs);
+        write_bundle(&dir.join("valid"), &public_inputs, Some(cases[0].inputs.to_prover_toml()));
+        write_bundle(&dir.join("mismatch"), &public_inputs, Some(cases[2].inputs.to_prover_toml()));
+        write_bundle(&dir.join("truncated"), &public_inputs, None);
+        std::fs::write(dir.join("truncated").join("public_inputs"), public_inputs.to_bytes32s()[..4].concat()).unwrap();
+
+        let check = |name: &str| check_bundle(&ProofBundle::load(dir.join(name)).unwrap());
+        assert_eq!(check("valid").unwrap(), public_inputs);
+        let error = check("mismatch").unwrap_err().to_string();
+        assert!(error.contains("do not match"), "{}", error);
+        let error = check("truncated").unwrap_err().to_string();
+        assert!(error.contains("Invalid number of public inputs"), "{}", error);
+    }
+
+    #[test]
+    fn writes_a_result_file_per_proof() {
+        let dir = temp_dir("results");
+        let public_inputs = TravelPublicInputs::from_inputs(&test_vectors::generate(1, 0).unwrap()[0].inputs);
+        write_bundle(&dir.join("bundles").join("stay_001"), &public_inputs, None);
+        let result = BatchResult::invalid(&dir.join("bundles").join("stay_001"), "The proof is already recorded on-chain".to_string());
+        let path = result.write(dir.join("results")).unwrap();
+        assert_eq!(path, dir.join("results").join("stay_001.json"));
+
+        let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(json["status"], "invalid");
+        assert_eq!(json["reason"], "The proof is already recorded on-chain");
+        assert_eq!(json["proof_hash"], keccak256([7u8; 64]).to_string());
+        assert_eq!(json["public_inputs"]["country_code"], 1999);
+    }
+}
@@ -0,0 +1,206 @@
+// @dev - Alloy
+use alloy::{
+    network::Network,
+    primitives::{Address, Bytes},
+    providers::Provider,
+};
+
+use crate::artifact::ContractArtifact;
+use crate::deployment::DeployedContract;
+
+use std::fmt;
+
+// @notice - The verification of the runtime code of a deployed contract (= eth_getCode) against the deployedBytecode of its artifact
+// @dev - The metadata hash (= the CBOR-encoded trailer of solc, i.e. a2 64 "ipfs" <34 bytes> 64 "solc" <3 bytes> 00 33) is stripped, and the immutables are masked with zeros,
+//        since they are filled in by the constructor.
+
+/**
+ * @notice - The result of a bytecode verification
+ * @dev - `MetadataOnly` is the same code built from different sources of the metadata (i.e. comments, file paths or compiler settings which do not change the code).
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BytecodeVerdict {
+    Match,
+    MetadataOnly,
+    Mismatch { deployed_size: usize, artifact_size: usize, first_difference: Option<usize> },
+    NoCode,
+}
+
+impl BytecodeVerdict {
+    /**
+     * @dev - Whether or not the deployed code is the code of the artifact (= a metadata-only difference is accepted)
+     */
+    pub fn is_verified(&self) -> bool {
+        matches!(self, BytecodeVerdict::Match | BytecodeVerdict::MetadataOnly)
+    }
+}
+
+impl fmt::Display for BytecodeVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BytecodeVerdict::Match => write!(f, "match"),
+            BytecodeVerdict::MetadataOnly => write!(f, "metadata-only difference"),
+            BytecodeVerdict::Mismatch { deployed_size, artifact_size, first_difference } => {
+                write!(f, "mismatch ({} bytes deployed, {} bytes in the artifact", deployed_size, artifact_size)?;
+                if let Some(offset) = first_difference {
+                    write!(f, ", first difference at byte {}", offset)?;
+                }
+                write!(f, ")")
+            }
+            BytecodeVerdict::NoCode => write!(f, "no code at the address"),
+        }
+    }
+}
+
+/**
+ * @notice - Split a runtime code into the code and the metadata trailer of solc (= the last 2 bytes are the length of the CBOR map before them)
+ * @dev - The metadata is empty if the code has no trailer (i.e. `bytecode_hash = "none"` + `cbor_metadata = false`).
+ */
+pub fn split_metadata(code: &[u8]) -> (&[u8], &[u8]) {
+    if code.len() < 2 {
+        return (code, &[]);
+    }
+    let cbor_length = u16::from_be_bytes([code[code.len() - 2], code[code.len() - 1]]) as usize;
+    if cbor_length == 0 || cbor_length + 2 > code.len() {
+        return (code, &[]);
+    }
+    let metadata_start = code.len() - 2 - cbor_length;
+    // @dev - The CBOR trailer is a map (= 0xa0..=0xbf)
+    match code[metadata_start] {
+        0xa0..=0xbf => code.split_at(metadata_start),
+        _ => (code, &[]),
+    }
+}
+
+/**
+ * @dev - Mask the immutables of a runtime code with zeros
+ */
+pub fn mask_immutables(code: &[u8], immutable_references: &[(usize, usize)]) -> Vec<u8> {
+    let mut masked = code.to_vec();
+    for (start, length) in immutable_references.iter() {
+        if let Some(immutable) = masked.get_mut(*start..start + length) {
+            immutable.fill(0);
+        }
+    }
+    masked
+}
+
+/**
+ * @notice - Compare a deployed runtime code with the deployedBytecode of an artifact
+ */
+pub fn compare(deployed_code: &[u8], artifact: &ContractArtifact) -> BytecodeVerdict {
+    if deployed_code.is_empty() {
+        return BytecodeVerdict::NoCode;
+    }
+    let deployed_code = mask_immutables(deployed_code, &artifact.immutable_references);
+    let artifact_code = mask_immutables(&artifact.deployed_bytecode, &artifact.immutable_references);
+    if deployed_code == artifact_code {
+        return BytecodeVerdict::Match;
+    }
+
+    let (deployed_body, _) = split_metadata(&deployed_code);
+    let (artifact_body, _) = split_metadata(&artifact_code);
+    if deployed_body == artifact_body {
+        return BytecodeVerdict::MetadataOnly;
+    }
+    BytecodeVerdict::Mismatch {
+        deployed_size: deployed_code.len(),
+        artifact_size: artifact_code.len(),
+        first_difference: deployed_body.iter().zip(artifact_body.iter()).position(|(deployed, artifact)| deployed != artifact),
+    }
+}
+
+/**
+ * @notice - The bytecode verification of a contract of the deployment manifest
+ */
+#[derive(Debug, Clone)]
+pub struct BytecodeVerification {
+    pub label: String,
+    pub contract: String,
+    pub address: Address,
+    pub verdict: BytecodeVerdict,
+}
+
+/**
+ * @notice - Verify the runtime code of a contract of the deployment manifest against an artifact
+ */
+pub async fn verify_deployed<P: Provider<N>, N: Network>(
+    provider: &P,
+    deployed: &DeployedContract,
+    artifact: &ContractArtifact,
+) -> eyre::Result<BytecodeVerification> {
+    let deployed_code: Bytes = provider.get_code_at(deployed.address).await?;
+    Ok(BytecodeVerification {
+        label: deployed.label.clone(),
+        contract: deployed.contract.clone(),
+        address: deployed.address,
+        verdict: compare(&deployed_code, artifact),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deployment::deploy_contract;
+    use alloy::{
+        hex,
+        json_abi::JsonAbi,
+        network::AnyNetwork,
+        providers::ProviderBuilder,
+        signers::local::PrivateKeySigner,
+    };
+    use alloy_node_bindings::Anvil;
+
+    /// @dev - A runtime code with an immutable at [2, 4) and a metadata trailer of 5 bytes (= a1 + 4 bytes) + its length (= 00 05)
+    fn artifact_of(deployed_bytecode: &[u8]) -> ContractArtifact {
+        ContractArtifact {
+            path: "Test.json".into(),
+            abi: JsonAbi::default(),
+            bytecode: Bytes::new(),
+            deployed_bytecode: Bytes::copy_from_slice(deployed_bytecode),
+            immutable_references: vec![(2, 2)],
+        }
+    }
+
+    #[test]
+    fn splits_the_metadata_trailer() {
+        let code = hex!("6080 0000 00 a1 01020304 0005");
+        assert_eq!(split_metadata(&code), (&code[..5], &code[5..]));
+        assert_eq!(split_metadata(&hex!("6080 0000")), (&hex!("6080 0000")[..], &[][..]));
+        assert_eq!(split_metadata(&hex!("6080 00ff")), (&hex!("6080 00ff")[..], &[][..]));
+    }
+
+    #[test]
+    fn compares_the_deployed_code_with_the_artifact() {
+        let artifact = artifact_of(&hex!("6080 0000 00 a1 01020304 0005"));
+        assert_eq!(compare(&hex!("6080 0000 00 a1 01020304 0005"), &artifact), BytecodeVerdict::Match);
+        assert_eq!(compare(&hex!("6080 abcd 00 a1 01020304 0005"), &artifact), BytecodeVerdict::Match);
+        assert_eq!(compare(&hex!("6080 abcd 00 a1 09090909 0005"), &artifact), BytecodeVerdict::MetadataOnly);
+        assert_eq!(
+            compare(&hex!("6081 abcd 00 a1 01020304 0005"), &artifact),
+            BytecodeVerdict::Mismatch { deployed_size: 12, artifact_size: 12, first_difference: Some(1) }
+        );
+        assert_eq!(compare(&[], &artifact), BytecodeVerdict::NoCode);
+        assert!(BytecodeVerdict::MetadataOnly.is_verified());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires Anvil (install it via `foundryup`)"]
+    async fn verifies_a_contract_deployed_on_anvil() {
+        let anvil = Anvil::new().spawn();
+        let signer: PrivateKeySigner = anvil.keys()[0].clone().into();
+        let provider = ProviderBuilder::new().network::<AnyNetwork>().wallet(signer).connect_http(anvil.endpoint_url());
+
+        let artifact = ContractArtifact::load(crate::artifact::TRAVEL_HISTORY_MANAGER_ARTIFACT_PATH).unwrap();
+        let constructor_args = Bytes::from(Address::repeat_byte(1).into_word().to_vec());
+        let deployed = deploy_contract(&provider, &artifact, "TravelHistoryManager", "TravelHistoryManager", constructor_args).await.unwrap();
+        assert_eq!(verify_deployed(&provider, &deployed, &artifact).await.unwrap().verdict, BytecodeVerdict::Match);
+
+        let staking_pool = ContractArtifact::load("artifacts/0910/StakingPool.sol/StakingPool.json").unwrap();
+        assert!(!verify_deployed(&provider, &deployed, &staking_pool).await.unwrap().verdict.is_verified());
+
+        let mut missing = deployed;
+        missing.address = Address::repeat_byte(2);
+        assert_eq!(verify_deployed(&provider, &missing, &artifact).await.unwrap().verdict, BytecodeVerdict::NoCode);
+    }
+}
@@ -0,0 +1,86 @@
+// @dev - Alloy
+use alloy::{primitives::Address, transports::http::reqwest::Url};
+
+use crate::deployment::{DeploymentManifest, DEFAULT_DEPLOYMENTS_PATH};
+
+use std::env;
+use std::fmt;
+
+/**
+ * @notice - A chain profile, which tells which RPC and which contract addresses (in .env) are used for a chain.
+ * @dev - The contract addresses are read from the deployment manifest of the chain (= deployments/<chain>.json),
+ *        or from the `<CONTRACT>_ON_<ENV_SUFFIX>` env vars (i.e. TRAVEL_HISTORY_MANAGER_ON_BASE_MAINNET).
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainProfile {
+    pub name: &'static str,
+    pub chain_id: u64,
+    pub rpc_env: &'static str,
+    pub env_suffix: &'static str,
+}
+
+pub const BASE_MAINNET: ChainProfile = ChainProfile {
+    name: "base-mainnet",
+    chain_id: 8453,
+    rpc_env: "BASE_MAINNET_RPC",
+    env_suffix: "BASE_MAINNET",
+};
+
+pub const CELO_MAINNET: ChainProfile = ChainProfile {
+    name: "celo-mainnet",
+    chain_id: 42220,
+    rpc_env: "CELO_MAINNET_RPC",
+    env_suffix: "CELO_MAINNET",
+};
+
+pub const LOCAL_ANVIL: ChainProfile = ChainProfile {
+    name: "local-anvil",
+    chain_id: 31337,
+    rpc_env: "LOCAL_ANVIL_RPC",
+    env_suffix: "LOCAL_ANVIL",
+};
+
+pub const CHAIN_PROFILES: [ChainProfile; 3] = [BASE_MAINNET, CELO_MAINNET, LOCAL_ANVIL];
+
+impl ChainProfile {
+    pub fn from_name(name: &str) -> eyre::Result<Self> {
+        CHAIN_PROFILES
+            .iter()
+            .find(|profile| profile.name == name)
+            .copied()
+            .ok_or_else(|| eyre::eyre!("Unknown chain profile: {} (expected one of: base-mainnet, celo-mainnet, local-anvil)", name))
+    }
+
+    /**
+     * @dev - The RPC URL of this chain, which is read from .env (i.e. BASE_MAINNET_RPC)
+     */
+    pub fn rpc_url(&self) -> eyre::Result<Url> {
+        let rpc_url = match env::var(self.rpc_env) {
+            Ok(rpc_url) => rpc_url,
+            Err(_) if *self == LOCAL_ANVIL => "http://127.0.0.1:8545".to_string(),
+            Err(_) => return Err(eyre::eyre!("Set {} in your .env", self.rpc_env)),
+        };
+        Ok(rpc_url.parse()?)
+    }
+
+    /**
+     * @dev - The address of a contract on this chain, which is read from the deployment manifest (= deployments/<chain>.json),
+     *        or from .env (i.e. TRAVEL_HISTORY_MANAGER_ON_BASE_MAINNET) if the contract is not in the manifest
+     * @param contract - The contract name in the env var format (i.e. "TRAVEL_HISTORY_MANAGER")
+     */
+    pub fn contract_address(&self, contract: &str) -> eyre::Result<Address> {
+        if let Some(address) = DeploymentManifest::load(DEFAULT_DEPLOYMENTS_PATH, self)?.address_of(contract) {
+            return Ok(address);
+        }
+        let env_var = format!("{}_ON_{}", contract, self.env_suffix);
+        let address = env::var(&env_var)
+            .map_err(|_| eyre::eyre!("Deploy {} via the `deploy` command, or set {} in your .env", contract, env_var))?;
+        address.trim().parse().map_err(|e| eyre::eyre!("Invalid address in {}: {}", env_var, e))
+    }
+}
+
+impl fmt::Display for ChainProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (chain id: {})", self.name, self.chain_id)
+    }
+}
@@ -0,0 +1,363 @@
+// @dev - Alloy
+use alloy::{
+    network::{AnyNetwork, ReceiptResponse},
+    primitives::{Address, TxHash},
+    providers::{DynProvider, Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+    transports::http::reqwest::Url,
+};
+
+use crate::chain::ChainProfile;
+use crate::contracts::{StakingPool, TravelBookingManager, TravelHistoryManager};
+use crate::deployment::{batch_targets, to_env_name, DeployedContract, DeploymentManifest, DEFAULT_DEPLOYMENTS_PATH};
+use crate::local_anvil::LOCAL_CONTRACTS;
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// @notice - The checkpoint plan of the mainnet batch runs (= base-mainnet/batch-sc-call): each signer calls checkpoint("checkpoint") of each target,
+//           and the run report which a real run, a rehearsal on a mainnet fork (= fork.rs) and the local-Anvil mode all write.
+
+/// @dev - The default directory of the run reports, relative to the root directory (= /rs)
+pub const DEFAULT_RUN_REPORTS_PATH: &str = "data/run-reports";
+
+/// @dev - The number of signers of the mainnet batch runs (= PRIVATE_KEY_1..5)
+pub const RUN_SIGNERS: usize = 5;
+
+/**
+ * @notice - A signer of a run
+ * @dev - `Impersonated` signers (= `anvil_impersonateAccount`) are only available on a fork, when the private key of an account is unavailable.
+ */
+#[derive(Debug, Clone)]
+pub enum RunSigner {
+    Key(PrivateKeySigner),
+    Impersonated(Address),
+}
+
+impl RunSigner {
+    pub fn address(&self) -> Address {
+        match self {
+            RunSigner::Key(signer) => signer.address(),
+            RunSigner::Impersonated(address) => *address,
+        }
+    }
+
+    /**
+     * @dev - The signers of the mainnet batch runs: PRIVATE_KEY_<i> (i = 1..5), or SIGNER_ADDRESS_<i> when the private key is unset (= impersonated on a fork)
+     */
+    pub fn from_env(allow_impersonation: bool) -> eyre::Result<Vec<Self>> {
+        let mut signers: Vec<Self> = Vec::new();
+        for i in 1..=RUN_SIGNERS {
+            let private_key = std::env::var(format!("PRIVATE_KEY_{}", i)).unwrap_or_default();
+            if !private_key.trim().is_empty() {
+                let signer: PrivateKeySigner = private_key.trim().parse().map_err(|e| eyre::eyre!("Invalid PRIVATE_KEY_{}: {}", i, e))?;
+                signers.push(RunSigner::Key(signer));
+                continue;
+            }
+            let address = std::env::var(format!("SIGNER_ADDRESS_{}", i)).unwrap_or_default();
+            match (address.trim().is_empty(), allow_impersonation) {
+                (true, _) => continue,
+                (false, true) => {
+                    signers.push(RunSigner::Impersonated(address.trim().parse().map_err(|e| eyre::eyre!("Invalid SIGNER_ADDRESS_{}: {}", i, e))?));
+                }
+                (false, false) => return Err(eyre::eyre!("Set PRIVATE_KEY_{} in your .env (= SIGNER_ADDRESS_{} is only impersonated on a fork)", i, i)),
+            }
+        }
+        if signers.is_empty() {
+            return Err(eyre::eyre!("Set PRIVATE_KEY_1..{} (or SIGNER_ADDRESS_1..{} on a fork) in your .env", RUN_SIGNERS, RUN_SIGNERS));
+        }
+        Ok(signers)
+    }
+
+    fn provider(&self, rpc_url: &Url) -> DynProvider<AnyNetwork> {
+        match self {
+            RunSigner::Key(signer) => {
+                ProviderBuilder::new().with_gas_estimation().network::<AnyNetwork>().wallet(signer.clone()).connect_http(rpc_url.clone()).erased()
+            }
+            // @dev - eth_sendTransaction from the impersonated account (= signed by Anvil)
+            RunSigner::Impersonated(_) => ProviderBuilder::new().with_gas_estimation().network::<AnyNetwork>().connect_http(rpc_url.clone()).erased(),
+        }
+    }
+}
+
+/**
+ * @notice - A target of a run
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunTarget {
+    pub label: String,
+    pub contract: String,
+    pub address: Address,
+}
+
+impl From<&DeployedContract> for RunTarget {
+    fn from(deployed: &DeployedContract) -> Self {
+        Self { label: deployed.label.clone(), contract: deployed.contract.clone(), address: deployed.address }
+    }
+}
+
+/**
+ * @notice - The targets of the mainnet batch runs: the contracts of the deployment manifest with some labels or tags (= `selectors`),
+ *           or the targets of each batch-run script (= `<CONTRACT>_TARGETS` or `<CONTRACT>_ON_<CHAIN>_LIST`)
+ */
+pub fn run_targets(chain: &ChainProfile, selectors: Option<&str>) -> eyre::Result<Vec<RunTarget>> {
+    let manifest = DeploymentManifest::load(DEFAULT_DEPLOYMENTS_PATH, chain)?;
+    let mut targets: Vec<RunTarget> = Vec::new();
+    match selectors {
+        Some(selectors) => {
+            for selector in selectors.split(',').map(str::trim).filter(|selector| !selector.is_empty()) {
+                let selected = manifest.select(selector);
+                if selected.is_empty() {
+                    return Err(eyre::eyre!("No contract labelled or tagged {} in {}", selector, manifest.path().display()));
+                }
+                targets.extend(selected.into_iter().filter(|deployed| LOCAL_CONTRACTS.contains(&deployed.contract.as_str())).map(RunTarget::from));
+            }
+        }
+        None => {
+            for contract in LOCAL_CONTRACTS.iter() {
+                for address in batch_targets(chain, &to_env_name(contract))? {
+                    let label = match manifest.contracts.iter().find(|deployed| deployed.address == address) {
+                        Some(deployed) => deployed.label.clone(),
+                        None => format!("{}@{}", contract, address),
+                    };
+                    targets.push(RunTarget { label, contract: contract.to_string(), address });
+                }
+            }
+        }
+    }
+    Ok(dedup_targets(targets))
+}
+
+/**
+ * @dev - Drop the targets whose address is already targeted (i.e. selected by both a label and a tag), keeping the first one of each address
+ */
+fn dedup_targets(mut targets: Vec<RunTarget>) -> Vec<RunTarget> {
+    let mut addresses: HashSet<Address> = HashSet::new();
+    targets.retain(|target| addresses.insert(target.address));
+    targets
+}
+
+/**
+ * @notice - The result of a checkpoint() call of the checkpoint plan
+ * @dev - `tx_hash` is None when the transaction could not be sent (i.e. the gas estimation has been reverted), w/ the reason in `error`.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckpointResult {
+    pub signer: Address,
+    pub label: String,
+    pub contract: String,
+    pub address: Address,
+    pub tx_hash: Option<TxHash>,
+    pub gas_used: Option<u64>,
+    pub success: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl fmt::Display for CheckpointResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {:?} -> {}#checkpoint()", if self.success { "✅" } else { "❌" }, self.signer, self.label)?;
+        match (&self.tx_hash, &self.error) {
+            (Some(tx_hash), _) => write!(f, " (tx: {}, gas used: {})", tx_hash, self.gas_used.unwrap_or_default()),
+            (None, Some(error)) => write!(f, " (not sent: {})", error),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/**
+ * @notice - Run the checkpoint plan: each signer calls checkpoint("checkpoint") of each target, and the transactions are sent for real.
+ * @dev - A call which fails is recorded in its result, and the plan goes on.
+ */
+pub async fn run_checkpoint_plan(rpc_url: &Url, signers: &[RunSigner], targets: &[RunTarget]) -> eyre::Result<Vec<CheckpointResult>> {
+    let mut results: Vec<CheckpointResult> = Vec::new();
+    for signer in signers.iter() {
+        let provider = signer.provider(rpc_url);
+        for target in targets.iter() {
+            let mut result = CheckpointResult {
+                signer: signer.address(),
+                label: target.label.clone(),
+                contract: target.contract.clone(),
+                address: target.address,
+                tx_hash: None,
+                gas_used: None,
+                success: false,
+                error: None,
+            };
+            match send_checkpoint(&provider, signer.address(), target).await {
+                Ok((tx_hash, gas_used, success)) => {
+                    result.tx_hash = Some(tx_hash);
+                    result.gas_used = Some(gas_used);
+                    result.success = success;
+                }
+                Err(e) => result.error = Some(e.to_string()),
+            }
+            results.push(result);
+        }
+    }
+    Ok(results)
+}
+
+async fn send_checkpoint(provider: &DynProvider<AnyNetwork>, from: Address, target: &RunTarget) -> eyre::Result<(TxHash, u64, bool)> {
+    let method_name = "checkpoint".to_string();
+    let pending_tx = match target.contract.as_str() {
+        "TravelHistoryManager" => TravelHistoryManager::new(target.address, provider).checkpoint(method_name).from(from).send().await?,
+        "TravelBookingManager" => TravelBookingManager::new(target.address, provider).checkpoint(method_name).from(from).send().await?,
+        "StakingPool" => StakingPool::new(target.address, provider).checkpoint(method_name).from(from).send().await?,
+        other => return Err(eyre::eyre!("{} has no checkpoint()", other)),
+    };
+    let receipt = pending_tx.get_receipt().await?;
+    Ok((receipt.transaction_hash(), receipt.gas_used(), receipt.status()))
+}
+
+/**
+ * @notice - Where a run has been rehearsed (= a local Anvil fork of the chain, see fork.rs)
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForkOrigin {
+    pub source: String, // @dev - The RPC (i.e. a local archive node) or the cached state file the fork has been started from
+    pub block_number: u64,
+    pub impersonated: Vec<Address>,
+}
+
+/**
+ * @notice - The report of a run of the checkpoint plan (= data/run-reports/<chain>[-fork-<block>]-<started_at>.json)
+ * @dev - A rehearsal on a fork writes the same report as a real run, with `fork` set.
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunReport {
+    pub chain: String,
+    pub chain_id: u64,
+    pub block_number: u64, // @dev - The block number when the run has started
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fork: Option<ForkOrigin>,
+    pub started_at: u64, // @dev - UNIX timestamp
+    pub finished_at: u64,
+    pub targets: Vec<RunTarget>,
+    pub results: Vec<CheckpointResult>,
+}
+
+impl RunReport {
+    /**
+     * @notice - Run the checkpoint plan against an RPC (= the chain, or a fork of it), and report it
+     */
+    pub async fn run(
+        chain: &ChainProfile,
+        rpc_url: &Url,
+        signers: &[RunSigner],
+        targets: &[RunTarget],
+        fork: Option<ForkOrigin>,
+    ) -> eyre::Result<Self> {
+        let provider = ProviderBuilder::new().network::<AnyNetwork>().connect_http(rpc_url.clone());
+        let chain_id = provider.get_chain_id().await?;
+        if chain_id != chain.chain_id {
+            return Err(eyre::eyre!("{} is an RPC of the chain id {}, not {}", rpc_url, chain_id, chain));
+        }
+        let block_number = provider.get_block_number().await?;
+        let started_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let results = run_checkpoint_plan(rpc_url, signers, targets).await?;
+        Ok(Self {
+            chain: chain.name.to_string(),
+            chain_id,
+            block_number,
+            fork,
+            started_at,
+            finished_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            targets: targets.to_vec(),
+            results,
+        })
+    }
+
+    pub fn failures(&self) -> usize {
+        self.results.iter().filter(|result| !result.success).count()
+    }
+
+    pub fn gas_used(&self) -> u64 {
+        self.results.iter().filter_map(|result| result.gas_used).sum()
+    }
+
+    pub fn file_name(&self) -> String {
+        match &self.fork {
+            Some(fork) => format!("{}-fork-{}-{}.json", self.chain, fork.block_number, self.started_at),
+            None => format!("{}-{}.json", self.chain, self.started_at),
+        }
+    }
+
+    pub fn write<P: AsRef<Path>>(&self, reports_dir: P) -> eyre::Result<PathBuf> {
+        std::fs::create_dir_all(reports_dir.as_ref())?;
+        let path = reports_dir.as_ref().join(self.file_name());
+        std::fs::write(&path, serde_json::to_string_pretty(self)? + "\n").map_err(|e| eyre::eyre!("Failed to write {}: {}", path.display(), e))?;
+        Ok(path)
+    }
+}
+
+impl fmt::Display for RunReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.fork {
+            Some(fork) => writeln!(f, "🔁 Rehearsal of {} (chain id: {}) on a fork at block {} ({})", self.chain, self.chain_id, fork.block_number, fork.source)?,
+            None => writeln!(f, "🚀 Run on {} (chain id: {}) from block {}", self.chain, self.chain_id, self.block_number)?,
+        }
+        for result in self.results.iter() {
+            writeln!(f, "{}", result)?;
+        }
+        write!(
+            f,
+            "{} of {} checkpoint() calls succeeded ({} signers x {} targets, {} gas used)",
+            self.results.len() - self.failures(),
+            self.results.len(),
+            self.results.len().checked_div(self.targets.len()).unwrap_or_default(),
+            self.targets.len(),
+            self.gas_used()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dedups_the_targets_by_address() {
+        let target = |label: &str, address: u8| RunTarget { label: label.to_string(), contract: "StakingPool".to_string(), address: Address::repeat_byte(address) };
+        let targets = vec![target("pool-a", 1), target("pool-b", 2), target("fleet-2025-09", 1), target("pool-b@tag", 2), target("pool-c", 3)];
+        let labels: Vec<String> = dedup_targets(targets).into_iter().map(|target| target.label).collect();
+        assert_eq!(labels, vec!["pool-a", "pool-b", "pool-c"]);
+    }
+
+    #[test]
+    fn names_and_summarizes_the_run_reports() {
+        let target = RunTarget { label: "StakingPool".to_string(), contract: "StakingPool".to_string(), address: Address::repeat_byte(1) };
+        let result = |success: bool| CheckpointResult {
+            signer: Address::repeat_byte(2),
+            label: target.label.clone(),
+            contract: target.contract.clone(),
+            address: target.address,
+            tx_hash: success.then_some(TxHash::repeat_byte(3)),
+            gas_used: success.then_some(30_000),
+            success,
+            error: (!success).then(|| "execution reverted".to_string()),
+        };
+        let mut report = RunReport {
+            chain: "base-mainnet".to_string(),
+            chain_id: 8453,
+            block_number: 100,
+            fork: None,
+            started_at: 1_700_000_000,
+            finished_at: 1_700_000_010,
+            targets: vec![target.clone()],
+            results: vec![result(true), result(false)],
+        };
+        assert_eq!(report.file_name(), "base-mainnet-1700000000.json");
+        assert_eq!(report.failures(), 1);
+        assert_eq!(report.gas_used(), 30_000);
+        assert!(report.to_string().ends_with("1 of 2 checkpoint() calls succeeded (2 signers x 1 targets, 30000 gas used)"));
+
+        report.fork = Some(ForkOrigin { source: "http://127.0.0.1:8545".to_string(), block_number: 99, impersonated: Vec::new() });
+        assert_eq!(report.file_name(), "base-mainnet-fork-99-1700000000.json");
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(serde_json::from_str::<RunReport>(&json).unwrap().results, report.results);
+    }
+}
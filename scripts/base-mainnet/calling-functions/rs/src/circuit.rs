@@ -0,0 +1,535 @@
+// @dev - Noir / ACVM
+use acvm::{
+    acir::{
+        circuit::{Circuit, Program},
+        native_types::{Witness, WitnessMap, WitnessStack},
+    },
+    brillig_vm::brillig::ForeignCallResult,
+    pwg::{ACVMStatus, OpcodeResolutionError, ResolvedAssertionPayload, ACVM},
+    FieldElement,
+};
+use bn254_blackbox_solver::Bn254BlackBoxSolver;
+
+use crate::inputs::TravelHistoryInputs;
+
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+/// @dev - The default path of the compiled travel_history circuit (= the output of `nargo compile`), relative to the root directory (= /rs)
+pub const DEFAULT_CIRCUIT_PATH: &str = "../../../../circuits/target/travel_history.json";
+
+/**
+ * @notice - The compiled travel_history circuit (= target/travel_history.json), which contains its ACIR bytecode and its ABI.
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct CompiledCircuit {
+    #[serde(default)]
+    pub noir_version: String,
+    pub abi: Abi,
+    #[serde(deserialize_with = "Program::deserialize_program_base64")]
+    pub bytecode: Program<FieldElement>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Abi {
+    pub parameters: Vec<AbiParameter>,
+    pub return_type: Option<AbiReturnType>,
+    #[serde(default)]
+    pub error_types: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AbiParameter {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub typ: AbiType,
+    pub visibility: AbiVisibility,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AbiReturnType {
+    pub abi_type: AbiType,
+    pub visibility: AbiVisibility,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AbiVisibility {
+    Public,
+    Private,
+    Databus,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum AbiType {
+    Field,
+    Boolean,
+    Integer { sign: String, width: u32 },
+    Array { length: u32, #[serde(rename = "type")] typ: Box<AbiType> },
+    String { length: u32 },
+    Struct { path: String, fields: Vec<(String, AbiType)> },
+    Tuple { fields: Vec<AbiType> },
+}
+
+impl AbiType {
+    /**
+     * @dev - The number of field elements (= witnesses) that a value of this type is flattened into
+     */
+    pub fn field_count(&self) -> u32 {
+        match self {
+            AbiType::Field | AbiType::Boolean | AbiType::Integer { .. } => 1,
+            AbiType::Array { length, typ } => length * typ.field_count(),
+            AbiType::String { length } => *length,
+            AbiType::Struct { fields, .. } => fields.iter().map(|(_, typ)| typ.field_count()).sum(),
+            AbiType::Tuple { fields } => fields.iter().map(AbiType::field_count).sum(),
+        }
+    }
+}
+
+/**
+ * @notice - The result of executing the travel_history circuit
+ * @dev - `nullifier` is the return value of the circuit (= pedersen_hash([root, secret]))
+ */
+#[derive(Debug, Clone)]
+pub struct Execution {
+    pub witness: WitnessMap<FieldElement>,
+    pub nullifier: FieldElement,
+}
+
+impl Execution {
+    /**
+     * @dev - Write the witness in the format of `nargo execute` (= target/travel_history.gz), which can be passed to `bb prove -w`
+     */
+    pub fn write_witness<P: AsRef<Path>>(&self, path: P) -> eyre::Result<()> {
+        let witness_stack = WitnessStack::from(self.witness.clone());
+        let bytes = witness_stack.serialize().map_err(|e| eyre::eyre!("Failed to serialize the witness: {}", e))?;
+        std::fs::write(path, bytes)?;
+        Ok(())
+    }
+}
+
+impl CompiledCircuit {
+    pub fn load<P: AsRef<Path>>(path: P) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read(path)
+            .map_err(|e| eyre::eyre!("Failed to read the compiled circuit {} (run `nargo compile` first): {}", path.display(), e))?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+
+    pub fn main(&self) -> &Circuit<FieldElement> {
+        &self.bytecode.functions[0]
+    }
+
+    /**
+     * @notice - Execute the circuit with the given inputs (= `nargo execute` without shelling out to nargo)
+     * @dev - The ABI parameters are flattened into the witnesses 0, 1, 2, ... in the order of the ABI (= the encoding of noirc_abi).
+     */
+    pub fn execute(&self, inputs: &TravelHistoryInputs) -> eyre::Result<Execution> {
+        let initial_witness = self.encode_inputs(&inputs.abi_values())?;
+
+        let circuit = self.main();
+        let blackbox_solver = Bn254BlackBoxSolver;
+        let mut acvm = ACVM::new(
+            &blackbox_solver,
+            &circuit.opcodes,
+            initial_witness,
+            &self.bytecode.unconstrained_functions,
+            &circuit.assert_messages,
+        );
+
+        loop {
+            match acvm.solve() {
+                ACVMStatus::Solved => break,
+                ACVMStatus::InProgress => unreachable!("ACVM#solve() returns only when the execution is paused or finished"),
+                ACVMStatus::Failure(error) => return Err(eyre::eyre!("Failed to execute the circuit: {}", self.describe_error(&error))),
+                // @dev - The circuit has no oracles, so the only foreign calls are `println` (= debugging), which return nothing.
+                ACVMStatus::RequiresForeignCall(_) => acvm.resolve_pending_foreign_call(ForeignCallResult::default()),
+                ACVMStatus::RequiresAcirCall(_) => return Err(eyre::eyre!("ACIR calls (= non-inlined functions) are not supported")),
+            }
+        }
+        let witness = acvm.finalize();
+
+        let return_witness = circuit
+            .return_values
+            .0
+            .first()
+            .ok_or_else(|| eyre::eyre!("The circuit does not return a nullifier"))?;
+        let nullifier = *witness
+            .get(return_witness)
+            .ok_or_else(|| eyre::eyre!("The return value (= nullifier) is not solved"))?;
+
+        Ok(Execution { witness, nullifier })
+    }
+
+    /**
+     * @dev - Encode the inputs (= the flattened value of each ABI parameter) into the initial witness map
+     */
+    pub fn encode_inputs(&self, values: &HashMap<String, Vec<FieldElement>>) -> eyre::Result<WitnessMap<FieldElement>> {
+        let mut witness_map = WitnessMap::new();
+        let mut index = 0u32;
+        for parameter in self.abi.parameters.iter() {
+            let value = values
+                .get(&parameter.name)
+                .ok_or_else(|| eyre::eyre!("Missing input: {}", parameter.name))?;
+            if value.len() != parameter.typ.field_count() as usize {
+                return Err(eyre::eyre!(
+                    "Invalid input {}: expected {} field(s), got {}",
+                    parameter.name,
+                    parameter.typ.field_count(),
+                    value.len()
+                ));
+            }
+            for field in value.iter() {
+                witness_map.insert(Witness(index), *field);
+                index += 1;
+            }
+        }
+        Ok(witness_map)
+    }
+
+    /**
+     * @dev - Resolve the message of a failed assertion (i.e. "Invalid country code") from the `error_types` of the ABI
+     */
+    fn describe_error(&self, error: &OpcodeResolutionError<FieldElement>) -> String {
+        let payload = match error {
+            OpcodeResolutionError::UnsatisfiedConstrain { payload, .. } => payload,
+            OpcodeResolutionError::BrilligFunctionFailed { payload, .. } => payload,
+            _ => &None,
+        };
+        match payload {
+            Some(ResolvedAssertionPayload::String(message)) => format!("{}: {}", error, message),
+            Some(ResolvedAssertionPayload::Raw(raw)) => {
+                let message = self
+                    .abi
+                    .error_types
+                    .get(&raw.selector.as_u64().to_string())
+                    .and_then(|error_type| error_type["string"].as_str());
+                match message {
+                    Some(message) => format!("{}: {}", error, message),
+                    None => format!("{} (error selector: {})", error, raw.selector.as_u64()),
+                }
+            }
+            None => error.to_string(),
+        }
+    }
+}
+
+/// @dev - The circuit fixture (= the ABI and the ACIR of circuits/src/main.nr, assembled by `tests::fixture`), which the tests execute without nargo
+#[cfg(test)]
+pub(crate) const CIRCUIT_FIXTURE_PATH: &str = "fixtures/circuit/travel_history.json";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_vectors::{self, INVALID_COUNTRY_CODE};
+    use acvm::AcirField;
+
+    fn executes_the_test_vectors_on(circuit: &CompiledCircuit) {
+        for case in test_vectors::generate(1, 0).unwrap().iter().filter(|case| case.should_fail_with.is_none()) {
+            let execution = circuit.execute(&case.inputs).unwrap_or_else(|e| panic!("{}: {}", case.name, e));
+            assert_eq!(execution.nullifier, case.expected_nullifier, "{}", case.name);
+        }
+    }
+
+    fn rejects_the_country_code_2000_on(circuit: &CompiledCircuit) {
+        let cases = test_vectors::generate(1, 0).unwrap();
+        let case = cases.iter().find(|case| case.inputs.country_code == 2000).unwrap();
+        assert_eq!(case.should_fail_with, Some(INVALID_COUNTRY_CODE));
+
+        let error = circuit.execute(&case.inputs).unwrap_err().to_string();
+        assert!(error.contains(INVALID_COUNTRY_CODE), "{}", error);
+    }
+
+    #[test]
+    fn executes_the_test_vectors() {
+        executes_the_test_vectors_on(&CompiledCircuit::load(CIRCUIT_FIXTURE_PATH).unwrap());
+    }
+
+    #[test]
+    fn rejects_the_country_code_2000() {
+        rejects_the_country_code_2000_on(&CompiledCircuit::load(CIRCUIT_FIXTURE_PATH).unwrap());
+    }
+
+    #[test]
+    fn rejects_a_witness_outside_of_the_merkle_tree() {
+        let circuit = CompiledCircuit::load(CIRCUIT_FIXTURE_PATH).unwrap();
+        let mut inputs = test_vectors::generate(1, 0).unwrap()[0].inputs.clone();
+        inputs.secret += FieldElement::one();
+        assert!(circuit.execute(&inputs).unwrap_err().to_string().contains("Failed to execute the circuit"));
+    }
+
+    #[test]
+    #[ignore = "requires the compiled circuit (run `nargo compile` in /circuits)"]
+    fn executes_the_test_vectors_on_the_compiled_circuit() {
+        let circuit = CompiledCircuit::load(DEFAULT_CIRCUIT_PATH).unwrap();
+        executes_the_test_vectors_on(&circuit);
+        rejects_the_country_code_2000_on(&circuit);
+    }
+
+    /**
+     * @dev - The committed fixture is the one assembled here (run w/ UPDATE_CIRCUIT_FIXTURE=1 to rewrite it)
+     */
+    #[test]
+    fn assembles_the_circuit_fixture() {
+        let json = fixture::compiled_circuit_json();
+        if std::env::var("UPDATE_CIRCUIT_FIXTURE").is_ok() {
+            std::fs::create_dir_all(Path::new(CIRCUIT_FIXTURE_PATH).parent().unwrap()).unwrap();
+            std::fs::write(CIRCUIT_FIXTURE_PATH, serde_json::to_string_pretty(&json).unwrap() + "\n").unwrap();
+        }
+        let committed: serde_json::Value = serde_json::from_slice(&std::fs::read(CIRCUIT_FIXTURE_PATH).unwrap()).unwrap();
+        assert_eq!(committed, json);
+    }
+
+    #[test]
+    fn counts_the_fields_of_an_abi_type() {
+        let array = AbiType::Array { length: 2, typ: Box::new(AbiType::Field) };
+        assert_eq!(array.field_count(), 2);
+        assert_eq!(AbiType::Integer { sign: "unsigned".to_string(), width: 64 }.field_count(), 1);
+        assert_eq!(AbiType::Tuple { fields: vec![array, AbiType::String { length: 3 }] }.field_count(), 5);
+    }
+
+    /**
+     * @dev - The ACIR of circuits/src/main.nr, assembled opcode by opcode like `nargo compile` lowers it (= a stand-in for the compiled circuit, since nargo is not available in CI).
+     *        The witnesses 0..=8 are the ABI parameters, the hints (= the scalar limbs, the bits of the index, the comparison w/ 2000) are solved by a div-mod Brillig function,
+     *        and the Pedersen hashes are MultiScalarMul black-box calls w/ the generators of `std::hash::pedersen_hash()`.
+     */
+    mod fixture {
+        use acvm::acir::{
+            brillig::{BinaryFieldOp, BitSize, HeapVector, IntegerBitSize, MemoryAddress, Opcode as BrilligOpcode},
+            circuit::{
+                brillig::{BrilligBytecode, BrilligFunctionId, BrilligInputs, BrilligOutputs},
+                opcodes::{BlackBoxFuncCall, FunctionInput},
+                AssertionPayload, Circuit, Opcode, OpcodeLocation, Program, PublicInputs,
+            },
+            native_types::{Expression, Witness},
+        };
+        use acvm::{AcirField, FieldElement};
+        use alloy::primitives::keccak256;
+        use bn254_blackbox_solver::derive_generators;
+
+        use crate::inputs::HASH_PATH_LENGTH;
+        use crate::test_vectors::INVALID_COUNTRY_CODE;
+
+        use std::collections::BTreeSet;
+
+        const ROOT: Witness = Witness(0);
+        const HASH_PATH: Witness = Witness(1);
+        const INDEX: Witness = Witness(3);
+        const SECRET: Witness = Witness(4);
+        const COUNTRY_CODE: Witness = Witness(6);
+        const ENTER_DATE: Witness = Witness(7);
+        const EXIT_DATE: Witness = Witness(8);
+        const PARAMETERS: u32 = 9;
+
+        fn pow2(bits: u32) -> FieldElement {
+            FieldElement::from(2u128).pow(&FieldElement::from(bits as u128))
+        }
+
+        /// @dev - The selector of the "Invalid country code" message in the `error_types` of the ABI
+        pub(super) fn error_selector() -> u64 {
+            u64::from_be_bytes(keccak256(INVALID_COUNTRY_CODE)[..8].try_into().unwrap())
+        }
+
+        struct Assembler {
+            opcodes: Vec<Opcode<FieldElement>>,
+            assert_messages: Vec<(OpcodeLocation, AssertionPayload<FieldElement>)>,
+            next_witness: u32,
+        }
+
+        impl Assembler {
+            fn witness(&mut self) -> Witness {
+                self.next_witness += 1;
+                Witness(self.next_witness - 1)
+            }
+
+            fn assert_zero(&mut self, mul_terms: Vec<(FieldElement, Witness, Witness)>, linear_combinations: Vec<(FieldElement, Witness)>, q_c: FieldElement) {
+                self.opcodes.push(Opcode::AssertZero(Expression { mul_terms, linear_combinations, q_c }));
+            }
+
+            fn range(&mut self, witness: Witness, num_bits: u32) {
+                self.opcodes.push(Opcode::BlackBoxFuncCall(BlackBoxFuncCall::RANGE { input: FunctionInput::Witness(witness), num_bits }));
+            }
+
+            fn boolean(&mut self, witness: Witness) {
+                self.assert_zero(vec![(FieldElement::one(), witness, witness)], vec![(-FieldElement::one(), witness)], FieldElement::zero());
+            }
+
+            /// @dev - (q, r) = (dividend / divisor, dividend % divisor), solved by the Brillig hint, and constrained as dividend == q * divisor + r
+            fn div_mod(&mut self, dividend: Expression<FieldElement>, divisor: FieldElement) -> (Witness, Witness) {
+                let (q, r) = (self.witness(), self.witness());
+                self.opcodes.push(Opcode::BrilligCall {
+                    id: BrilligFunctionId::new(0),
+                    inputs: vec![BrilligInputs::Single(dividend.clone()), BrilligInputs::Single(Expression::from_field(divisor))],
+                    outputs: vec![BrilligOutputs::Simple(q), BrilligOutputs::Simple(r)],
+                    predicate: Expression::one(),
+                });
+                let mut linear_combinations = dividend.linear_combinations.clone();
+                linear_combinations.extend([(-divisor, q), (-FieldElement::one(), r)]);
+                self.assert_zero(Vec::new(), linear_combinations, dividend.q_c);
+                (q, r)
+            }
+
+            /// @dev - std::hash::pedersen_hash(inputs) = (inputs[0] * G[0] + ... + N * H).x, w/ each input split into its (lo, hi) 128-bit limbs (= from_field_unsafe())
+            fn pedersen_hash(&mut self, inputs: &[Witness]) -> Witness {
+                let mut generators = derive_generators(b"DEFAULT_DOMAIN_SEPARATOR", inputs.len() as u32, 0);
+                generators.extend(derive_generators(b"pedersen_hash_length", 1, 0));
+                let points = generators
+                    .iter()
+                    .flat_map(|generator| [FieldElement::from_repr(generator.x), FieldElement::from_repr(generator.y)])
+                    .map(FunctionInput::Constant)
+                    .collect();
+
+                let mut scalars = Vec::new();
+                for input in inputs {
+                    let (hi, lo) = self.div_mod(Expression::from(*input), pow2(128));
+                    scalars.extend([FunctionInput::Witness(lo), FunctionInput::Witness(hi)]);
+                }
+                scalars.extend([FunctionInput::Constant(FieldElement::from(inputs.len() as u128)), FunctionInput::Constant(FieldElement::zero())]);
+
+                let (x, y) = (self.witness(), self.witness());
+                self.opcodes.push(Opcode::BlackBoxFuncCall(BlackBoxFuncCall::MultiScalarMul {
+                    points,
+                    scalars,
+                    predicate: FunctionInput::Constant(FieldElement::one()),
+                    outputs: (x, y),
+                }));
+                x
+            }
+
+            /// @dev - std::merkle::compute_merkle_root(leaf, index, hash_path): the i-th bit of `index` puts the current node on the right (= 1) or on the left (= 0)
+            fn compute_merkle_root(&mut self, leaf: Witness) -> Witness {
+                let mut bits = Vec::new();
+                let mut rest = INDEX;
+                for _ in 0..HASH_PATH_LENGTH {
+                    let (q, bit) = self.div_mod(Expression::from(rest), FieldElement::from(2u128));
+                    self.boolean(bit);
+                    bits.push(bit);
+                    rest = q;
+                }
+                self.assert_zero(Vec::new(), vec![(FieldElement::one(), rest)], FieldElement::zero());
+
+                let mut current = leaf;
+                for (i, bit) in bits.into_iter().enumerate() {
+                    let sibling = Witness(HASH_PATH.0 + i as u32);
+                    // @dev - left = current + bit * (sibling - current), right = sibling + current - left
+                    let (left, right) = (self.witness(), self.witness());
+                    self.assert_zero(
+                        vec![(FieldElement::one(), bit, sibling), (-FieldElement::one(), bit, current)],
+                        vec![(FieldElement::one(), current), (-FieldElement::one(), left)],
+                        FieldElement::zero(),
+                    );
+                    self.assert_zero(
+                        Vec::new(),
+                        vec![(FieldElement::one(), sibling), (FieldElement::one(), current), (-FieldElement::one(), left), (-FieldElement::one(), right)],
+                        FieldElement::zero(),
+                    );
+                    current = self.pedersen_hash(&[left, right]);
+                }
+                current
+            }
+        }
+
+        /**
+         * @dev - (q, r) = (calldata[0] / calldata[1], calldata[0] % calldata[1]) as unsigned integers
+         */
+        fn div_mod_hint() -> BrilligBytecode<FieldElement> {
+            let u32_const = |address: u32, value: u128| BrilligOpcode::Const {
+                destination: MemoryAddress::direct(address),
+                bit_size: BitSize::Integer(IntegerBitSize::U32),
+                value: FieldElement::from(value),
+            };
+            let field_op = |destination: u32, op: BinaryFieldOp, lhs: u32, rhs: u32| BrilligOpcode::BinaryFieldOp {
+                destination: MemoryAddress::direct(destination),
+                op,
+                lhs: MemoryAddress::direct(lhs),
+                rhs: MemoryAddress::direct(rhs),
+            };
+            BrilligBytecode {
+                function_name: "div_mod".to_string(),
+                bytecode: vec![
+                    u32_const(0, 2),
+                    u32_const(1, 0),
+                    BrilligOpcode::CalldataCopy {
+                        destination_address: MemoryAddress::direct(10),
+                        size_address: MemoryAddress::direct(0),
+                        offset_address: MemoryAddress::direct(1),
+                    },
+                    field_op(12, BinaryFieldOp::IntegerDiv, 10, 11),
+                    field_op(13, BinaryFieldOp::Mul, 12, 11),
+                    field_op(13, BinaryFieldOp::Sub, 10, 13),
+                    u32_const(2, 12),
+                    BrilligOpcode::Stop { return_data: HeapVector { pointer: MemoryAddress::direct(2), size: MemoryAddress::direct(0) } },
+                ],
+            }
+        }
+
+        pub(super) fn program() -> Program<FieldElement> {
+            let mut assembler = Assembler { opcodes: Vec::new(), assert_messages: Vec::new(), next_witness: PARAMETERS };
+            // @dev - The u64 parameters
+            for witness in [COUNTRY_CODE, ENTER_DATE, EXIT_DATE] {
+                assembler.range(witness, 64);
+            }
+
+            let note_commitment = assembler.pedersen_hash(&[SECRET]);
+            let nullifier = assembler.pedersen_hash(&[ROOT, SECRET]);
+
+            // @dev - assert(root == check_root)
+            let check_root = assembler.compute_merkle_root(note_commitment);
+            assembler.assert_zero(Vec::new(), vec![(FieldElement::one(), ROOT), (-FieldElement::one(), check_root)], FieldElement::zero());
+
+            // @dev - assert(country_code < 2000, "Invalid country code"): country_code + 2^64 - 2000 = q * 2^64 + r, where q = 1 iff country_code >= 2000
+            let mut shifted = Expression::from(COUNTRY_CODE);
+            shifted.q_c = pow2(64) - FieldElement::from(2000u128);
+            let (q, r) = assembler.div_mod(shifted, pow2(64));
+            assembler.range(r, 64);
+            assembler.boolean(q);
+            let location = OpcodeLocation::Acir(assembler.opcodes.len());
+            assembler.assert_zero(Vec::new(), vec![(FieldElement::one(), q)], FieldElement::zero());
+            assembler.assert_messages.push((location, AssertionPayload { error_selector: error_selector(), payload: Vec::new() }));
+
+            // @dev - The return value (= the nullifier) is a public output witness
+            let output = assembler.witness();
+            assembler.assert_zero(Vec::new(), vec![(FieldElement::one(), nullifier), (-FieldElement::one(), output)], FieldElement::zero());
+
+            let circuit = Circuit {
+                function_name: "main".to_string(),
+                opcodes: assembler.opcodes,
+                private_parameters: (1..=5).map(Witness).collect(),
+                public_parameters: PublicInputs([ROOT, COUNTRY_CODE, ENTER_DATE, EXIT_DATE].into_iter().collect::<BTreeSet<_>>()),
+                return_values: PublicInputs(BTreeSet::from([output])),
+                assert_messages: assembler.assert_messages,
+            };
+            Program { functions: vec![circuit], unconstrained_functions: vec![div_mod_hint()] }
+        }
+
+        /**
+         * @dev - The fixture in the format of `nargo compile` (= target/travel_history.json)
+         */
+        pub(super) fn compiled_circuit_json() -> serde_json::Value {
+            let field = serde_json::json!({ "kind": "field" });
+            let u64_type = serde_json::json!({ "kind": "integer", "sign": "unsigned", "width": 64 });
+            let parameter = |name: &str, typ: &serde_json::Value, visibility: &str| serde_json::json!({ "name": name, "type": typ, "visibility": visibility });
+            let bytecode = Program::serialize_program_base64(&program(), serde_json::value::Serializer).unwrap();
+            serde_json::json!({
+                "noir_version": "fixture (assembled by circuit::tests::fixture)",
+                "abi": {
+                    "parameters": [
+                        parameter("root", &field, "public"),
+                        parameter("hash_path", &serde_json::json!({ "kind": "array", "length": HASH_PATH_LENGTH, "type": field }), "private"),
+                        parameter("index", &field, "private"),
+                        parameter("secret", &field, "private"),
+                        parameter("passport_number", &field, "private"),
+                        parameter("country_code", &u64_type, "public"),
+                        parameter("enter_date", &u64_type, "public"),
+                        parameter("exit_date", &u64_type, "public"),
+                    ],
+                    "return_type": { "abi_type": field, "visibility": "public" },
+                    "error_types": { error_selector().to_string(): { "error_kind": "string", "string": INVALID_COUNTRY_CODE } },
+                },
+                "bytecode": bytecode,
+            })
+        }
+    }
+}
@@ -0,0 +1,34 @@
+// @dev - Alloy
+use alloy::sol;
+
+// Generate the contract bindings for the TravelHistoryManager interface.
+sol! {
+    // The `rpc` attribute enables contract interaction via the provider.
+    #[sol(rpc)]
+    TravelHistoryManager,
+    "artifacts/0910/TravelHistoryManager.sol/TravelHistoryManager.json"
+}
+
+// Generate the contract bindings for the TravelBookingManager interface.
+sol! {
+    #[sol(rpc)]
+    TravelBookingManager,
+    "artifacts/0910/TravelBookingManager.sol/TravelBookingManager.json"
+}
+
+// Generate the contract bindings for the StakingPool interface.
+sol! {
+    #[sol(rpc)]
+    StakingPool,
+    "artifacts/0910/StakingPool.sol/StakingPool.json"
+}
+
+// Generate the contract bindings for the TravelHistoryProofVerifier interface (= contracts/TravelHistoryProofVerifier.sol), whose artifact is not in ./artifacts.
+sol! {
+    #[sol(rpc)]
+    contract TravelHistoryProofVerifier {
+        function verifier() public view returns (address);
+        function version() public view returns (string);
+        function verifyTravelHistoryProof(bytes calldata proof, bytes32[] calldata publicInput) public view returns (bool);
+    }
+}
@@ -0,0 +1,511 @@
+use std::fmt;
+use std::str::FromStr;
+
+// @notice - The catalogue of the country codes, which are used as the `country_code` of the travel_history circuit.
+// @dev - A country code is the calling code of a country without "+" (i.e. USA is 1, France is 33).
+//        For the countries of the North American Numbering Plan (NANP) with their own area code, the area code is appended (i.e. the Dominican Republic (+1-809) is 1809).
+// @dev - Some calling codes are shared by several countries (i.e. 1 = USA and Canada, 7 = Russia and Kazakhstan), so a country code can be ambiguous.
+
+/// @dev - The circuit enforces `assert(country_code < 2000, "Invalid country code")`
+pub const CIRCUIT_COUNTRY_CODE_LIMIT: u64 = 2000;
+
+/// @dev - The overlay area codes of the NANP countries with several area codes (= not their primary one: Dominican Republic 1809, Jamaica 1876, Puerto Rico 1787)
+pub const NANP_OVERLAY_AREA_CODES: &[u64] = &[1829, 1849, 1658, 1939];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Region {
+    Schengen,
+    Eu,
+    Eea,
+}
+
+impl FromStr for Region {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "schengen" => Ok(Region::Schengen),
+            "eu" => Ok(Region::Eu),
+            "eea" => Ok(Region::Eea),
+            _ => Err(eyre::eyre!("Unknown region: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Region::Schengen => write!(f, "Schengen"),
+            Region::Eu => write!(f, "EU"),
+            Region::Eea => write!(f, "EEA"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Country {
+    pub country_code: u64,
+    pub alpha2: &'static str, // @dev - ISO 3166-1 alpha-2
+    pub alpha3: &'static str, // @dev - ISO 3166-1 alpha-3
+    pub name: &'static str,
+    pub regions: &'static [Region],
+}
+
+impl Country {
+    pub fn is_in(&self, region: Region) -> bool {
+        self.regions.contains(&region)
+    }
+
+    /**
+     * @dev - Whether or not this country is a NANP country with its own area code (i.e. 1809)
+     */
+    pub fn is_nanp_area_code(&self) -> bool {
+        (1_000..CIRCUIT_COUNTRY_CODE_LIMIT).contains(&self.country_code)
+    }
+
+    /**
+     * @dev - Whether or not this is an overlay area code of a NANP country (i.e. 1658 of Jamaica, whose primary area code is 1876)
+     */
+    pub fn is_nanp_overlay(&self) -> bool {
+        NANP_OVERLAY_AREA_CODES.contains(&self.country_code)
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}/{})", self.name, self.alpha2, self.alpha3)
+    }
+}
+
+/**
+ * @notice - The result of looking up a country code in the catalogue.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lookup {
+    pub country_code: u64,
+    pub countries: Vec<&'static Country>,
+}
+
+impl Lookup {
+    pub fn is_known(&self) -> bool {
+        !self.countries.is_empty()
+    }
+
+    /**
+     * @dev - Whether or not the country code is shared by several countries (i.e. 1 = USA and Canada)
+     */
+    pub fn is_ambiguous(&self) -> bool {
+        self.countries.len() > 1
+    }
+
+    /**
+     * @dev - The only country of this country code, or None if it is unknown or ambiguous.
+     */
+    pub fn unique(&self) -> Option<&'static Country> {
+        match self.countries.as_slice() {
+            [country] => Some(country),
+            _ => None,
+        }
+    }
+
+    /**
+     * @dev - Whether or not all countries of this country code are in a given region
+     */
+    pub fn is_in(&self, region: Region) -> bool {
+        self.is_known() && self.countries.iter().all(|country| country.is_in(region))
+    }
+}
+
+impl fmt::Display for Lookup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.countries.as_slice() {
+            [] => write!(f, "{} (unknown)", self.country_code),
+            [country] => write!(f, "{} ({})", self.country_code, country.alpha2),
+            countries => {
+                let alpha2s: Vec<&str> = countries.iter().map(|country| country.alpha2).collect();
+                write!(f, "{} (ambiguous: {})", self.country_code, alpha2s.join("/"))
+            }
+        }
+    }
+}
+
+pub fn lookup(country_code: u64) -> Lookup {
+    Lookup {
+        country_code,
+        countries: COUNTRIES.iter().filter(|country| country.country_code == country_code).collect(),
+    }
+}
+
+/**
+ * @dev - Find a country by its ISO 3166-1 alpha-2 code, alpha-3 code or name (case-insensitive).
+ * @dev - For a NANP country with several area codes (i.e. Jamaica: 1876, 1658), its primary area code is returned (= not one of NANP_OVERLAY_AREA_CODES),
+ *        regardless of the order of the catalogue.
+ */
+pub fn find(query: &str) -> Option<&'static Country> {
+    let query = query.trim();
+    COUNTRIES
+        .iter()
+        .filter(|country| {
+            country.alpha2.eq_ignore_ascii_case(query)
+                || country.alpha3.eq_ignore_ascii_case(query)
+                || country.name.eq_ignore_ascii_case(query)
+        })
+        .min_by_key(|country| country.is_nanp_overlay())
+}
+
+/**
+ * @dev - The country codes of all countries in a given region
+ */
+pub fn country_codes_in(region: Region) -> Vec<u64> {
+    COUNTRIES.iter().filter(|country| country.is_in(region)).map(|country| country.country_code).collect()
+}
+
+/**
+ * @notice - Validate a country code before it is used as the `country_code` of the circuit (i.e. in a Prover.toml).
+ * @dev - An error is returned if the country code is rejected by the circuit (>= 2000) or is not in the catalogue.
+ * @dev - An ambiguous country code is accepted, so the caller should check Lookup#is_ambiguous() to warn about it.
+ */
+pub fn validate_for_circuit(country_code: u64) -> eyre::Result<Lookup> {
+    if country_code >= CIRCUIT_COUNTRY_CODE_LIMIT {
+        return Err(eyre::eyre!(
+            "Invalid country code: {} (the circuit requires country_code < {})",
+            country_code,
+            CIRCUIT_COUNTRY_CODE_LIMIT
+        ));
+    }
+    let lookup = lookup(country_code);
+    if !lookup.is_known() {
+        return Err(eyre::eyre!("Unknown country code: {}", country_code));
+    }
+    Ok(lookup)
+}
+
+const EU_SCHENGEN: &[Region] = &[Region::Eu, Region::Eea, Region::Schengen];
+const EU_ONLY: &[Region] = &[Region::Eu, Region::Eea];
+const EEA_SCHENGEN: &[Region] = &[Region::Eea, Region::Schengen];
+const SCHENGEN_ONLY: &[Region] = &[Region::Schengen];
+const NONE: &[Region] = &[];
+
+const fn country(country_code: u64, alpha2: &'static str, alpha3: &'static str, name: &'static str, regions: &'static [Region]) -> Country {
+    Country { country_code, alpha2, alpha3, name, regions }
+}
+
+/**
+ * @dev - The regions are the memberships of the member states only (i.e. Monaco and the Vatican City are not listed as Schengen members).
+ */
+pub static COUNTRIES: &[Country] = &[
+    country(1, "US", "USA", "United States", NONE),
+    country(1, "CA", "CAN", "Canada", NONE),
+    country(1242, "BS", "BHS", "Bahamas", NONE),
+    country(1246, "BB", "BRB", "Barbados", NONE),
+    country(1264, "AI", "AIA", "Anguilla", NONE),
+    country(1268, "AG", "ATG", "Antigua and Barbuda", NONE),
+    country(1284, "VG", "VGB", "British Virgin Islands", NONE),
+    country(1340, "VI", "VIR", "U.S. Virgin Islands", NONE),
+    country(1345, "KY", "CYM", "Cayman Islands", NONE),
+    country(1441, "BM", "BMU", "Bermuda", NONE),
+    country(1473, "GD", "GRD", "Grenada", NONE),
+    country(1649, "TC", "TCA", "Turks and Caicos Islands", NONE),
+    country(1658, "JM", "JAM", "Jamaica", NONE),
+    country(1664, "MS", "MSR", "Montserrat", NONE),
+    country(1670, "MP", "MNP", "Northern Mariana Islands", NONE),
+    country(1671, "GU", "GUM", "Guam", NONE),
+    country(1684, "AS", "ASM", "American Samoa", NONE),
+    country(1721, "SX", "SXM", "Sint Maarten", NONE),
+    country(1758, "LC", "LCA", "Saint Lucia", NONE),
+    country(1767, "DM", "DMA", "Dominica", NONE),
+    country(1784, "VC", "VCT", "Saint Vincent and the Grenadines", NONE),
+    country(1787, "PR", "PRI", "Puerto Rico", NONE),
+    country(1809, "DO", "DOM", "Dominican Republic", NONE),
+    country(1829, "DO", "DOM", "Dominican Republic", NONE),
+    country(1849, "DO", "DOM", "Dominican Republic", NONE),
+    country(1868, "TT", "TTO", "Trinidad and Tobago", NONE),
+    country(1869, "KN", "KNA", "Saint Kitts and Nevis", NONE),
+    country(1876, "JM", "JAM", "Jamaica", NONE),
+    country(1939, "PR", "PRI", "Puerto Rico", NONE),
+    country(7, "RU", "RUS", "Russia", NONE),
+    country(7, "KZ", "KAZ", "Kazakhstan", NONE),
+    country(20, "EG", "EGY", "Egypt", NONE),
+    country(27, "ZA", "ZAF", "South Africa", NONE),
+    country(30, "GR", "GRC", "Greece", EU_SCHENGEN),
+    country(31, "NL", "NLD", "Netherlands", EU_SCHENGEN),
+    country(32, "BE", "BEL", "Belgium", EU_SCHENGEN),
+    country(33, "FR", "FRA", "France", EU_SCHENGEN),
+    country(34, "ES", "ESP", "Spain", EU_SCHENGEN),
+    country(36, "HU", "HUN", "Hungary", EU_SCHENGEN),
+    country(39, "IT", "ITA", "Italy", EU_SCHENGEN),
+    country(39, "VA", "VAT", "Vatican City", NONE),
+    country(40, "RO", "ROU", "Romania", EU_SCHENGEN),
+    country(41, "CH", "CHE", "Switzerland", SCHENGEN_ONLY),
+    country(43, "AT", "AUT", "Austria", EU_SCHENGEN),
+    country(44, "GB", "GBR", "United Kingdom", NONE),
+    country(44, "GG", "GGY", "Guernsey", NONE),
+    country(44, "JE", "JEY", "Jersey", NONE),
+    country(44, "IM", "IMN", "Isle of Man", NONE),
+    country(45, "DK", "DNK", "Denmark", EU_SCHENGEN),
+    country(46, "SE", "SWE", "Sweden", EU_SCHENGEN),
+    country(47, "NO", "NOR", "Norway", EEA_SCHENGEN),
+    country(47, "SJ", "SJM", "Svalbard and Jan Mayen", NONE),
+    country(48, "PL", "POL", "Poland", EU_SCHENGEN),
+    country(49, "DE", "DEU", "Germany", EU_SCHENGEN),
+    country(51, "PE", "PER", "Peru", NONE),
+    country(52, "MX", "MEX", "Mexico", NONE),
+    country(53, "CU", "CUB", "Cuba", NONE),
+    country(54, "AR", "ARG", "Argentina", NONE),
+    country(55, "BR", "BRA", "Brazil", NONE),
+    country(56, "CL", "CHL", "Chile", NONE),
+    country(57, "CO", "COL", "Colombia", NONE),
+    country(58, "VE", "VEN", "Venezuela", NONE),
+    country(60, "MY", "MYS", "Malaysia", NONE),
+    country(61, "AU", "AUS", "Australia", NONE),
+    country(61, "CX", "CXR", "Christmas Island", NONE),
+    country(61, "CC", "CCK", "Cocos (Keeling) Islands", NONE),
+    country(62, "ID", "IDN", "Indonesia", NONE),
+    country(63, "PH", "PHL", "Philippines", NONE),
+    country(64, "NZ", "NZL", "New Zealand", NONE),
+    country(64, "PN", "PCN", "Pitcairn Islands", NONE),
+    country(65, "SG", "SGP", "Singapore", NONE),
+    country(66, "TH", "THA", "Thailand", NONE),
+    country(81, "JP", "JPN", "Japan", NONE),
+    country(82, "KR", "KOR", "South Korea", NONE),
+    country(84, "VN", "VNM", "Vietnam", NONE),
+    country(86, "CN", "CHN", "China", NONE),
+    country(90, "TR", "TUR", "Turkey", NONE),
+    country(91, "IN", "IND", "India", NONE),
+    country(92, "PK", "PAK", "Pakistan", NONE),
+    country(93, "AF", "AFG", "Afghanistan", NONE),
+    country(94, "LK", "LKA", "Sri Lanka", NONE),
+    country(95, "MM", "MMR", "Myanmar", NONE),
+    country(98, "IR", "IRN", "Iran", NONE),
+    country(211, "SS", "SSD", "South Sudan", NONE),
+    country(212, "MA", "MAR", "Morocco", NONE),
+    country(212, "EH", "ESH", "Western Sahara", NONE),
+    country(213, "DZ", "DZA", "Algeria", NONE),
+    country(216, "TN", "TUN", "Tunisia", NONE),
+    country(218, "LY", "LBY", "Libya", NONE),
+    country(220, "GM", "GMB", "Gambia", NONE),
+    country(221, "SN", "SEN", "Senegal", NONE),
+    country(222, "MR", "MRT", "Mauritania", NONE),
+    country(223, "ML", "MLI", "Mali", NONE),
+    country(224, "GN", "GIN", "Guinea", NONE),
+    country(225, "CI", "CIV", "Cote d'Ivoire", NONE),
+    country(226, "BF", "BFA", "Burkina Faso", NONE),
+    country(227, "NE", "NER", "Niger", NONE),
+    country(228, "TG", "TGO", "Togo", NONE),
+    country(229, "BJ", "BEN", "Benin", NONE),
+    country(230, "MU", "MUS", "Mauritius", NONE),
+    country(231, "LR", "LBR", "Liberia", NONE),
+    country(232, "SL", "SLE", "Sierra Leone", NONE),
+    country(233, "GH", "GHA", "Ghana", NONE),
+    country(234, "NG", "NGA", "Nigeria", NONE),
+    country(235, "TD", "TCD", "Chad", NONE),
+    country(236, "CF", "CAF", "Central African Republic", NONE),
+    country(237, "CM", "CMR", "Cameroon", NONE),
+    country(238, "CV", "CPV", "Cabo Verde", NONE),
+    country(239, "ST", "STP", "Sao Tome and Principe", NONE),
+    country(240, "GQ", "GNQ", "Equatorial Guinea", NONE),
+    country(241, "GA", "GAB", "Gabon", NONE),
+    country(242, "CG", "COG", "Republic of the Congo", NONE),
+    country(243, "CD", "COD", "DR Congo", NONE),
+    country(244, "AO", "AGO", "Angola", NONE),
+    country(245, "GW", "GNB", "Guinea-Bissau", NONE),
+    country(246, "IO", "IOT", "British Indian Ocean Territory", NONE),
+    country(248, "SC", "SYC", "Seychelles", NONE),
+    country(249, "SD", "SDN", "Sudan", NONE),
+    country(250, "RW", "RWA", "Rwanda", NONE),
+    country(251, "ET", "ETH", "Ethiopia", NONE),
+    country(252, "SO", "SOM", "Somalia", NONE),
+    country(253, "DJ", "DJI", "Djibouti", NONE),
+    country(254, "KE", "KEN", "Kenya", NONE),
+    country(255, "TZ", "TZA", "Tanzania", NONE),
+    country(256, "UG", "UGA", "Uganda", NONE),
+    country(257, "BI", "BDI", "Burundi", NONE),
+    country(258, "MZ", "MOZ", "Mozambique", NONE),
+    country(260, "ZM", "ZMB", "Zambia", NONE),
+    country(261, "MG", "MDG", "Madagascar", NONE),
+    country(262, "RE", "REU", "Reunion", EU_ONLY),
+    country(262, "YT", "MYT", "Mayotte", EU_ONLY),
+    country(263, "ZW", "ZWE", "Zimbabwe", NONE),
+    country(264, "NA", "NAM", "Namibia", NONE),
+    country(265, "MW", "MWI", "Malawi", NONE),
+    country(266, "LS", "LSO", "Lesotho", NONE),
+    country(267, "BW", "BWA", "Botswana", NONE),
+    country(268, "SZ", "SWZ", "Eswatini", NONE),
+    country(269, "KM", "COM", "Comoros", NONE),
+    country(290, "SH", "SHN", "Saint Helena", NONE),
+    country(291, "ER", "ERI", "Eritrea", NONE),
+    country(297, "AW", "ABW", "Aruba", NONE),
+    country(298, "FO", "FRO", "Faroe Islands", NONE),
+    country(299, "GL", "GRL", "Greenland", NONE),
+    country(350, "GI", "GIB", "Gibraltar", NONE),
+    country(351, "PT", "PRT", "Portugal", EU_SCHENGEN),
+    country(352, "LU", "LUX", "Luxembourg", EU_SCHENGEN),
+    country(353, "IE", "IRL", "Ireland", EU_ONLY),
+    country(354, "IS", "ISL", "Iceland", EEA_SCHENGEN),
+    country(355, "AL", "ALB", "Albania", NONE),
+    country(356, "MT", "MLT", "Malta", EU_SCHENGEN),
+    country(357, "CY", "CYP", "Cyprus", EU_ONLY),
+    country(358, "FI", "FIN", "Finland", EU_SCHENGEN),
+    country(358, "AX", "ALA", "Aland Islands", EU_SCHENGEN),
+    country(359, "BG", "BGR", "Bulgaria", EU_SCHENGEN),
+    country(370, "LT", "LTU", "Lithuania", EU_SCHENGEN),
+    country(371, "LV", "LVA", "Latvia", EU_SCHENGEN),
+    country(372, "EE", "EST", "Estonia", EU_SCHENGEN),
+    country(373, "MD", "MDA", "Moldova", NONE),
+    country(374, "AM", "ARM", "Armenia", NONE),
+    country(375, "BY", "BLR", "Belarus", NONE),
+    country(376, "AD", "AND", "Andorra", NONE),
+    country(377, "MC", "MCO", "Monaco", NONE),
+    country(378, "SM", "SMR", "San Marino", NONE),
+    country(380, "UA", "UKR", "Ukraine", NONE),
+    country(381, "RS", "SRB", "Serbia", NONE),
+    country(382, "ME", "MNE", "Montenegro", NONE),
+    country(385, "HR", "HRV", "Croatia", EU_SCHENGEN),
+    country(386, "SI", "SVN", "Slovenia", EU_SCHENGEN),
+    country(387, "BA", "BIH", "Bosnia and Herzegovina", NONE),
+    country(389, "MK", "MKD", "North Macedonia", NONE),
+    country(420, "CZ", "CZE", "Czechia", EU_SCHENGEN),
+    country(421, "SK", "SVK", "Slovakia", EU_SCHENGEN),
+    country(423, "LI", "LIE", "Liechtenstein", EEA_SCHENGEN),
+    country(500, "FK", "FLK", "Falkland Islands", NONE),
+    country(501, "BZ", "BLZ", "Belize", NONE),
+    country(502, "GT", "GTM", "Guatemala", NONE),
+    country(503, "SV", "SLV", "El Salvador", NONE),
+    country(504, "HN", "HND", "Honduras", NONE),
+    country(505, "NI", "NIC", "Nicaragua", NONE),
+    country(506, "CR", "CRI", "Costa Rica", NONE),
+    country(507, "PA", "PAN", "Panama", NONE),
+    country(508, "PM", "SPM", "Saint Pierre and Miquelon", NONE),
+    country(509, "HT", "HTI", "Haiti", NONE),
+    country(590, "GP", "GLP", "Guadeloupe", EU_ONLY),
+    country(590, "BL", "BLM", "Saint Barthelemy", NONE),
+    country(590, "MF", "MAF", "Saint Martin", EU_ONLY),
+    country(591, "BO", "BOL", "Bolivia", NONE),
+    country(592, "GY", "GUY", "Guyana", NONE),
+    country(593, "EC", "ECU", "Ecuador", NONE),
+    country(594, "GF", "GUF", "French Guiana", EU_ONLY),
+    country(595, "PY", "PRY", "Paraguay", NONE),
+    country(596, "MQ", "MTQ", "Martinique", EU_ONLY),
+    country(597, "SR", "SUR", "Suriname", NONE),
+    country(598, "UY", "URY", "Uruguay", NONE),
+    country(599, "CW", "CUW", "Curacao", NONE),
+    country(599, "BQ", "BES", "Caribbean Netherlands", NONE),
+    country(670, "TL", "TLS", "Timor-Leste", NONE),
+    country(672, "NF", "NFK", "Norfolk Island", NONE),
+    country(673, "BN", "BRN", "Brunei", NONE),
+    country(674, "NR", "NRU", "Nauru", NONE),
+    country(675, "PG", "PNG", "Papua New Guinea", NONE),
+    country(676, "TO", "TON", "Tonga", NONE),
+    country(677, "SB", "SLB", "Solomon Islands", NONE),
+    country(678, "VU", "VUT", "Vanuatu", NONE),
+    country(679, "FJ", "FJI", "Fiji", NONE),
+    country(680, "PW", "PLW", "Palau", NONE),
+    country(681, "WF", "WLF", "Wallis and Futuna", NONE),
+    country(682, "CK", "COK", "Cook Islands", NONE),
+    country(683, "NU", "NIU", "Niue", NONE),
+    country(685, "WS", "WSM", "Samoa", NONE),
+    country(686, "KI", "KIR", "Kiribati", NONE),
+    country(687, "NC", "NCL", "New Caledonia", NONE),
+    country(688, "TV", "TUV", "Tuvalu", NONE),
+    country(689, "PF", "PYF", "French Polynesia", NONE),
+    country(690, "TK", "TKL", "Tokelau", NONE),
+    country(691, "FM", "FSM", "Micronesia", NONE),
+    country(692, "MH", "MHL", "Marshall Islands", NONE),
+    country(850, "KP", "PRK", "North Korea", NONE),
+    country(852, "HK", "HKG", "Hong Kong", NONE),
+    country(853, "MO", "MAC", "Macao", NONE),
+    country(855, "KH", "KHM", "Cambodia", NONE),
+    country(856, "LA", "LAO", "Laos", NONE),
+    country(880, "BD", "BGD", "Bangladesh", NONE),
+    country(886, "TW", "TWN", "Taiwan", NONE),
+    country(960, "MV", "MDV", "Maldives", NONE),
+    country(961, "LB", "LBN", "Lebanon", NONE),
+    country(962, "JO", "JOR", "Jordan", NONE),
+    country(963, "SY", "SYR", "Syria", NONE),
+    country(964, "IQ", "IRQ", "Iraq", NONE),
+    country(965, "KW", "KWT", "Kuwait", NONE),
+    country(966, "SA", "SAU", "Saudi Arabia", NONE),
+    country(967, "YE", "YEM", "Yemen", NONE),
+    country(968, "OM", "OMN", "Oman", NONE),
+    country(970, "PS", "PSE", "Palestine", NONE),
+    country(971, "AE", "ARE", "United Arab Emirates", NONE),
+    country(972, "IL", "ISR", "Israel", NONE),
+    country(973, "BH", "BHR", "Bahrain", NONE),
+    country(974, "QA", "QAT", "Qatar", NONE),
+    country(975, "BT", "BTN", "Bhutan", NONE),
+    country(976, "MN", "MNG", "Mongolia", NONE),
+    country(977, "NP", "NPL", "Nepal", NONE),
+    country(992, "TJ", "TJK", "Tajikistan", NONE),
+    country(993, "TM", "TKM", "Turkmenistan", NONE),
+    country(994, "AZ", "AZE", "Azerbaijan", NONE),
+    country(995, "GE", "GEO", "Georgia", NONE),
+    country(996, "KG", "KGZ", "Kyrgyzstan", NONE),
+    country(998, "UZ", "UZB", "Uzbekistan", NONE),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_a_country_by_its_alpha2_alpha3_or_name() {
+        assert_eq!(find("FR").unwrap().country_code, 33);
+        assert_eq!(find("fra").unwrap().country_code, 33);
+        assert_eq!(find(" France ").unwrap().country_code, 33);
+        assert_eq!(find("DEU").unwrap().alpha2, "DE");
+        assert!(find("XX").is_none());
+    }
+
+    #[test]
+    fn finds_the_primary_area_code_of_a_nanp_country() {
+        assert_eq!(find("JM").unwrap().country_code, 1876);
+        assert_eq!(find("Jamaica").unwrap().country_code, 1876);
+        assert_eq!(find("DOM").unwrap().country_code, 1809);
+        assert_eq!(find("PR").unwrap().country_code, 1787);
+
+        // @dev - Each overlay area code belongs to a country which also has a primary area code
+        for overlay in NANP_OVERLAY_AREA_CODES.iter() {
+            let country = lookup(*overlay).unique().unwrap();
+            assert!(country.is_nanp_area_code() && country.is_nanp_overlay());
+            assert!(!find(country.alpha2).unwrap().is_nanp_overlay());
+        }
+    }
+
+    #[test]
+    fn looks_up_a_unique_an_ambiguous_and_an_unknown_country_code() {
+        let france = lookup(33);
+        assert!(france.is_known() && !france.is_ambiguous());
+        assert_eq!(france.unique().unwrap().alpha3, "FRA");
+        assert!(france.is_in(Region::Schengen));
+        assert_eq!(france.to_string(), "33 (FR)");
+
+        let north_america = lookup(1);
+        assert!(north_america.is_ambiguous());
+        assert!(north_america.unique().is_none());
+        assert_eq!(north_america.to_string(), "1 (ambiguous: US/CA)");
+        assert!(lookup(7).is_ambiguous());
+
+        let unknown = lookup(999);
+        assert!(!unknown.is_known() && !unknown.is_ambiguous());
+        assert!(!unknown.is_in(Region::Schengen));
+        assert_eq!(unknown.to_string(), "999 (unknown)");
+    }
+
+    #[test]
+    fn validates_a_country_code_for_the_circuit() {
+        assert_eq!(validate_for_circuit(33).unwrap().unique().unwrap().alpha2, "FR");
+        assert!(validate_for_circuit(1).unwrap().is_ambiguous());
+        assert!(validate_for_circuit(1876).is_ok());
+        assert!(validate_for_circuit(999).unwrap_err().to_string().contains("Unknown country code: 999"));
+        assert!(validate_for_circuit(CIRCUIT_COUNTRY_CODE_LIMIT).unwrap_err().to_string().contains("country_code < 2000"));
+    }
+
+    #[test]
+    fn resolves_the_regions() {
+        assert_eq!("Schengen".parse::<Region>().unwrap(), Region::Schengen);
+        assert_eq!("EEA".parse::<Region>().unwrap(), Region::Eea);
+        assert!("nordic".parse::<Region>().is_err());
+        assert!(country_codes_in(Region::Eu).contains(&49));
+        assert!(!country_codes_in(Region::Eu).contains(&44));
+    }
+}
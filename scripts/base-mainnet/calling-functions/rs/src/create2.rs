@@ -0,0 +1,242 @@
+// @dev - Alloy
+use alloy::{
+    network::{Network, ReceiptResponse, TransactionBuilder},
+    primitives::{address, keccak256, Address, Bytes, TxHash, B256},
+    providers::Provider,
+    sol_types::SolValue,
+};
+
+use crate::artifact::ContractArtifact;
+use crate::deployment::{load_suite_artifacts, DeployedContract, CONTRACT_SUITE};
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// @notice - Deterministic deployments through a CREATE2 factory: the same artifact + constructor arguments + salt is deployed at the same address on every chain (i.e. Base Mainnet and Celo Mainnet)
+// @dev - The factory is the deterministic deployment proxy (= https://github.com/Arachnid/deterministic-deployment-proxy), which is deployed at the same address on Base, Celo and Anvil.
+//        Its input is <salt (32 bytes)><init code>, and it deploys the init code via CREATE2.
+
+/// @dev - The address of the deterministic deployment proxy
+pub const CREATE2_FACTORY: Address = address!("4e59b44847b379578588920cA78FbF26c0B4956C");
+
+/// @dev - The default salt of the contract suite
+pub const DEFAULT_SALT: &str = "travel-history-v1";
+
+/**
+ * @notice - The salts of the contracts of a deterministic deployment: a default salt, and the salts overridden per contract (i.e. "StakingPool=staking-pool-v2")
+ * @dev - A salt is either a 32-byte hex (= used as-is) or a string (= its keccak256).
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Create2Salts {
+    pub default: B256,
+    pub overrides: BTreeMap<String, B256>,
+}
+
+impl Create2Salts {
+    /**
+     * @dev - Parse the `--salt` options: a bare value is the default salt, and `<Contract>=<salt>` overrides the salt of a contract
+     */
+    pub fn parse(values: &[String]) -> eyre::Result<Self> {
+        let mut salts = Create2Salts { default: salt_from(DEFAULT_SALT), overrides: BTreeMap::new() };
+        for value in values.iter() {
+            match value.split_once('=') {
+                Some((contract, salt)) => {
+                    if !CONTRACT_SUITE.contains(&contract) {
+                        return Err(eyre::eyre!("{} is not a contract of the suite ({})", contract, CONTRACT_SUITE.join(", ")));
+                    }
+                    salts.overrides.insert(contract.to_string(), salt_from(salt));
+                }
+                None => salts.default = salt_from(value),
+            }
+        }
+        Ok(salts)
+    }
+
+    pub fn salt_of(&self, contract: &str) -> B256 {
+        self.overrides.get(contract).copied().unwrap_or(self.default)
+    }
+}
+
+/**
+ * @dev - A salt from a 32-byte hex (= as-is) or a string (= keccak256 of it)
+ */
+pub fn salt_from(value: &str) -> B256 {
+    B256::from_str(value).unwrap_or_else(|_| keccak256(value.as_bytes()))
+}
+
+/**
+ * @notice - The address which an init code is deployed at with a salt through the factory (= computable offline)
+ */
+pub fn predict_address(salt: B256, init_code: &[u8]) -> Address {
+    CREATE2_FACTORY.create2_from_code(salt, init_code)
+}
+
+/**
+ * @notice - A contract of a deterministic deployment, and its predicted address
+ */
+#[derive(Debug, Clone)]
+pub struct PlannedContract {
+    pub contract: String,
+    pub salt: B256,
+    pub constructor_args: Bytes,
+    pub address: Address,
+    pub artifact: ContractArtifact,
+}
+
+impl PlannedContract {
+    pub fn init_code(&self) -> Bytes {
+        self.artifact.deploy_code(&self.constructor_args)
+    }
+
+    /**
+     * @dev - The entry of the deployment manifest of a contract which has been deployed by an earlier run at the predicted address (= the tx hash is unknown)
+     */
+    pub fn adopted(&self) -> eyre::Result<DeployedContract> {
+        Ok(DeployedContract {
+            contract: self.contract.clone(),
+            label: self.contract.clone(),
+            address: self.address,
+            tx_hash: TxHash::ZERO,
+            block_number: None,
+            artifact: self.artifact.path.clone(),
+            artifact_hash: self.artifact.bytecode_hash(),
+            constructor_args: self.constructor_args.clone(),
+            deployed_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            tags: Vec::new(),
+            salt: Some(self.salt),
+        })
+    }
+}
+
+/**
+ * @notice - Plan the deterministic deployment of the contract suite offline: the constructor arguments are wired to the predicted addresses of the previous contracts,
+ *           so that the whole suite has the same addresses on every chain.
+ */
+pub fn plan_suite(artifacts_dir: &Path, salts: &Create2Salts) -> eyre::Result<Vec<PlannedContract>> {
+    let artifacts = load_suite_artifacts(artifacts_dir)?;
+    let mut planned_contracts: Vec<PlannedContract> = Vec::new();
+    for (contract, artifact) in CONTRACT_SUITE.iter().zip(artifacts) {
+        let dependency = match *contract {
+            "TravelHistoryProofVerifier" => Some("HonkVerifier"),
+            "TravelHistoryManager" => Some("TravelHistoryProofVerifier"),
+            _ => None,
+        };
+        let constructor_args = match dependency {
+            Some(dependency) => {
+                let planned = planned_contracts
+                    .iter()
+                    .find(|planned| planned.contract == dependency)
+                    .expect("the contract suite is planned in order");
+                Bytes::from(planned.address.abi_encode())
+            }
+            None => Bytes::new(),
+        };
+        let salt = salts.salt_of(contract);
+        let address = predict_address(salt, &artifact.deploy_code(&constructor_args));
+        planned_contracts.push(PlannedContract { contract: contract.to_string(), salt, constructor_args, address, artifact });
+    }
+    Ok(planned_contracts)
+}
+
+/**
+ * @notice - The outcome of a deterministic deployment
+ * @dev - `AlreadyDeployed` is a salt which has been used before (= the predicted address has code), in which case no transaction is sent.
+ */
+#[derive(Debug, Clone)]
+pub enum Create2Deployment {
+    Deployed(Box<DeployedContract>),
+    AlreadyDeployed(Address),
+}
+
+/**
+ * @notice - Deploy a planned contract through the factory, unless its salt has already been deployed
+ */
+pub async fn deploy_planned<P: Provider<N>, N: Network>(provider: &P, planned: &PlannedContract) -> eyre::Result<Create2Deployment> {
+    if !provider.get_code_at(planned.address).await?.is_empty() {
+        return Ok(Create2Deployment::AlreadyDeployed(planned.address));
+    }
+    if provider.get_code_at(CREATE2_FACTORY).await?.is_empty() {
+        return Err(eyre::eyre!("The CREATE2 factory ({}) is not deployed on this chain", CREATE2_FACTORY));
+    }
+
+    let mut input = planned.salt.to_vec();
+    input.extend_from_slice(&planned.init_code());
+    let deploy_tx = N::TransactionRequest::default().with_to(CREATE2_FACTORY).with_input(Bytes::from(input));
+    let receipt = provider.send_transaction(deploy_tx).await?.get_receipt().await?;
+    if !receipt.status() {
+        return Err(eyre::eyre!("The deployment of {} has been reverted (tx: {})", planned.contract, receipt.transaction_hash()));
+    }
+    // @dev - The code is checked at the predicted address, so that a contract deployed elsewhere (i.e. by another factory at this address) is not recorded
+    if provider.get_code_at(planned.address).await?.is_empty() {
+        return Err(eyre::eyre!("{} has not been deployed at {} (tx: {})", planned.contract, planned.address, receipt.transaction_hash()));
+    }
+
+    Ok(Create2Deployment::Deployed(Box::new(DeployedContract {
+        contract: planned.contract.clone(),
+        label: planned.contract.clone(),
+        address: planned.address,
+        tx_hash: receipt.transaction_hash(),
+        block_number: receipt.block_number(),
+        artifact: planned.artifact.path.clone(),
+        artifact_hash: planned.artifact.bytecode_hash(),
+        constructor_args: planned.constructor_args.clone(),
+        deployed_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        tags: Vec::new(),
+        salt: Some(planned.salt),
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::{hex, primitives::b256};
+
+    #[test]
+    fn predicts_the_addresses_of_the_factory() {
+        // @dev - The example of EIP-1014 with the address of the factory as the deployer
+        let init_code = hex!("00");
+        assert_eq!(predict_address(B256::ZERO, &init_code), CREATE2_FACTORY.create2(B256::ZERO, keccak256(init_code)));
+        assert_eq!(
+            Address::ZERO.create2_from_code(B256::ZERO, init_code),
+            address!("4D1A2e2bB4F88F0250f26Ffff098B0b30B26BF38")
+        );
+        assert_ne!(predict_address(salt_from("a"), &init_code), predict_address(salt_from("b"), &init_code));
+
+        // @dev - The known answer of forge-std (= test_ComputeCreate2AddressWithDefaultDeployer() in lib/forge-std/test/StdUtils.t.sol), whose salt is mined for a vanity address under the factory
+        let salt = b256!("c290c670fde54e5ef686f9132cbc8711e76a98f0333a438a92daa442c71403c0");
+        let init_code = hex!("6080");
+        assert_eq!(keccak256(init_code), b256!("1a578b7a4b0b5755db6d121b4118d4bc68fe170dca840c59bc922f14175a76b0"));
+        assert_eq!(predict_address(salt, &init_code), address!("c0ffEe2198a06235aAbFffe5Db0CacF1717f5Ac6"));
+    }
+
+    #[test]
+    fn plans_the_suite_from_every_artifact_it_names() {
+        let dir = std::env::temp_dir().join(format!("travel_history_create2_artifacts_{}", std::process::id()));
+        let error = plan_suite(&dir, &Create2Salts::parse(&[]).unwrap()).unwrap_err().to_string();
+        assert!(error.contains("forge build"), "{}", error);
+
+        // @dev - The TravelHistoryManager artifact stands in for every contract of the suite
+        for contract in CONTRACT_SUITE {
+            let path = crate::deployment::artifact_path(&dir, contract);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::copy(crate::artifact::TRAVEL_HISTORY_MANAGER_ARTIFACT_PATH, &path).unwrap();
+        }
+        let planned_contracts = plan_suite(&dir, &Create2Salts::parse(&[]).unwrap()).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(planned_contracts.iter().map(|planned| planned.contract.as_str()).collect::<Vec<_>>(), CONTRACT_SUITE.to_vec());
+        // @dev - The constructor arguments are wired to the predicted addresses of the dependencies
+        assert_eq!(planned_contracts[1].constructor_args, Bytes::from(planned_contracts[0].address.abi_encode()));
+        assert_eq!(planned_contracts[2].constructor_args, Bytes::from(planned_contracts[1].address.abi_encode()));
+    }
+
+    #[test]
+    fn parses_the_salts() {
+        let salts = Create2Salts::parse(&["v2".to_string(), "StakingPool=0x0000000000000000000000000000000000000000000000000000000000000001".to_string()]).unwrap();
+        assert_eq!(salts.salt_of("TravelHistoryManager"), keccak256("v2"));
+        assert_eq!(salts.salt_of("StakingPool"), B256::with_last_byte(1));
+        assert_eq!(Create2Salts::parse(&[]).unwrap().default, keccak256(DEFAULT_SALT));
+        assert!(Create2Salts::parse(&["Unknown=v1".to_string()]).is_err());
+    }
+}
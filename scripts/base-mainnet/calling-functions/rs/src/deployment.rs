@@ -0,0 +1,538 @@
+// @dev - Alloy
+use alloy::{
+    network::{Network, ReceiptResponse, TransactionBuilder},
+    primitives::{Address, Bytes, TxHash, B256},
+    providers::Provider,
+    sol_types::SolValue,
+};
+
+use crate::artifact::ContractArtifact;
+use crate::chain::ChainProfile;
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// @dev - The default directory of the deployment manifests (= <dir>/<chain>.json), relative to the root directory (= /rs)
+pub const DEFAULT_DEPLOYMENTS_PATH: &str = "deployments";
+
+/// @dev - The default directory of the Foundry artifacts of the contract suite
+pub const DEFAULT_ARTIFACTS_PATH: &str = "artifacts/0910";
+
+/// @dev - The contract suite in the order of deployment: HonkVerifier -> TravelHistoryProofVerifier(HonkVerifier) -> TravelHistoryManager(TravelHistoryProofVerifier) -> TravelBookingManager, StakingPool
+pub const CONTRACT_SUITE: [&str; 5] = ["HonkVerifier", "TravelHistoryProofVerifier", "TravelHistoryManager", "TravelBookingManager", "StakingPool"];
+
+/**
+ * @notice - A deployed contract in a deployment manifest
+ * @dev - `artifact_hash` is the keccak256 of the creation bytecode of the artifact, which tells which build a contract was deployed from.
+ * @dev - `label` is unique in a manifest (= the contract name for the contract suite, <label-prefix>-<NNN> for a fleet).
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeployedContract {
+    pub contract: String,
+    pub label: String,
+    pub address: Address,
+    pub tx_hash: TxHash,
+    pub block_number: Option<u64>,
+    pub artifact: PathBuf,
+    pub artifact_hash: B256,
+    pub constructor_args: Bytes,
+    pub deployed_at: u64, // @dev - UNIX timestamp
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>, // @dev - i.e. "fleet-2025-09" (= a fleet deployment), which batch runs select their targets by
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salt: Option<B256>, // @dev - The salt of a deterministic deployment through the CREATE2 factory (= create2.rs)
+}
+
+/**
+ * @notice - The deployment manifest of a chain (= deployments/<chain>.json), which every command reads the contract addresses from
+ */
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeploymentManifest {
+    #[serde(skip)]
+    path: PathBuf,
+    pub chain: String,
+    pub chain_id: u64,
+    pub contracts: Vec<DeployedContract>,
+}
+
+impl DeploymentManifest {
+    pub fn path_of<P: AsRef<Path>>(dir: P, chain: &ChainProfile) -> PathBuf {
+        dir.as_ref().join(format!("{}.json", chain.name))
+    }
+
+    /**
+     * @dev - An empty manifest of a chain, which is not backed by a file (i.e. the manifest of a fixture loaded into Anvil)
+     */
+    pub fn new(chain: &ChainProfile) -> Self {
+        DeploymentManifest { chain: chain.name.to_string(), chain_id: chain.chain_id, ..Default::default() }
+    }
+
+    /**
+     * @dev - Load the manifest of a chain. An empty manifest is returned if the file does not exist yet.
+     */
+    pub fn load<P: AsRef<Path>>(dir: P, chain: &ChainProfile) -> eyre::Result<Self> {
+        let path = Self::path_of(dir, chain);
+        let mut manifest: DeploymentManifest = if path.exists() {
+            let json = std::fs::read_to_string(&path).map_err(|e| eyre::eyre!("Failed to read {}: {}", path.display(), e))?;
+            serde_json::from_str(&json).map_err(|e| eyre::eyre!("Invalid deployment manifest {}: {}", path.display(), e))?
+        } else {
+            Self::new(chain)
+        };
+        if manifest.chain_id != chain.chain_id {
+            return Err(eyre::eyre!("{} is the manifest of the chain id {}, not {}", path.display(), manifest.chain_id, chain));
+        }
+        manifest.path = path;
+        Ok(manifest)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn save(&self) -> eyre::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, serde_json::to_string_pretty(self)? + "\n")
+            .map_err(|e| eyre::eyre!("Failed to write {}: {}", self.path.display(), e))
+    }
+
+    /**
+     * @dev - Add a deployed contract. A contract with the same label is replaced (= a re-deployment of the contract suite).
+     */
+    pub fn add(&mut self, deployed_contract: DeployedContract) {
+        self.contracts.retain(|contract| contract.label != deployed_contract.label);
+        self.contracts.push(deployed_contract);
+    }
+
+    pub fn by_label(&self, label: &str) -> Option<&DeployedContract> {
+        self.contracts.iter().find(|contract| contract.label == label)
+    }
+
+    /**
+     * @notice - The contracts whose label or one of whose tags is the selector, in the order of the manifest
+     */
+    pub fn select(&self, selector: &str) -> Vec<&DeployedContract> {
+        self.contracts
+            .iter()
+            .filter(|deployed| deployed.label == selector || deployed.tags.iter().any(|tag| tag == selector))
+            .collect()
+    }
+
+    /**
+     * @notice - The addresses of the contracts of a type, which are selected by comma-separated labels or tags (i.e. "fleet-2025-09,TravelHistoryManager")
+     * @dev - A selector which matches no contract of the type is an error, so that a typo does not silently skip targets.
+     */
+    pub fn select_addresses(&self, contract: &str, selectors: &str) -> eyre::Result<Vec<Address>> {
+        let mut addresses: Vec<Address> = Vec::new();
+        for selector in selectors.split(',').map(str::trim).filter(|selector| !selector.is_empty()) {
+            let selected: Vec<Address> =
+                self.select(selector).into_iter().filter(|deployed| deployed.contract == contract).map(|deployed| deployed.address).collect();
+            if selected.is_empty() {
+                return Err(eyre::eyre!("No {} is labelled or tagged with \"{}\" in {}", contract, selector, self.path.display()));
+            }
+            for address in selected {
+                if !addresses.contains(&address) {
+                    addresses.push(address);
+                }
+            }
+        }
+        Ok(addresses)
+    }
+
+    /**
+     * @dev - The next free labels of a fleet: <prefix>-<NNN>, numbered after the labels of the previous fleets with the same prefix
+     */
+    pub fn next_labels(&self, prefix: &str, count: usize) -> Vec<String> {
+        let next = self
+            .contracts
+            .iter()
+            .filter_map(|deployed| deployed.label.strip_prefix(prefix)?.strip_prefix('-')?.parse::<usize>().ok())
+            .max()
+            .map_or(1, |last| last + 1);
+        (next..next + count).map(|number| format!("{}-{:03}", prefix, number)).collect()
+    }
+
+    /**
+     * @notice - The latest deployment of a contract (i.e. "TravelHistoryManager")
+     */
+    pub fn latest(&self, contract: &str) -> Option<&DeployedContract> {
+        self.contracts.iter().filter(|deployed| deployed.contract == contract).max_by_key(|deployed| (deployed.block_number, deployed.deployed_at))
+    }
+
+    /**
+     * @notice - The address of a contract in the env var format (i.e. "TRAVEL_HISTORY_MANAGER"), which is the label of the contract suite
+     */
+    pub fn address_of(&self, env_name: &str) -> Option<Address> {
+        self.contracts
+            .iter()
+            .find(|deployed| deployed.label == deployed.contract && to_env_name(&deployed.contract) == env_name)
+            .or_else(|| self.contracts.iter().rev().find(|deployed| to_env_name(&deployed.contract) == env_name))
+            .map(|deployed| deployed.address)
+    }
+}
+
+/**
+ * @dev - The contract name in the env var format (i.e. "TravelHistoryManager" -> "TRAVEL_HISTORY_MANAGER")
+ */
+pub fn to_env_name(contract: &str) -> String {
+    let mut env_name = String::new();
+    let mut previous: Option<char> = None;
+    for c in contract.chars() {
+        if c.is_ascii_uppercase() && previous.is_some_and(|previous| !previous.is_ascii_uppercase()) {
+            env_name.push('_');
+        }
+        env_name.push(c.to_ascii_uppercase());
+        previous = Some(c);
+    }
+    env_name
+}
+
+/**
+ * @dev - The path of the artifact of a contract in a directory of Foundry artifacts (= <dir>/<Contract>.sol/<Contract>.json).
+ *        The HonkVerifier is generated as plonk_vk.sol (= circuits/build.sh).
+ */
+pub fn artifact_path<P: AsRef<Path>>(dir: P, contract: &str) -> PathBuf {
+    let file = if contract == "HonkVerifier" { "plonk_vk".to_string() } else { contract.to_string() };
+    dir.as_ref().join(format!("{}.sol", file)).join(format!("{}.json", contract))
+}
+
+/**
+ * @notice - Check that the artifacts of the contracts exist before anything is sent, or fail w/ the steps to build and copy the missing ones
+ * @dev - The verifier artifacts (= HonkVerifier, TravelHistoryProofVerifier) are not committed in artifacts/0910, since the HonkVerifier is generated from the circuit.
+ *        The steps run at the root directory (= /rs), and `forge build` writes the artifacts into ./out of the repository root (= ../../../..).
+ */
+pub fn ensure_artifacts(artifacts: &[(&str, PathBuf)]) -> eyre::Result<()> {
+    let missing: Vec<&(&str, PathBuf)> = artifacts.iter().filter(|(_, path)| !path.exists()).collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    let mut steps = vec!["(cd ../../../../circuits && sh build.sh)".to_string(), "(cd ../../../.. && forge build)".to_string()];
+    for (contract, path) in missing.iter() {
+        let out_path = artifact_path("../../../../out", contract);
+        let dir = path.parent().map(|dir| dir.display().to_string()).unwrap_or_default();
+        steps.push(format!("mkdir -p {} && cp {} {}", dir, out_path.display(), path.display()));
+    }
+    Err(eyre::eyre!(
+        "The artifacts of {} do not exist ({}). Build and copy them at the root directory (= /rs) first:\n  {}",
+        missing.iter().map(|(contract, _)| *contract).collect::<Vec<_>>().join(", "),
+        missing.iter().map(|(_, path)| path.display().to_string()).collect::<Vec<_>>().join(", "),
+        steps.join("\n  ")
+    ))
+}
+
+/**
+ * @notice - Load the artifacts of the contract suite (= CONTRACT_SUITE) from a directory, after checking that every one of them exists
+ */
+pub fn load_suite_artifacts(artifacts_dir: &Path) -> eyre::Result<Vec<ContractArtifact>> {
+    let paths: Vec<(&str, PathBuf)> = CONTRACT_SUITE.iter().map(|contract| (*contract, artifact_path(artifacts_dir, contract))).collect();
+    ensure_artifacts(&paths)?;
+    paths.into_iter().map(|(_, path)| ContractArtifact::load(path)).collect()
+}
+
+/**
+ * @notice - Deploy a contract from its artifact, and return its entry of the deployment manifest
+ */
+pub async fn deploy_contract<P: Provider<N>, N: Network>(
+    provider: &P,
+    artifact: &ContractArtifact,
+    contract: &str,
+    label: &str,
+    constructor_args: Bytes,
+) -> eyre::Result<DeployedContract> {
+    let deploy_tx = N::TransactionRequest::default().with_deploy_code(artifact.deploy_code(&constructor_args));
+    let receipt = provider.send_transaction(deploy_tx).await?.get_receipt().await?;
+    if !receipt.status() {
+        return Err(eyre::eyre!("The deployment of {} has been reverted (tx: {})", artifact.path.display(), receipt.transaction_hash()));
+    }
+    let address = receipt
+        .contract_address()
+        .ok_or_else(|| eyre::eyre!("The deployment of {} has no contract address", artifact.path.display()))?;
+
+    Ok(DeployedContract {
+        contract: contract.to_string(),
+        label: label.to_string(),
+        address,
+        tx_hash: receipt.transaction_hash(),
+        block_number: receipt.block_number(),
+        artifact: artifact.path.clone(),
+        artifact_hash: artifact.bytecode_hash(),
+        constructor_args,
+        deployed_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        tags: Vec::new(),
+        salt: None,
+    })
+}
+
+/**
+ * @notice - Deploy the contract suite (= CONTRACT_SUITE) in order, wiring the constructor arguments, and add each contract to the manifest.
+ * @dev - The manifest is saved after each deployment, so that a failure in the middle does not lose the contracts already deployed.
+ */
+pub async fn deploy_suite<P: Provider<N>, N: Network>(
+    provider: &P,
+    artifacts_dir: &Path,
+    manifest: &mut DeploymentManifest,
+) -> eyre::Result<Vec<DeployedContract>> {
+    // @dev - Load all the artifacts first, so that a missing artifact does not leave a partial deployment
+    let artifacts = load_suite_artifacts(artifacts_dir)?;
+
+    let mut deployed_contracts: Vec<DeployedContract> = Vec::new();
+    for (contract, artifact) in CONTRACT_SUITE.iter().zip(artifacts.iter()) {
+        let constructor_args = match *contract {
+            "TravelHistoryProofVerifier" => address_in(&deployed_contracts, "HonkVerifier").abi_encode(),
+            "TravelHistoryManager" => address_in(&deployed_contracts, "TravelHistoryProofVerifier").abi_encode(),
+            _ => Vec::new(),
+        };
+        let deployed_contract = deploy_contract(provider, artifact, contract, contract, Bytes::from(constructor_args)).await?;
+        manifest.add(deployed_contract.clone());
+        manifest.save()?;
+        deployed_contracts.push(deployed_contract);
+    }
+    Ok(deployed_contracts)
+}
+
+/**
+ * @notice - The constructor arguments of a contract wired to the latest deployments in the manifest (i.e. a TravelHistoryManager shares the TravelHistoryProofVerifier of the suite)
+ */
+pub fn constructor_args_from(manifest: &DeploymentManifest, contract: &str) -> eyre::Result<Bytes> {
+    let dependency = match contract {
+        "TravelHistoryProofVerifier" => "HonkVerifier",
+        "TravelHistoryManager" => "TravelHistoryProofVerifier",
+        _ => return Ok(Bytes::new()),
+    };
+    let deployed = manifest
+        .latest(dependency)
+        .ok_or_else(|| eyre::eyre!("{} needs a {}: deploy the contract suite first ({})", contract, dependency, manifest.path().display()))?;
+    Ok(Bytes::from(deployed.address.abi_encode()))
+}
+
+/**
+ * @notice - Deploy a fleet of instances of a contract (= one per label) in parallel, and add them to the manifest with the tags
+ * @dev - All the deployments are sent first with consecutive nonces from the pending nonce of the deployer (= they are mined in the same blocks),
+ *        and their receipts are awaited afterwards. A nonce is only consumed by a deployment which has been sent,
+ *        so that the instances already sent are still added to the manifest if a later one fails.
+ */
+pub async fn deploy_fleet<P: Provider<N>, N: Network>(
+    provider: &P,
+    deployer: Address,
+    artifact: &ContractArtifact,
+    contract: &str,
+    labels: &[String],
+    tags: &[String],
+    manifest: &mut DeploymentManifest,
+) -> eyre::Result<Vec<DeployedContract>> {
+    let constructor_args = constructor_args_from(manifest, contract)?;
+    let deploy_code = artifact.deploy_code(&constructor_args);
+    let mut nonce = provider.get_transaction_count(deployer).pending().await?;
+
+    // 1. Send all the deployments
+    let mut pending_deployments = Vec::new();
+    let mut send_error = None;
+    for label in labels.iter() {
+        let deploy_tx = N::TransactionRequest::default().with_from(deployer).with_nonce(nonce).with_deploy_code(deploy_code.clone());
+        match provider.send_transaction(deploy_tx).await {
+            Ok(pending_tx) => {
+                pending_deployments.push((label, pending_tx));
+                nonce += 1;
+            }
+            Err(e) => {
+                send_error = Some(eyre::eyre!("Failed to send the deployment of {}: {}", label, e));
+                break;
+            }
+        }
+    }
+
+    // 2. Await the receipts, and add each instance to the manifest
+    let mut deployed_contracts: Vec<DeployedContract> = Vec::new();
+    let mut receipt_errors: Vec<String> = Vec::new();
+    for (label, pending_tx) in pending_deployments {
+        let tx_hash = *pending_tx.tx_hash();
+        let receipt = match pending_tx.get_receipt().await {
+            Ok(receipt) => receipt,
+            Err(e) => {
+                receipt_errors.push(format!("{} (tx: {}): {}", label, tx_hash, e));
+                continue;
+            }
+        };
+        let Some(address) = receipt.contract_address().filter(|_| receipt.status()) else {
+            receipt_errors.push(format!("{} (tx: {}): the deployment has been reverted", label, tx_hash));
+            continue;
+        };
+        let deployed_contract = DeployedContract {
+            contract: contract.to_string(),
+            label: label.clone(),
+            address,
+            tx_hash,
+            block_number: receipt.block_number(),
+            artifact: artifact.path.clone(),
+            artifact_hash: artifact.bytecode_hash(),
+            constructor_args: constructor_args.clone(),
+            deployed_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            tags: tags.to_vec(),
+            salt: None,
+        };
+        manifest.add(deployed_contract.clone());
+        deployed_contracts.push(deployed_contract);
+    }
+    manifest.save()?;
+
+    if let Some(e) = send_error {
+        return Err(e.wrap_err(format!("{} of {} instances have been deployed into {}", deployed_contracts.len(), labels.len(), manifest.path().display())));
+    }
+    if !receipt_errors.is_empty() {
+        return Err(eyre::eyre!("{} of {} deployments have failed: {}", receipt_errors.len(), labels.len(), receipt_errors.join(", ")));
+    }
+    Ok(deployed_contracts)
+}
+
+/**
+ * @notice - The target addresses of a batch run of a contract (i.e. "TRAVEL_HISTORY_MANAGER")
+ * @dev - `<CONTRACT>_TARGETS` (= comma-separated labels or tags of the deployment manifest) is preferred over the address list in `<CONTRACT>_ON_<CHAIN>_LIST`.
+ */
+pub fn batch_targets(chain: &ChainProfile, env_name: &str) -> eyre::Result<Vec<Address>> {
+    let targets_env_var = format!("{}_TARGETS", env_name);
+    if let Ok(selectors) = std::env::var(&targets_env_var) {
+        let manifest = DeploymentManifest::load(DEFAULT_DEPLOYMENTS_PATH, chain)?;
+        let contract = CONTRACT_SUITE
+            .iter()
+            .find(|contract| to_env_name(contract) == env_name)
+            .ok_or_else(|| eyre::eyre!("{} is not a contract of the suite", env_name))?;
+        return manifest.select_addresses(contract, &selectors);
+    }
+
+    let list_env_var = format!("{}_ON_{}_LIST", env_name, chain.env_suffix);
+    let list = std::env::var(&list_env_var)
+        .map_err(|_| eyre::eyre!("Set {} (= labels or tags of the deployment manifest) or {} in your .env", targets_env_var, list_env_var))?;
+    parse_address_list(&list).map_err(|e| eyre::eyre!("Invalid address in {}: {}", list_env_var, e))
+}
+
+/**
+ * @dev - Parse an address list of the .env file (i.e. `["0x...", "0x..."]` or `0x...,0x...`)
+ */
+pub fn parse_address_list(list: &str) -> Result<Vec<Address>, alloy::hex::FromHexError> {
+    list.trim_matches(|c| c == '[' || c == ']' || c == ' ')
+        .split(',')
+        .map(|s| s.trim_matches(|c: char| c == '"' || c.is_whitespace()))
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse())
+        .collect()
+}
+
+fn address_in(deployed_contracts: &[DeployedContract], contract: &str) -> Address {
+    deployed_contracts
+        .iter()
+        .find(|deployed| deployed.contract == contract)
+        .map(|deployed| deployed.address)
+        .expect("the contract suite is deployed in order")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain::{BASE_MAINNET, CELO_MAINNET};
+
+    fn deployed(contract: &str, label: &str, address: u8, block_number: u64) -> DeployedContract {
+        DeployedContract {
+            contract: contract.to_string(),
+            label: label.to_string(),
+            address: Address::repeat_byte(address),
+            tx_hash: TxHash::repeat_byte(address),
+            block_number: Some(block_number),
+            artifact: artifact_path(DEFAULT_ARTIFACTS_PATH, contract),
+            artifact_hash: B256::ZERO,
+            constructor_args: Bytes::new(),
+            deployed_at: 1,
+            tags: Vec::new(),
+            salt: None,
+        }
+    }
+
+    #[test]
+    fn converts_contract_names_into_env_names() {
+        assert_eq!(to_env_name("TravelHistoryManager"), "TRAVEL_HISTORY_MANAGER");
+        assert_eq!(to_env_name("StakingPool"), "STAKING_POOL");
+        assert_eq!(to_env_name("HonkVerifier"), "HONK_VERIFIER");
+        assert_eq!(
+            artifact_path("artifacts/0910", "HonkVerifier"),
+            PathBuf::from("artifacts/0910/plonk_vk.sol/HonkVerifier.json")
+        );
+    }
+
+    #[test]
+    fn loads_every_artifact_of_the_suite_or_reports_the_steps_to_build_it() {
+        // @dev - The committed artifacts load, and the missing ones (= the verifiers built from the circuit) are reported w/ the steps to build and copy them
+        if let Err(e) = load_suite_artifacts(Path::new(DEFAULT_ARTIFACTS_PATH)) {
+            let error = e.to_string();
+            assert!(error.contains("forge build"), "{}", error);
+            for contract in CONTRACT_SUITE {
+                let path = artifact_path(DEFAULT_ARTIFACTS_PATH, contract);
+                if path.exists() {
+                    assert!(!ContractArtifact::load(&path).unwrap().bytecode.is_empty());
+                } else {
+                    assert!(error.contains(&format!("cp {} {}", artifact_path("../../../../out", contract).display(), path.display())), "{}", error);
+                }
+            }
+        }
+
+        // @dev - Once every artifact is there, the whole suite loads (= the TravelHistoryManager artifact stands in for the others)
+        let dir = std::env::temp_dir().join(format!("travel_history_suite_artifacts_{}", std::process::id()));
+        for contract in CONTRACT_SUITE {
+            let path = artifact_path(&dir, contract);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::copy(crate::artifact::TRAVEL_HISTORY_MANAGER_ARTIFACT_PATH, &path).unwrap();
+        }
+        let artifacts = load_suite_artifacts(&dir).unwrap();
+        assert_eq!(artifacts.iter().map(|artifact| artifact.path.clone()).collect::<Vec<_>>(), CONTRACT_SUITE.map(|contract| artifact_path(&dir, contract)));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn round_trips_a_manifest() {
+        let dir = std::env::temp_dir().join(format!("travel_history_deployments_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let mut manifest = DeploymentManifest::load(&dir, &BASE_MAINNET).unwrap();
+        assert!(manifest.contracts.is_empty());
+        manifest.add(deployed("TravelHistoryManager", "TravelHistoryManager", 1, 10));
+        manifest.add(deployed("TravelHistoryManager", "TravelHistoryManager", 2, 20));
+        manifest.add(deployed("StakingPool", "StakingPool", 3, 20));
+        manifest.save().unwrap();
+
+        let manifest = DeploymentManifest::load(&dir, &BASE_MAINNET).unwrap();
+        assert_eq!(manifest.contracts.len(), 2);
+        assert_eq!(manifest.address_of("TRAVEL_HISTORY_MANAGER"), Some(Address::repeat_byte(2)));
+        assert_eq!(manifest.latest("StakingPool").unwrap().address, Address::repeat_byte(3));
+        assert_eq!(manifest.address_of("TRAVEL_BOOKING_MANAGER"), None);
+
+        std::fs::copy(manifest.path(), DeploymentManifest::path_of(&dir, &CELO_MAINNET)).unwrap();
+        assert!(DeploymentManifest::load(&dir, &CELO_MAINNET).is_err());
+    }
+
+    #[test]
+    fn selects_a_fleet_by_label_or_tag() {
+        let mut manifest = DeploymentManifest::default();
+        manifest.add(deployed("TravelHistoryManager", "TravelHistoryManager", 1, 10));
+        assert_eq!(manifest.next_labels("fleet", 2), vec!["fleet-001", "fleet-002"]);
+        for (i, label) in manifest.next_labels("fleet", 2).into_iter().enumerate() {
+            let mut instance = deployed("TravelHistoryManager", &label, 2 + i as u8, 20);
+            instance.tags = vec!["fleet-2025-09".to_string()];
+            manifest.add(instance);
+        }
+        assert_eq!(manifest.next_labels("fleet", 1), vec!["fleet-003"]);
+
+        assert_eq!(
+            manifest.select_addresses("TravelHistoryManager", "fleet-2025-09").unwrap(),
+            vec![Address::repeat_byte(2), Address::repeat_byte(3)]
+        );
+        assert_eq!(
+            manifest.select_addresses("TravelHistoryManager", "TravelHistoryManager, fleet-002").unwrap(),
+            vec![Address::repeat_byte(1), Address::repeat_byte(3)]
+        );
+        assert!(manifest.select_addresses("StakingPool", "fleet-2025-09").is_err());
+        assert_eq!(
+            parse_address_list(&format!("[\"{}\", \"{}\"]", Address::repeat_byte(1), Address::repeat_byte(2))).unwrap(),
+            vec![Address::repeat_byte(1), Address::repeat_byte(2)]
+        );
+    }
+}
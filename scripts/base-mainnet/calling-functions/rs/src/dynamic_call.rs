@@ -0,0 +1,396 @@
+// @dev - Alloy
+use alloy::{
+    dyn_abi::{DynSolType, DynSolValue, FunctionExt, JsonAbiExt, Specifier},
+    json_abi::{Function, JsonAbi, Param, StateMutability},
+    network::{AnyNetwork, Network, ReceiptResponse, TransactionBuilder},
+    primitives::{utils::parse_units, Address, Bytes, TxHash, U256},
+    providers::Provider,
+};
+
+use crate::artifact::ContractArtifact;
+
+use serde_json::{Map, Value};
+use std::fmt;
+
+// @notice - The ABI-driven caller: any function of an artifact is called by its name or signature w/ JSON-encoded arguments (= alloy's dyn-abi),
+//           instead of a static `sol!` binding, and its return values (or its revert reason) are decoded from the ABI of the artifact.
+// @dev - An argument is a JSON value of its Solidity type: a string or a number for the (u)ints (= a string keeps the precision of a uint256), a hex string for the addresses and bytes,
+//        an array for the arrays and the tuples (or an object keyed by the component names for a tuple). The arguments are an array, or an object keyed by the parameter names.
+
+/**
+ * @notice - A call of a function of an artifact
+ */
+#[derive(Debug, Clone)]
+pub struct DynamicCall {
+    pub function: Function,
+    pub args: Vec<DynSolValue>,
+    pub value: U256,
+}
+
+impl DynamicCall {
+    /**
+     * @notice - Build a call from the name (i.e. "listAvailableRooms") or the signature (i.e. "listAvailableRooms(uint256,uint256)") of a function, and its JSON-encoded arguments
+     */
+    pub fn new(abi: &JsonAbi, name_or_signature: &str, json_args: &str, value: U256) -> eyre::Result<Self> {
+        let function = find_function(abi, name_or_signature)?.clone();
+        if !value.is_zero() && function.state_mutability != StateMutability::Payable {
+            return Err(eyre::eyre!("{} is not payable, so it cannot be called with a value", function.signature()));
+        }
+        let json: Value = serde_json::from_str(if json_args.trim().is_empty() { "[]" } else { json_args })
+            .map_err(|e| eyre::eyre!("The arguments are not a valid JSON: {}", e))?;
+        let args = args_from_json(&function.inputs, &json).map_err(|e| eyre::eyre!("Invalid arguments of {}: {}", function.signature(), e))?;
+        Ok(Self { function, args, value })
+    }
+
+    /**
+     * @dev - Whether or not the function does not change the state (= it is called w/ eth_call only)
+     */
+    pub fn is_view(&self) -> bool {
+        matches!(self.function.state_mutability, StateMutability::View | StateMutability::Pure)
+    }
+
+    pub fn calldata(&self) -> eyre::Result<Bytes> {
+        Ok(Bytes::from(self.function.abi_encode_input(&self.args)?))
+    }
+
+    pub fn transaction_request(&self, to: Address, from: Option<Address>) -> eyre::Result<<AnyNetwork as Network>::TransactionRequest> {
+        let mut tx = <AnyNetwork as Network>::TransactionRequest::default().with_to(to).with_input(self.calldata()?).with_value(self.value);
+        if let Some(from) = from {
+            tx = tx.with_from(from);
+        }
+        Ok(tx)
+    }
+
+    /**
+     * @notice - Decode the return data of the function into JSON (= an object keyed by the output names if every output is named, or an array)
+     */
+    pub fn decode_output(&self, data: &[u8]) -> eyre::Result<Value> {
+        let values = self.function.abi_decode_output(data)?;
+        Ok(params_to_json(&self.function.outputs, &values))
+    }
+}
+
+/**
+ * @notice - Find a function of an ABI by its name or its signature
+ * @dev - A name of an overloaded function is ambiguous: the signature is required then.
+ */
+pub fn find_function<'a>(abi: &'a JsonAbi, name_or_signature: &str) -> eyre::Result<&'a Function> {
+    let name_or_signature: String = name_or_signature.chars().filter(|c| !c.is_whitespace()).collect();
+    let candidates: Vec<&Function> = match name_or_signature.split_once('(') {
+        Some((name, _)) => abi.function(name).into_iter().flatten().filter(|function| function.signature() == name_or_signature).collect(),
+        None => abi.function(&name_or_signature).into_iter().flatten().collect(),
+    };
+    match candidates.as_slice() {
+        [function] => Ok(function),
+        [] => Err(eyre::eyre!("No function {} in the ABI", name_or_signature)),
+        overloads => Err(eyre::eyre!(
+            "{} is overloaded: call it by its signature ({})",
+            name_or_signature,
+            overloads.iter().map(|function| function.signature()).collect::<Vec<String>>().join(", ")
+        )),
+    }
+}
+
+/**
+ * @dev - The arguments of a function from a JSON array, or from a JSON object keyed by the parameter names
+ * @dev - A single array or tuple parameter may be given as it is (i.e. [1, 2] for f(uint256[]), {"roomId": 1, ...} for f((uint256,...))),
+ *        so it is tried before the JSON is taken as the list of the arguments (i.e. [[1, 2]] for f(uint256[])).
+ */
+pub fn args_from_json(params: &[Param], json: &Value) -> eyre::Result<Vec<DynSolValue>> {
+    if let [param] = params {
+        let ty = param.resolve()?;
+        let is_keyed_by_the_param = matches!(json, Value::Object(values) if values.contains_key(&param.name));
+        if matches!(ty, DynSolType::Array(_) | DynSolType::FixedArray(..) | DynSolType::Tuple(_)) && !is_keyed_by_the_param {
+            if let Ok(value) = value_from_json(&ty, &param.components, json) {
+                return Ok(vec![value]);
+            }
+        }
+    }
+
+    let values: Vec<&Value> = match json {
+        Value::Array(values) => values.iter().collect(),
+        Value::Object(values) => params
+            .iter()
+            .map(|param| values.get(&param.name).ok_or_else(|| eyre::eyre!("missing the argument {}", param.name)))
+            .collect::<eyre::Result<Vec<&Value>>>()?,
+        // @dev - A single argument may be given as it is (i.e. "hello" for checkpoint(string))
+        value if params.len() == 1 => vec![value],
+        _ => return Err(eyre::eyre!("the arguments must be a JSON array or object")),
+    };
+    if values.len() != params.len() {
+        return Err(eyre::eyre!("expected {} arguments, got {}", params.len(), values.len()));
+    }
+    params
+        .iter()
+        .zip(values)
+        .map(|(param, value)| {
+            let ty = param.resolve()?;
+            value_from_json(&ty, &param.components, value).map_err(|e| eyre::eyre!("{} ({}): {}", param.name, ty, e))
+        })
+        .collect()
+}
+
+fn value_from_json(ty: &DynSolType, components: &[Param], json: &Value) -> eyre::Result<DynSolValue> {
+    match (ty, json) {
+        // @dev - A string is taken as it is (= coerce_str strips its quotes)
+        (DynSolType::String, Value::String(s)) => Ok(DynSolValue::String(s.clone())),
+        (DynSolType::Bool, Value::Bool(b)) => Ok(DynSolValue::Bool(*b)),
+        (DynSolType::Array(inner), Value::Array(values)) => {
+            Ok(DynSolValue::Array(values.iter().map(|value| value_from_json(inner, components, value)).collect::<eyre::Result<_>>()?))
+        }
+        (DynSolType::FixedArray(inner, size), Value::Array(values)) => {
+            if values.len() != *size {
+                return Err(eyre::eyre!("expected {} elements, got {}", size, values.len()));
+            }
+            Ok(DynSolValue::FixedArray(values.iter().map(|value| value_from_json(inner, components, value)).collect::<eyre::Result<_>>()?))
+        }
+        (DynSolType::Tuple(types), Value::Array(_) | Value::Object(_)) => {
+            let values: Vec<&Value> = match json {
+                Value::Object(values) => components
+                    .iter()
+                    .map(|component| values.get(&component.name).ok_or_else(|| eyre::eyre!("missing the component {}", component.name)))
+                    .collect::<eyre::Result<_>>()?,
+                Value::Array(values) => values.iter().collect(),
+                _ => unreachable!(),
+            };
+            if values.len() != types.len() {
+                return Err(eyre::eyre!("expected {} components, got {}", types.len(), values.len()));
+            }
+            let tuple = types
+                .iter()
+                .zip(values)
+                .enumerate()
+                .map(|(i, (ty, value))| value_from_json(ty, components.get(i).map(|component| &component.components[..]).unwrap_or_default(), value))
+                .collect::<eyre::Result<_>>()?;
+            Ok(DynSolValue::Tuple(tuple))
+        }
+        (_, Value::String(s)) => Ok(ty.coerce_str(s)?),
+        (_, Value::Number(n)) => Ok(ty.coerce_str(&n.to_string())?),
+        (_, Value::Bool(b)) => Ok(ty.coerce_str(&b.to_string())?),
+        (_, other) => Err(eyre::eyre!("{} is not a value of this type", other)),
+    }
+}
+
+/**
+ * @dev - Decoded values into JSON: an object keyed by the parameter names if every parameter is named, or an array
+ */
+pub fn params_to_json(params: &[Param], values: &[DynSolValue]) -> Value {
+    let json: Vec<Value> = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| value_to_json(value, params.get(i).map(|param| &param.components[..]).unwrap_or_default()))
+        .collect();
+    if !params.is_empty() && params.iter().all(|param| !param.name.is_empty()) {
+        return Value::Object(params.iter().map(|param| param.name.clone()).zip(json).collect::<Map<String, Value>>());
+    }
+    Value::Array(json)
+}
+
+fn value_to_json(value: &DynSolValue, components: &[Param]) -> Value {
+    match value {
+        DynSolValue::Bool(b) => Value::Bool(*b),
+        // @dev - The (u)ints are decimal strings (= a JSON number loses the precision of a uint256)
+        DynSolValue::Int(i, _) => Value::String(i.to_string()),
+        DynSolValue::Uint(u, _) => Value::String(u.to_string()),
+        DynSolValue::FixedBytes(word, size) => Value::String(Bytes::copy_from_slice(&word[..*size]).to_string()),
+        DynSolValue::Address(address) => Value::String(address.to_checksum(None)),
+        DynSolValue::Function(function) => Value::String(function.to_string()),
+        DynSolValue::Bytes(bytes) => Value::String(Bytes::copy_from_slice(bytes).to_string()),
+        DynSolValue::String(s) => Value::String(s.clone()),
+        DynSolValue::Array(values) | DynSolValue::FixedArray(values) => Value::Array(values.iter().map(|value| value_to_json(value, components)).collect()),
+        DynSolValue::Tuple(values) => params_to_json(components, values),
+    }
+}
+
+/**
+ * @notice - Parse a value in wei (i.e. "1000"), or w/ a unit (i.e. "0.01ether", "1 gwei")
+ */
+pub fn parse_value(value: &str) -> eyre::Result<U256> {
+    let value = value.trim();
+    for unit in ["ether", "gwei", "wei"] {
+        if let Some(amount) = value.strip_suffix(unit) {
+            return Ok(parse_units(amount.trim(), unit).map_err(|e| eyre::eyre!("Invalid value {}: {}", value, e))?.get_absolute());
+        }
+    }
+    value.parse().map_err(|e| eyre::eyre!("Invalid value {} (expected wei, or i.e. 0.01ether): {}", value, e))
+}
+
+/**
+ * @notice - The result of a call simulated w/ eth_call
+ */
+#[derive(Debug, Clone, PartialEq)]
+pub enum CallOutcome {
+    Returned(Value),
+    Reverted(String),
+}
+
+impl fmt::Display for CallOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CallOutcome::Returned(value) => write!(f, "✅ returned: {}", serde_json::to_string_pretty(value).map_err(|_| fmt::Error)?),
+            CallOutcome::Reverted(reason) => write!(f, "❌ reverted: {}", reason),
+        }
+    }
+}
+
+/**
+ * @notice - The result of a call sent as a transaction
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SentCall {
+    pub tx_hash: TxHash,
+    pub block_number: Option<u64>,
+    pub gas_used: u64,
+    pub success: bool,
+}
+
+/**
+ * @notice - Call a function w/ eth_call, and decode its return values or its revert reason (= a custom error of the artifact, Error(string) or Panic(uint256))
+ */
+pub async fn simulate<P: Provider<AnyNetwork>>(
+    provider: &P,
+    artifact: &ContractArtifact,
+    to: Address,
+    from: Option<Address>,
+    call: &DynamicCall,
+) -> eyre::Result<CallOutcome> {
+    match provider.call(call.transaction_request(to, from)?).await {
+        Ok(output) => Ok(CallOutcome::Returned(call.decode_output(&output)?)),
+        Err(e) => match e.as_error_resp().and_then(|payload| payload.as_revert_data()) {
+            Some(data) => Ok(CallOutcome::Reverted(artifact.decode_revert(&data))),
+            None => Err(e.into()),
+        },
+    }
+}
+
+/**
+ * @notice - Send a call as a transaction from the signer of the provider, and await its receipt
+ */
+pub async fn send<P: Provider<AnyNetwork>>(provider: &P, to: Address, call: &DynamicCall) -> eyre::Result<SentCall> {
+    let receipt = provider.send_transaction(call.transaction_request(to, None)?).await?.get_receipt().await?;
+    Ok(SentCall { tx_hash: receipt.transaction_hash(), block_number: receipt.block_number(), gas_used: receipt.gas_used(), success: receipt.status() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deployment::deploy_contract;
+    use alloy::{providers::ProviderBuilder, signers::local::PrivateKeySigner};
+    use alloy_node_bindings::Anvil;
+    use serde_json::json;
+
+    const TRAVEL_BOOKING_MANAGER_ARTIFACT_PATH: &str = "artifacts/0910/TravelBookingManager.sol/TravelBookingManager.json";
+
+    #[test]
+    fn builds_calls_from_json_arguments() {
+        let artifact = ContractArtifact::load(TRAVEL_BOOKING_MANAGER_ARTIFACT_PATH).unwrap();
+        let call = DynamicCall::new(&artifact.abi, "listAvailableRooms", r#"["1", 10000000000000000]"#, U256::ZERO).unwrap();
+        assert_eq!(call.args, vec![DynSolValue::Uint(U256::from(1), 256), DynSolValue::Uint(U256::from(10_000_000_000_000_000u64), 256)]);
+        assert!(!call.is_view());
+
+        // @dev - By signature, w/ the arguments keyed by the parameter names, or a single argument as it is
+        let by_signature = DynamicCall::new(&artifact.abi, "checkpoint(string)", r#"{"methodName": "\"quoted\""}"#, U256::ZERO).unwrap();
+        assert_eq!(by_signature.args, vec![DynSolValue::String("\"quoted\"".to_string())]);
+        assert_eq!(DynamicCall::new(&artifact.abi, "checkpoint", r#""quoted""#, U256::ZERO).unwrap().args, vec![DynSolValue::String("quoted".to_string())]);
+
+        let is_booked = DynamicCall::new(&artifact.abi, "isBooked", r#"["0x70997970C51812dc3A010C7d01b50e0d17dc79C8", "0x2"]"#, U256::ZERO).unwrap();
+        assert!(is_booked.is_view());
+        assert_eq!(&is_booked.calldata().unwrap()[..4], is_booked.function.selector().as_slice());
+        assert_eq!(is_booked.decode_output(&U256::from(1).to_be_bytes::<32>()).unwrap(), json!([true]));
+
+        assert!(DynamicCall::new(&artifact.abi, "listAvailableRooms", r#"["1"]"#, U256::ZERO).is_err());
+        assert!(DynamicCall::new(&artifact.abi, "listAvailableRooms", r#"["one", "2"]"#, U256::ZERO).is_err());
+        assert!(DynamicCall::new(&artifact.abi, "registerAsBooker", "", U256::from(1)).is_err());
+        assert!(DynamicCall::new(&artifact.abi, "bookBooking", "[1]", U256::from(1)).is_ok());
+        assert!(DynamicCall::new(&artifact.abi, "noSuchFunction", "[]", U256::ZERO).is_err());
+    }
+
+    #[test]
+    fn resolves_overloads_and_tuples() {
+        let mut abi = JsonAbi::parse(["function book(uint256 roomId)", "function book(uint256 roomId, address booker)"]).unwrap();
+        let quote: Function = serde_json::from_value(json!({
+            "type": "function",
+            "name": "quote",
+            "stateMutability": "view",
+            "inputs": [{ "name": "stay", "type": "tuple", "components": [{ "name": "roomId", "type": "uint256" }, { "name": "nights", "type": "uint256[]" }] }],
+            "outputs": [{ "name": "quote", "type": "tuple", "components": [{ "name": "price", "type": "uint256" }, { "name": "owner", "type": "address" }] }]
+        }))
+        .unwrap();
+        abi.functions.insert("quote".to_string(), vec![quote]);
+        assert!(find_function(&abi, "book").unwrap_err().to_string().contains("book(uint256), book(uint256,address)"));
+        assert_eq!(find_function(&abi, "book(uint256, address)").unwrap().inputs.len(), 2);
+
+        let call = DynamicCall::new(&abi, "quote", r#"[{"roomId": 1, "nights": [1, 2]}]"#, U256::ZERO).unwrap();
+        let stay = DynSolValue::Tuple(vec![
+            DynSolValue::Uint(U256::from(1), 256),
+            DynSolValue::Array(vec![DynSolValue::Uint(U256::from(1), 256), DynSolValue::Uint(U256::from(2), 256)]),
+        ]);
+        assert_eq!(call.args, vec![stay]);
+
+        let output = DynSolValue::Tuple(vec![DynSolValue::Tuple(vec![DynSolValue::Uint(U256::from(5), 256), DynSolValue::Address(Address::ZERO)])]);
+        assert_eq!(
+            call.decode_output(&output.abi_encode_params()).unwrap(),
+            json!({ "quote": { "price": "5", "owner": "0x0000000000000000000000000000000000000000" } })
+        );
+    }
+
+    #[test]
+    fn takes_a_single_array_or_tuple_argument_as_it_is() {
+        let mut abi = JsonAbi::parse(["function sum(uint256[] values)", "function pair(uint256[2] values)"]).unwrap();
+        let quote: Function = serde_json::from_value(json!({
+            "type": "function",
+            "name": "quote",
+            "stateMutability": "view",
+            "inputs": [{ "name": "stay", "type": "tuple", "components": [{ "name": "roomId", "type": "uint256" }, { "name": "nights", "type": "uint256" }] }],
+            "outputs": []
+        }))
+        .unwrap();
+        abi.functions.insert("quote".to_string(), vec![quote]);
+
+        let values = DynSolValue::Array(vec![DynSolValue::Uint(U256::from(1), 256), DynSolValue::Uint(U256::from(2), 256)]);
+        assert_eq!(DynamicCall::new(&abi, "sum", "[1, 2]", U256::ZERO).unwrap().args, vec![values.clone()]);
+        // @dev - The list of the arguments, or the arguments keyed by the parameter names
+        assert_eq!(DynamicCall::new(&abi, "sum", "[[1, 2]]", U256::ZERO).unwrap().args, vec![values.clone()]);
+        assert_eq!(DynamicCall::new(&abi, "sum", r#"{"values": [1, 2]}"#, U256::ZERO).unwrap().args, vec![values]);
+        assert_eq!(DynamicCall::new(&abi, "sum", "[]", U256::ZERO).unwrap().args, vec![DynSolValue::Array(Vec::new())]);
+
+        let pair = DynSolValue::FixedArray(vec![DynSolValue::Uint(U256::from(3), 256), DynSolValue::Uint(U256::from(4), 256)]);
+        assert_eq!(DynamicCall::new(&abi, "pair", "[3, 4]", U256::ZERO).unwrap().args, vec![pair]);
+        assert!(DynamicCall::new(&abi, "pair", "[3, 4, 5]", U256::ZERO).is_err());
+
+        let stay = DynSolValue::Tuple(vec![DynSolValue::Uint(U256::from(1), 256), DynSolValue::Uint(U256::from(7), 256)]);
+        assert_eq!(DynamicCall::new(&abi, "quote", "[1, 7]", U256::ZERO).unwrap().args, vec![stay.clone()]);
+        assert_eq!(DynamicCall::new(&abi, "quote", r#"{"roomId": 1, "nights": 7}"#, U256::ZERO).unwrap().args, vec![stay.clone()]);
+        assert_eq!(DynamicCall::new(&abi, "quote", r#"[{"roomId": 1, "nights": 7}]"#, U256::ZERO).unwrap().args, vec![stay]);
+    }
+
+    #[test]
+    fn parses_values_with_units() {
+        assert_eq!(parse_value("1000").unwrap(), U256::from(1000));
+        assert_eq!(parse_value("0.01ether").unwrap(), U256::from(10_000_000_000_000_000u64));
+        assert_eq!(parse_value("2 gwei").unwrap(), U256::from(2_000_000_000u64));
+        assert!(parse_value("1 btc").is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires Anvil (install it via `foundryup`)"]
+    async fn calls_any_function_of_a_deployed_artifact() {
+        let anvil = Anvil::new().spawn();
+        let signer: PrivateKeySigner = anvil.keys()[0].clone().into();
+        let booker = signer.address();
+        let provider = ProviderBuilder::new().with_gas_estimation().network::<AnyNetwork>().wallet(signer).connect_http(anvil.endpoint_url());
+        let artifact = ContractArtifact::load(TRAVEL_BOOKING_MANAGER_ARTIFACT_PATH).unwrap();
+        let address = deploy_contract(&provider, &artifact, "TravelBookingManager", "TravelBookingManager", Bytes::new()).await.unwrap().address;
+
+        let register = DynamicCall::new(&artifact.abi, "registerAsBooker", "[]", U256::ZERO).unwrap();
+        assert_eq!(simulate(&provider, &artifact, address, Some(booker), &register).await.unwrap(), CallOutcome::Returned(json!([true])));
+        assert!(send(&provider, address, &register).await.unwrap().success);
+
+        let is_booker = DynamicCall::new(&artifact.abi, "isBooker", &format!(r#"["{}"]"#, booker), U256::ZERO).unwrap();
+        assert_eq!(simulate(&provider, &artifact, address, None, &is_booker).await.unwrap(), CallOutcome::Returned(json!([true])));
+        assert_eq!(
+            simulate(&provider, &artifact, address, Some(booker), &register).await.unwrap(),
+            CallOutcome::Reverted("Booker already exists".to_string())
+        );
+    }
+}
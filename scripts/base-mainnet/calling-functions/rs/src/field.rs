@@ -0,0 +1,45 @@
+// @dev - Alloy
+use alloy::primitives::{FixedBytes, U256};
+
+// @dev - Noir / ACVM
+use acvm::{AcirField, FieldElement};
+
+/// @dev - The modulus of the BN254 scalar field (= the `Field` of Noir)
+pub const BN254_MODULUS: U256 = U256::from_limbs([
+    0x43e1f593f0000001,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+
+/**
+ * @notice - Parse a field element from a decimal or "0x"-prefixed hex string (i.e. a value in Prover.toml)
+ * @dev - Unlike FieldElement::try_from_str(), a value which is >= the BN254 modulus is rejected instead of being reduced.
+ */
+pub fn parse_field(value: &str) -> eyre::Result<FieldElement> {
+    let value = value.trim();
+    let parsed: U256 = if let Some(hex) = value.strip_prefix("0x") {
+        U256::from_str_radix(hex, 16).map_err(|e| eyre::eyre!("Invalid hex value \"{}\": {}", value, e))?
+    } else {
+        U256::from_str_radix(value, 10).map_err(|e| eyre::eyre!("Invalid decimal value \"{}\": {}", value, e))?
+    };
+    u256_to_field(parsed)
+}
+
+pub fn u256_to_field(value: U256) -> eyre::Result<FieldElement> {
+    if value >= BN254_MODULUS {
+        return Err(eyre::eyre!("{:#x} overflows the BN254 field modulus", value));
+    }
+    Ok(FieldElement::from_be_bytes_reduce(&value.to_be_bytes::<32>()))
+}
+
+/**
+ * @dev - Convert a field element into a bytes32 (big-endian), which is the format of the publicInputs of a proof
+ */
+pub fn field_to_bytes32(value: FieldElement) -> FixedBytes<32> {
+    FixedBytes::left_padding_from(&value.to_be_bytes())
+}
+
+pub fn bytes32_to_field(value: FixedBytes<32>) -> eyre::Result<FieldElement> {
+    u256_to_field(U256::from_be_bytes(value.0))
+}
@@ -0,0 +1,349 @@
+// @dev - Alloy
+use alloy::{
+    network::{AnyNetwork, ReceiptResponse},
+    primitives::{keccak256, Address, Bytes, B256, U256},
+    providers::{DynProvider, PendingTransactionBuilder, Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+    transports::http::reqwest::Url,
+};
+use alloy_node_bindings::{Anvil, AnvilInstance};
+
+use crate::artifact_registry::ArtifactRegistry;
+use crate::chain::LOCAL_ANVIL;
+use crate::contracts::{StakingPool, TravelBookingManager, TravelHistoryManager};
+use crate::deployment::{DeployedContract, DeploymentManifest};
+use crate::local_anvil::{deploy_local_contracts, MockInjection, MockVerifier, LOCAL_CONTRACTS};
+use crate::public_inputs::TravelPublicInputs;
+use crate::test_vectors;
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// @notice - The Anvil state fixtures: the contract suite is deployed on Anvil (w/ the always-true mock verifier), the state is seeded (= travelers, property owners w/ listed rooms, OTAs, stakers and recorded proofs),
+//           and dumped with `anvil_dumpState` into a fixture file (= fixtures/<name>.v<format version>.json), which a test loads into a fresh Anvil with `anvil_loadState`.
+// @dev - A fixture records the hashes of the artifacts it has been built from, and is rejected once an artifact changes (= rebuild it with the `build_fixture` command).
+
+/// @dev - The default directory of the fixture files, relative to the root directory (= /rs)
+pub const DEFAULT_FIXTURES_PATH: &str = "fixtures";
+
+/// @dev - The format version of the fixture files, which is bumped when the format (or the seeding) changes
+pub const FIXTURE_FORMAT_VERSION: u32 = 1;
+
+/**
+ * @notice - What a fixture is seeded with
+ * @dev - Each role has its own development accounts (= account 0 is the deployer), so Anvil is spawned with `accounts()` accounts.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixtureSpec {
+    pub travelers: usize,
+    pub proofs_per_traveler: usize,
+    pub property_owners: usize,
+    pub rooms_per_owner: usize,
+    pub room_price_wei: U256,
+    pub otas: usize,
+    pub stakers: usize,
+    pub stake_wei: U256,
+    pub seed: u64, // @dev - The seed of the test vectors of the recorded proofs
+}
+
+impl Default for FixtureSpec {
+    fn default() -> Self {
+        Self {
+            travelers: 2,
+            proofs_per_traveler: 2,
+            property_owners: 2,
+            rooms_per_owner: 2,
+            room_price_wei: U256::from(10u64).pow(U256::from(16)), // @dev - 0.01 ETH
+            otas: 1,
+            stakers: 2,
+            stake_wei: U256::from(10u64).pow(U256::from(18)), // @dev - 1 ETH
+            seed: 1,
+        }
+    }
+}
+
+impl FixtureSpec {
+    pub fn accounts(&self) -> usize {
+        1 + self.travelers + self.property_owners + self.otas + self.stakers
+    }
+}
+
+/**
+ * @notice - The development accounts of each role of a fixture
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FixtureAccounts {
+    pub deployer: Address,
+    pub travelers: Vec<Address>,
+    pub property_owners: Vec<Address>,
+    pub otas: Vec<Address>,
+    pub stakers: Vec<Address>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ListedRoom {
+    pub owner: Address,
+    pub room_id: U256,
+    pub price_wei: U256,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedProof {
+    pub traveler: Address,
+    pub proof: Bytes,
+    pub public_inputs: Vec<B256>,
+}
+
+/**
+ * @notice - A fixture file
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fixture {
+    pub format_version: u32,
+    pub name: String,
+    pub created_at: u64, // @dev - UNIX timestamp
+    pub spec: FixtureSpec,
+    pub artifact_hashes: BTreeMap<String, B256>,
+    pub contracts: Vec<DeployedContract>,
+    pub accounts: FixtureAccounts,
+    pub rooms: Vec<ListedRoom>,
+    pub proofs: Vec<RecordedProof>,
+    pub state: Bytes, // @dev - The output of `anvil_dumpState`
+}
+
+/**
+ * @notice - A fixture loaded into a fresh Anvil
+ * @dev - The Anvil is killed when this is dropped.
+ */
+pub struct LoadedFixture {
+    pub anvil: AnvilInstance,
+    pub provider: DynProvider<AnyNetwork>,
+    pub signers: Vec<PrivateKeySigner>,
+    pub manifest: DeploymentManifest,
+}
+
+impl Fixture {
+    pub fn path_of<P: AsRef<Path>>(dir: P, name: &str) -> PathBuf {
+        dir.as_ref().join(format!("{}.v{}.json", name, FIXTURE_FORMAT_VERSION))
+    }
+
+    /**
+     * @notice - Build a fixture: spawn Anvil, deploy the contract suite, seed the state, and dump it
+     */
+    pub async fn build(name: &str, spec: &FixtureSpec, registry: &ArtifactRegistry) -> eyre::Result<Self> {
+        let anvil = spawn_anvil(spec.accounts())?;
+        let rpc_url = anvil.endpoint_url();
+        let signers: Vec<PrivateKeySigner> = anvil.keys().iter().map(|key| key.clone().into()).collect();
+        let deployer = provider_of(&signers[0], &rpc_url);
+
+        // 1. Deploy the contract suite (w/ the always-true mock verifier)
+        let contracts = deploy_local_contracts(&deployer, registry, &MockVerifier::AlwaysTrue, MockInjection::Deploy).await?;
+        let address_of = |contract: &str| contracts.iter().find(|deployed| deployed.contract == contract).map(|deployed| deployed.address).expect("deployed");
+
+        // 2. Assign the development accounts to the roles
+        let mut accounts = signers[1..].iter();
+        let mut take = |count: usize| accounts.by_ref().take(count).cloned().collect::<Vec<PrivateKeySigner>>();
+        let (travelers, property_owners, otas, stakers) = (take(spec.travelers), take(spec.property_owners), take(spec.otas), take(spec.stakers));
+
+        // 3. Seed the state
+        let mut cases = test_vectors::generate(spec.seed, spec.travelers * spec.proofs_per_traveler)?
+            .into_iter()
+            .filter(|case| case.should_fail_with.is_none());
+        let mut proofs: Vec<RecordedProof> = Vec::new();
+        for traveler in travelers.iter() {
+            let provider = provider_of(traveler, &rpc_url);
+            let travel_history_manager = TravelHistoryManager::new(address_of("TravelHistoryManager"), &provider);
+            confirm(travel_history_manager.registerAsTraveler().send().await?, "registerAsTraveler").await?;
+            for case in cases.by_ref().take(spec.proofs_per_traveler) {
+                let proof = Bytes::from(keccak256(format!("fixture proof: {}", case.name)).to_vec());
+                let public_inputs = TravelPublicInputs::from_inputs(&case.inputs).to_bytes32s();
+                confirm(travel_history_manager.recordTravelHistoryProof(proof.clone(), public_inputs.clone()).send().await?, "recordTravelHistoryProof").await?;
+                proofs.push(RecordedProof { traveler: traveler.address(), proof, public_inputs });
+            }
+        }
+
+        let mut rooms: Vec<ListedRoom> = Vec::new();
+        for owner in property_owners.iter() {
+            let provider = provider_of(owner, &rpc_url);
+            let travel_booking_manager = TravelBookingManager::new(address_of("TravelBookingManager"), &provider);
+            confirm(travel_booking_manager.registerAsPropertyOwner().send().await?, "registerAsPropertyOwner").await?;
+            for _ in 0..spec.rooms_per_owner {
+                let room_id = U256::from(rooms.len() + 1);
+                confirm(travel_booking_manager.listAvailableRooms(room_id, spec.room_price_wei).send().await?, "listAvailableRooms").await?;
+                rooms.push(ListedRoom { owner: owner.address(), room_id, price_wei: spec.room_price_wei });
+            }
+        }
+
+        for ota in otas.iter() {
+            let provider = provider_of(ota, &rpc_url);
+            let travel_booking_manager = TravelBookingManager::new(address_of("TravelBookingManager"), &provider);
+            confirm(travel_booking_manager.registerAsOTA().send().await?, "registerAsOTA").await?;
+        }
+
+        for staker in stakers.iter() {
+            let provider = provider_of(staker, &rpc_url);
+            let staking_pool = StakingPool::new(address_of("StakingPool"), &provider);
+            confirm(staking_pool.registerAsStaker().send().await?, "registerAsStaker").await?;
+            confirm(staking_pool.stakeNativeTokenIntoStakingPool().value(spec.stake_wei).send().await?, "stakeNativeTokenIntoStakingPool").await?;
+        }
+
+        // 4. Dump the state
+        let state: Bytes = deployer.raw_request("anvil_dumpState".into(), ()).await?;
+
+        let addresses = |signers: &[PrivateKeySigner]| signers.iter().map(|signer| signer.address()).collect::<Vec<Address>>();
+        Ok(Self {
+            format_version: FIXTURE_FORMAT_VERSION,
+            name: name.to_string(),
+            created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            spec: spec.clone(),
+            artifact_hashes: artifact_hashes(registry)?,
+            contracts,
+            accounts: FixtureAccounts {
+                deployer: signers[0].address(),
+                travelers: addresses(&travelers),
+                property_owners: addresses(&property_owners),
+                otas: addresses(&otas),
+                stakers: addresses(&stakers),
+            },
+            rooms,
+            proofs,
+            state,
+        })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, dir: P) -> eyre::Result<PathBuf> {
+        let path = Self::path_of(&dir, &self.name);
+        std::fs::create_dir_all(dir.as_ref())?;
+        std::fs::write(&path, serde_json::to_string_pretty(self)? + "\n").map_err(|e| eyre::eyre!("Failed to write {}: {}", path.display(), e))?;
+        Ok(path)
+    }
+
+    /**
+     * @notice - Load a fixture file of the current format version, which has been built from the current artifacts of the registry
+     */
+    pub fn load<P: AsRef<Path>>(dir: P, name: &str, registry: &ArtifactRegistry) -> eyre::Result<Self> {
+        let path = Self::path_of(&dir, name);
+        let json = std::fs::read_to_string(&path)
+            .map_err(|e| eyre::eyre!("Failed to read the fixture {} (build it via the `build_fixture` command): {}", path.display(), e))?;
+        let fixture: Fixture = serde_json::from_str(&json).map_err(|e| eyre::eyre!("Invalid fixture {}: {}", path.display(), e))?;
+        if fixture.format_version != FIXTURE_FORMAT_VERSION {
+            return Err(eyre::eyre!("{} has the format version {}, not {}", path.display(), fixture.format_version, FIXTURE_FORMAT_VERSION));
+        }
+        let current_hashes = artifact_hashes(registry)?;
+        if fixture.artifact_hashes != current_hashes {
+            return Err(eyre::eyre!("{} has been built from other artifacts than the current ones: rebuild it via the `build_fixture` command", path.display()));
+        }
+        Ok(fixture)
+    }
+
+    /**
+     * @notice - Load the state of this fixture into a fresh Anvil
+     */
+    pub async fn spawn(&self) -> eyre::Result<LoadedFixture> {
+        let anvil = spawn_anvil(self.spec.accounts())?;
+        let signers: Vec<PrivateKeySigner> = anvil.keys().iter().map(|key| key.clone().into()).collect();
+        let provider = provider_of(&signers[0], &anvil.endpoint_url());
+        let loaded: bool = provider.raw_request("anvil_loadState".into(), (self.state.clone(),)).await?;
+        if !loaded {
+            return Err(eyre::eyre!("Anvil has failed to load the state of the fixture {}", self.name));
+        }
+
+        let mut manifest = DeploymentManifest::new(&LOCAL_ANVIL);
+        for deployed in self.contracts.iter() {
+            manifest.add(deployed.clone());
+        }
+        Ok(LoadedFixture { anvil, provider, signers, manifest })
+    }
+}
+
+/**
+ * @dev - The hashes of the latest artifacts of the contracts of the local-Anvil mode in the registry
+ */
+fn artifact_hashes(registry: &ArtifactRegistry) -> eyre::Result<BTreeMap<String, B256>> {
+    LOCAL_CONTRACTS
+        .iter()
+        .map(|contract| Ok((contract.to_string(), registry.latest(contract)?.bytecode_hash())))
+        .collect()
+}
+
+fn spawn_anvil(accounts: usize) -> eyre::Result<AnvilInstance> {
+    Anvil::new()
+        .arg("--accounts")
+        .arg(accounts.to_string())
+        .try_spawn()
+        .map_err(|e| eyre::eyre!("Failed to spawn Anvil (install it via `foundryup`): {}", e))
+}
+
+fn provider_of(signer: &PrivateKeySigner, rpc_url: &Url) -> DynProvider<AnyNetwork> {
+    ProviderBuilder::new()
+        .with_gas_estimation()
+        .network::<AnyNetwork>()
+        .wallet(signer.clone())
+        .connect_http(rpc_url.clone())
+        .erased()
+}
+
+async fn confirm(pending_tx: PendingTransactionBuilder<AnyNetwork>, function: &str) -> eyre::Result<()> {
+    let receipt = pending_tx.get_receipt().await?;
+    if !receipt.status() {
+        return Err(eyre::eyre!("{}() has been reverted while seeding the fixture (tx: {})", function, receipt.transaction_hash()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact_registry::DEFAULT_ARTIFACT_REGISTRY_PATH;
+
+    #[test]
+    fn rejects_stale_fixtures() {
+        let dir = std::env::temp_dir().join(format!("travel_history_fixtures_{}", std::process::id()));
+        let registry = ArtifactRegistry::load(DEFAULT_ARTIFACT_REGISTRY_PATH).unwrap();
+        let mut fixture = Fixture {
+            format_version: FIXTURE_FORMAT_VERSION,
+            name: "empty".to_string(),
+            created_at: 0,
+            spec: FixtureSpec::default(),
+            artifact_hashes: artifact_hashes(&registry).unwrap(),
+            contracts: Vec::new(),
+            accounts: FixtureAccounts::default(),
+            rooms: Vec::new(),
+            proofs: Vec::new(),
+            state: Bytes::new(),
+        };
+        assert_eq!(fixture.save(&dir).unwrap(), dir.join(format!("empty.v{}.json", FIXTURE_FORMAT_VERSION)));
+        assert_eq!(Fixture::load(&dir, "empty", &registry).unwrap().spec, FixtureSpec::default());
+
+        fixture.artifact_hashes.insert("StakingPool".to_string(), B256::ZERO);
+        fixture.save(&dir).unwrap();
+        assert!(Fixture::load(&dir, "empty", &registry).is_err());
+        assert!(Fixture::load(&dir, "missing", &registry).is_err());
+        assert_eq!(FixtureSpec::default().accounts(), 8);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires Anvil (install it via `foundryup`)"]
+    async fn loads_a_seeded_fixture_into_a_fresh_anvil() {
+        let registry = ArtifactRegistry::load(DEFAULT_ARTIFACT_REGISTRY_PATH).unwrap();
+        let fixture = Fixture::build("test", &FixtureSpec::default(), &registry).await.unwrap();
+        assert_eq!(fixture.proofs.len(), 4);
+        assert_eq!(fixture.rooms.len(), 4);
+
+        let loaded = fixture.spawn().await.unwrap();
+
+        let travel_history_manager = TravelHistoryManager::new(loaded.manifest.latest("TravelHistoryManager").unwrap().address, &loaded.provider);
+        let proof = &fixture.proofs[0];
+        assert!(travel_history_manager.travelers(proof.traveler).call().await.unwrap());
+        assert!(travel_history_manager.isTravelHistoryProofRecorded(proof.traveler, proof.proof.clone()).call().await.unwrap());
+
+        let travel_booking_manager = TravelBookingManager::new(loaded.manifest.latest("TravelBookingManager").unwrap().address, &loaded.provider);
+        assert!(travel_booking_manager.listedRoomes(fixture.rooms[0].room_id).call().await.unwrap());
+        assert!(travel_booking_manager.OTAs(fixture.accounts.otas[0]).call().await.unwrap());
+
+        let staking_pool = StakingPool::new(loaded.manifest.latest("StakingPool").unwrap().address, &loaded.provider);
+        assert_eq!(staking_pool.stakedAmounts(fixture.accounts.stakers[0]).call().await.unwrap(), fixture.spec.stake_wei);
+    }
+}
@@ -0,0 +1,271 @@
+// @dev - Alloy
+use alloy::{
+    network::AnyNetwork,
+    primitives::{Address, Bytes},
+    providers::{Provider, ProviderBuilder},
+    transports::http::reqwest::Url,
+};
+use alloy_node_bindings::{Anvil, AnvilInstance};
+
+use crate::chain::ChainProfile;
+use crate::checkpoint_run::{ForkOrigin, RunSigner, RunTarget};
+use crate::contracts::{StakingPool, TravelBookingManager, TravelHistoryManager};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// @notice - The mainnet-fork mode: a local Anvil fork of Base / Celo Mainnet at a pinned block, w/ the real deployed TravelHistoryManager / TravelBookingManager / StakingPool instances and the real signer balances,
+//           which a batch run is rehearsed against before it is sent for real (= the same run report, w/ the fork recorded in it).
+// @dev - The fork source is the RPC of the chain, another RPC (i.e. a local archive node, = `<CHAIN>_FORK_URL`), or a cached fork state (= `anvil_dumpState` of a fork), which keeps the tests offline.
+
+/// @dev - The default directory of the cached fork states (= <dir>/<chain>-<block>.json), relative to the root directory (= /rs)
+pub const DEFAULT_FORK_STATES_PATH: &str = "data/fork-states";
+
+/**
+ * @notice - Where a fork is started from
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ForkSource {
+    Rpc(Url),
+    CachedState(PathBuf),
+}
+
+impl ForkSource {
+    /**
+     * @dev - The default fork source of a chain: `<CHAIN>_FORK_URL` (i.e. BASE_MAINNET_FORK_URL of a local archive node) if it is set, or the RPC of the chain
+     */
+    pub fn of_chain(chain: &ChainProfile) -> eyre::Result<Self> {
+        let env_var = format!("{}_FORK_URL", chain.env_suffix);
+        match std::env::var(&env_var) {
+            Ok(fork_url) if !fork_url.trim().is_empty() => fork_url.trim().parse(),
+            _ => Ok(ForkSource::Rpc(chain.rpc_url()?)),
+        }
+    }
+}
+
+impl FromStr for ForkSource {
+    type Err = eyre::Report;
+
+    /**
+     * @dev - An URL (= http(s):// or ws(s)://) is an RPC, and anything else is the path of a cached fork state
+     */
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if ["http://", "https://", "ws://", "wss://"].iter().any(|scheme| s.starts_with(scheme)) {
+            return Ok(ForkSource::Rpc(s.parse().map_err(|e| eyre::eyre!("Invalid fork URL {}: {}", s, e))?));
+        }
+        Ok(ForkSource::CachedState(PathBuf::from(s)))
+    }
+}
+
+impl fmt::Display for ForkSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ForkSource::Rpc(url) => write!(f, "{}", url),
+            ForkSource::CachedState(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/**
+ * @notice - A cached fork state (= `anvil_dumpState` of a fork at a pinned block), which a fork is started from offline
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedForkState {
+    pub chain: String,
+    pub chain_id: u64,
+    pub block_number: u64,
+    pub created_at: u64, // @dev - UNIX timestamp
+    pub state: Bytes,
+}
+
+impl CachedForkState {
+    pub fn path_of<P: AsRef<Path>>(dir: P, chain: &ChainProfile, block_number: u64) -> PathBuf {
+        dir.as_ref().join(format!("{}-{}.json", chain.name, block_number))
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| eyre::eyre!("Failed to read the cached fork state {}: {}", path.display(), e))?;
+        serde_json::from_str(&json).map_err(|e| eyre::eyre!("Invalid cached fork state {}: {}", path.display(), e))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, dir: P, chain: &ChainProfile) -> eyre::Result<PathBuf> {
+        std::fs::create_dir_all(dir.as_ref())?;
+        let path = Self::path_of(&dir, chain, self.block_number);
+        std::fs::write(&path, serde_json::to_string_pretty(self)? + "\n").map_err(|e| eyre::eyre!("Failed to write {}: {}", path.display(), e))?;
+        Ok(path)
+    }
+}
+
+/**
+ * @notice - A local Anvil fork of a chain at a pinned block
+ * @dev - The Anvil is killed when this is dropped.
+ */
+pub struct MainnetFork {
+    pub anvil: AnvilInstance,
+    pub rpc_url: Url,
+    pub chain: ChainProfile,
+    pub block_number: u64,
+    pub source: ForkSource,
+    pub impersonated: Vec<Address>,
+}
+
+impl MainnetFork {
+    /**
+     * @notice - Fork a chain at a pinned block (= the latest block of the source if `block_number` is None)
+     */
+    pub async fn start(chain: &ChainProfile, source: ForkSource, block_number: Option<u64>) -> eyre::Result<Self> {
+        let (anvil, block_number) = match &source {
+            ForkSource::Rpc(fork_url) => {
+                let provider = ProviderBuilder::new().network::<AnyNetwork>().connect_http(fork_url.clone());
+                let chain_id = provider.get_chain_id().await?;
+                if chain_id != chain.chain_id {
+                    return Err(eyre::eyre!("The fork source {} is an RPC of the chain id {}, not {}", fork_url, chain_id, chain));
+                }
+                let block_number = match block_number {
+                    Some(block_number) => block_number,
+                    None => provider.get_block_number().await?,
+                };
+                let anvil = Anvil::new().fork(fork_url.to_string()).fork_block_number(block_number).try_spawn().map_err(spawn_error)?;
+                (anvil, block_number)
+            }
+            ForkSource::CachedState(path) => {
+                let cached = CachedForkState::load(path)?;
+                if cached.chain_id != chain.chain_id {
+                    return Err(eyre::eyre!("{} is a fork state of the chain id {}, not {}", path.display(), cached.chain_id, chain));
+                }
+                if block_number.is_some_and(|block_number| block_number != cached.block_number) {
+                    return Err(eyre::eyre!("{} is a fork state at block {}, not {}", path.display(), cached.block_number, block_number.unwrap_or_default()));
+                }
+                let anvil = Anvil::new().arg("--chain-id").arg(cached.chain_id.to_string()).try_spawn().map_err(spawn_error)?;
+                let provider = ProviderBuilder::new().network::<AnyNetwork>().connect_http(anvil.endpoint_url());
+                let loaded: bool = provider.raw_request("anvil_loadState".into(), (cached.state,)).await?;
+                if !loaded {
+                    return Err(eyre::eyre!("Anvil has failed to load the cached fork state {}", path.display()));
+                }
+                (anvil, cached.block_number)
+            }
+        };
+        let rpc_url = anvil.endpoint_url();
+        Ok(Self { anvil, rpc_url, chain: *chain, block_number, source, impersonated: Vec::new() })
+    }
+
+    /**
+     * @notice - Impersonate the signers whose private keys are unavailable (= `anvil_impersonateAccount`)
+     */
+    pub async fn impersonate(&mut self, signers: &[RunSigner]) -> eyre::Result<()> {
+        let provider = ProviderBuilder::new().network::<AnyNetwork>().connect_http(self.rpc_url.clone());
+        for signer in signers.iter() {
+            if let RunSigner::Impersonated(address) = signer {
+                provider.raw_request::<_, ()>("anvil_impersonateAccount".into(), (address,)).await?;
+                self.impersonated.push(*address);
+            }
+        }
+        Ok(())
+    }
+
+    /**
+     * @notice - Cache the state of this fork (= `anvil_dumpState`), so that a fork is started from it offline
+     * @dev - The state of a fork only holds what has been fetched from its source: the accounts of the signers and the storage which the checkpoint plan touches
+     *        (= `eth_createAccessList` of each call) are fetched first. Call this before the run, so that the cached state is the one at the pinned block.
+     */
+    pub async fn cache_state<P: AsRef<Path>>(&self, dir: P, signers: &[RunSigner], targets: &[RunTarget]) -> eyre::Result<PathBuf> {
+        let provider = ProviderBuilder::new().network::<AnyNetwork>().connect_http(self.rpc_url.clone());
+        for signer in signers.iter() {
+            provider.get_balance(signer.address()).await?;
+            provider.get_transaction_count(signer.address()).await?;
+            for target in targets.iter() {
+                let method_name = "checkpoint".to_string();
+                let tx = match target.contract.as_str() {
+                    "TravelHistoryManager" => TravelHistoryManager::new(target.address, &provider).checkpoint(method_name).from(signer.address()).into_transaction_request(),
+                    "TravelBookingManager" => TravelBookingManager::new(target.address, &provider).checkpoint(method_name).from(signer.address()).into_transaction_request(),
+                    "StakingPool" => StakingPool::new(target.address, &provider).checkpoint(method_name).from(signer.address()).into_transaction_request(),
+                    _ => continue,
+                };
+                provider.create_access_list(&tx).await?;
+            }
+        }
+
+        let state: Bytes = provider.raw_request("anvil_dumpState".into(), ()).await?;
+        let cached = CachedForkState {
+            chain: self.chain.name.to_string(),
+            chain_id: self.chain.chain_id,
+            block_number: self.block_number,
+            created_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            state,
+        };
+        cached.save(dir, &self.chain)
+    }
+
+    /**
+     * @dev - The fork recorded in the run report
+     */
+    pub fn origin(&self) -> ForkOrigin {
+        ForkOrigin { source: self.source.to_string(), block_number: self.block_number, impersonated: self.impersonated.clone() }
+    }
+}
+
+fn spawn_error(e: alloy_node_bindings::NodeError) -> eyre::Report {
+    eyre::eyre!("Failed to spawn the Anvil fork (install Anvil via `foundryup`): {}", e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact_registry::{ArtifactRegistry, DEFAULT_ARTIFACT_REGISTRY_PATH};
+    use crate::chain::LOCAL_ANVIL;
+    use crate::checkpoint_run::RunReport;
+    use crate::local_anvil::{AnvilMode, LocalAnvil, MockInjection, MockVerifier, MOCK_TAG};
+    use alloy::primitives::U256;
+
+    #[test]
+    fn parses_the_fork_sources() {
+        assert_eq!("http://127.0.0.1:8545".parse::<ForkSource>().unwrap(), ForkSource::Rpc("http://127.0.0.1:8545".parse().unwrap()));
+        assert_eq!(
+            "data/fork-states/base-mainnet-100.json".parse::<ForkSource>().unwrap(),
+            ForkSource::CachedState(PathBuf::from("data/fork-states/base-mainnet-100.json"))
+        );
+        assert!("https://".parse::<ForkSource>().is_err());
+        assert_eq!(
+            CachedForkState::path_of(DEFAULT_FORK_STATES_PATH, &crate::chain::BASE_MAINNET, 100),
+            PathBuf::from("data/fork-states/base-mainnet-100.json")
+        );
+    }
+
+    #[tokio::test]
+    #[ignore = "requires Anvil (install it via `foundryup`)"]
+    async fn rehearses_a_run_on_a_fork_and_from_its_cached_state() {
+        // @dev - The "mainnet" is a local Anvil w/ the contracts deployed, which is forked like a local archive node
+        let dir = std::env::temp_dir().join(format!("travel_history_fork_{}", std::process::id()));
+        let registry = ArtifactRegistry::load(DEFAULT_ARTIFACT_REGISTRY_PATH).unwrap();
+        let mainnet = LocalAnvil::deploy(AnvilMode::Spawn { port: None }, &registry, &dir, &MockVerifier::AlwaysTrue, MockInjection::SetCode).await.unwrap();
+        let targets: Vec<RunTarget> = mainnet.manifest.contracts.iter().filter(|deployed| !deployed.tags.contains(&MOCK_TAG.to_string())).map(RunTarget::from).collect();
+        let block_number = mainnet.provider.get_block_number().await.unwrap();
+
+        // @dev - A signer w/o a private key is impersonated
+        let signers = vec![RunSigner::Key(mainnet.signers[0].clone()), RunSigner::Impersonated(mainnet.signers[1].address())];
+        let mut fork = MainnetFork::start(&LOCAL_ANVIL, ForkSource::Rpc(mainnet.rpc_url.clone()), Some(block_number)).await.unwrap();
+        fork.impersonate(&signers).await.unwrap();
+        let cached_state = fork.cache_state(&dir, &signers, &targets).await.unwrap();
+
+        let report = RunReport::run(&LOCAL_ANVIL, &fork.rpc_url, &signers, &targets, Some(fork.origin())).await.unwrap();
+        assert_eq!(report.failures(), 0);
+        assert_eq!(report.results.len(), 6);
+        assert_eq!(report.fork.as_ref().unwrap().impersonated, vec![mainnet.signers[1].address()]);
+
+        // @dev - The rehearsal has not touched the "mainnet"
+        assert_eq!(mainnet.provider.get_block_number().await.unwrap(), block_number);
+
+        // @dev - Offline: the fork is started from its cached state
+        drop(fork);
+        let mut offline = MainnetFork::start(&LOCAL_ANVIL, ForkSource::CachedState(cached_state), Some(block_number)).await.unwrap();
+        offline.impersonate(&signers).await.unwrap();
+        let provider = ProviderBuilder::new().network::<AnyNetwork>().connect_http(offline.rpc_url.clone());
+        assert!(provider.get_balance(mainnet.signers[1].address()).await.unwrap() > U256::ZERO);
+        let report = RunReport::run(&LOCAL_ANVIL, &offline.rpc_url, &signers, &targets, Some(offline.origin())).await.unwrap();
+        assert_eq!(report.failures(), 0);
+    }
+}
@@ -0,0 +1,112 @@
+// @dev - Noir / ACVM
+use acvm::{AcirField, FieldElement};
+use bn254_blackbox_solver::{derive_generators, multi_scalar_mul};
+
+/**
+ * @notice - The Pedersen hash, which is identical to the `std::hash::pedersen_hash()` of Noir.
+ * @dev - pedersen_hash(inputs) = (inputs[0] * G[0] + ... + inputs[N-1] * G[N-1] + N * H).x on the Grumpkin curve,
+ *        where G = derive_generators("DEFAULT_DOMAIN_SEPARATOR", N, 0) and H = derive_generators("pedersen_hash_length", 1, 0)[0]
+ */
+pub fn pedersen_hash(inputs: &[FieldElement]) -> FieldElement {
+    let mut generators = derive_generators(b"DEFAULT_DOMAIN_SEPARATOR", inputs.len() as u32, 0);
+    generators.extend(derive_generators(b"pedersen_hash_length", 1, 0));
+
+    let mut scalars: Vec<FieldElement> = inputs.to_vec();
+    scalars.push(FieldElement::from(inputs.len() as u128));
+
+    let points: Vec<FieldElement> = generators
+        .iter()
+        .flat_map(|generator| [FieldElement::from_repr(generator.x), FieldElement::from_repr(generator.y)])
+        .collect();
+    let (scalars_lo, scalars_hi): (Vec<FieldElement>, Vec<FieldElement>) = scalars.iter().map(|scalar| split_scalar(*scalar)).unzip();
+
+    let (x, _y) = multi_scalar_mul(&points, &scalars_lo, &scalars_hi)
+        .expect("A BN254 field element is always a valid Grumpkin scalar");
+    x
+}
+
+/**
+ * @dev - Split a field element into its (lo, hi) 128-bit limbs (= `from_field_unsafe()` of the EmbeddedCurveScalar in Noir)
+ */
+fn split_scalar(scalar: FieldElement) -> (FieldElement, FieldElement) {
+    let be_bytes = scalar.to_be_bytes();
+    let (hi, lo) = be_bytes.split_at(be_bytes.len() - 16);
+    (FieldElement::from_be_bytes_reduce(lo), FieldElement::from_be_bytes_reduce(hi))
+}
+
+/**
+ * @notice - The Merkle root, which is identical to the `std::merkle::compute_merkle_root()` of Noir.
+ * @dev - The i-th bit (little-endian) of `index` decides whether the current node is the right (= 1) or the left (= 0) child.
+ */
+pub fn compute_merkle_root(leaf: FieldElement, index: FieldElement, hash_path: &[FieldElement]) -> FieldElement {
+    let index_le_bytes = index.to_le_bytes();
+    let mut current = leaf;
+    for (i, sibling) in hash_path.iter().enumerate() {
+        let path_bit = (index_le_bytes[i / 8] >> (i % 8)) & 1 == 1;
+        current = if path_bit {
+            pedersen_hash(&[*sibling, current])
+        } else {
+            pedersen_hash(&[current, *sibling])
+        };
+    }
+    current
+}
+
+/**
+ * @dev - note_commitment = pedersen_hash([secret]), which is the leaf of the Merkle tree in the travel_history circuit
+ */
+pub fn note_commitment(secret: FieldElement) -> FieldElement {
+    pedersen_hash(&[secret])
+}
+
+/**
+ * @dev - nullifier = pedersen_hash([root, secret]), which is returned by the travel_history circuit (= the 5th publicInput)
+ */
+pub fn nullifier(root: FieldElement, secret: FieldElement) -> FieldElement {
+    pedersen_hash(&[root, secret])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::parse_field;
+    use crate::test_vectors;
+
+    fn field(value: &str) -> FieldElement {
+        parse_field(value).unwrap()
+    }
+
+    /**
+     * @dev - The known answer of `test_verifier()` in circuits/src/tests/mod.nr (secret = 1, index = 0)
+     */
+    #[test]
+    fn computes_the_merkle_root_of_the_circuit_test() {
+        let secret = FieldElement::one();
+        let hash_path = [
+            field("0x1efa9d6bb4dfdf86063cc77efdec90eb9262079230f1898049efad264835b6c8"),
+            field("0x2a653551d87767c545a2a11b29f0581a392b4e177a87c8e3eb425c51a26a8c77"),
+        ];
+        let root = compute_merkle_root(note_commitment(secret), FieldElement::zero(), &hash_path);
+        assert_eq!(root, field("0x215597bacd9c7e977dfc170f320074155de974be494579d2586e5b268fa3b629"));
+        assert_eq!(nullifier(root, secret), pedersen_hash(&[root, secret]));
+    }
+
+    /**
+     * @dev - The known answers of circuits/src/tests/generated.nr (= the cases w/ a non-zero index exercise the right-child branch)
+     */
+    #[test]
+    fn computes_the_root_and_the_nullifier_of_the_generated_test_vectors() {
+        let cases = test_vectors::generate(1, 0).unwrap();
+        let case = cases.iter().find(|case| case.name == "country_code_1999").unwrap();
+        assert_eq!(case.inputs.root, field("0x0de434af1f925b7c1b125e51a927e2f65338361aa11840c30c0119b2e9fd7cf5"));
+        assert_eq!(case.inputs.index, FieldElement::one());
+        assert_eq!(
+            compute_merkle_root(note_commitment(case.inputs.secret), case.inputs.index, &case.inputs.hash_path),
+            case.inputs.root
+        );
+        assert_eq!(
+            nullifier(case.inputs.root, case.inputs.secret),
+            field("0x2b4b5c03751c6153741644951a4d11422245d32c2b55d4a91545ab4f69d5845c")
+        );
+    }
+}
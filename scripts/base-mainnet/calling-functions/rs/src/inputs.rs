@@ -0,0 +1,131 @@
+// @dev - Noir / ACVM
+use acvm::{AcirField, FieldElement};
+
+use crate::field::parse_field;
+use crate::hash;
+
+use std::collections::HashMap;
+use std::path::Path;
+
+/// @dev - The length of the `hash_path` of the travel_history circuit (= the depth of the Merkle tree)
+pub const HASH_PATH_LENGTH: usize = 2;
+
+/**
+ * @notice - The typed inputs of the travel_history circuit (= the values of Prover.toml)
+ * @dev - The public inputs are `root`, `country_code`, `enter_date` and `exit_date`. The others are private inputs.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TravelHistoryInputs {
+    pub root: FieldElement,                          // @dev - Merkle Root
+    pub hash_path: [FieldElement; HASH_PATH_LENGTH],
+    pub index: FieldElement,
+    pub secret: FieldElement,
+    pub passport_number: FieldElement,               // @dev - Passport number of the traveler
+    pub country_code: u64,                           // @dev - i.e). USA is "1" (by removing "+" from "+1")
+    pub enter_date: u64,                             // @dev - UNIX timestamp
+    pub exit_date: u64,                              // @dev - UNIX timestamp
+}
+
+impl TravelHistoryInputs {
+    /**
+     * @dev - Load the inputs from a Prover.toml file
+     */
+    pub fn load<P: AsRef<Path>>(path: P) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let prover_toml = std::fs::read_to_string(path)
+            .map_err(|e| eyre::eyre!("Failed to read {}: {}", path.display(), e))?;
+        Self::from_prover_toml_str(&prover_toml)
+    }
+
+    pub fn from_prover_toml_str(prover_toml: &str) -> eyre::Result<Self> {
+        let table: toml::Table = toml::from_str(prover_toml)?;
+
+        let hash_path = match table.get("hash_path") {
+            Some(toml::Value::Array(values)) if values.len() == HASH_PATH_LENGTH => {
+                let mut hash_path = [FieldElement::zero(); HASH_PATH_LENGTH];
+                for (i, value) in values.iter().enumerate() {
+                    hash_path[i] = field_of(value, "hash_path")?;
+                }
+                hash_path
+            }
+            Some(_) => return Err(eyre::eyre!("hash_path must be an array of {} fields", HASH_PATH_LENGTH)),
+            None => return Err(eyre::eyre!("Missing input: hash_path")),
+        };
+
+        Ok(Self {
+            root: field_of(required(&table, "root")?, "root")?,
+            hash_path,
+            index: field_of(required(&table, "index")?, "index")?,
+            secret: field_of(required(&table, "secret")?, "secret")?,
+            passport_number: field_of(required(&table, "passport_number")?, "passport_number")?,
+            country_code: u64_of(required(&table, "country_code")?, "country_code")?,
+            enter_date: u64_of(required(&table, "enter_date")?, "enter_date")?,
+            exit_date: u64_of(required(&table, "exit_date")?, "exit_date")?,
+        })
+    }
+
+    /**
+     * @dev - Serialize the inputs into the format of Prover.toml
+     */
+    pub fn to_prover_toml(&self) -> String {
+        format!(
+            "root = \"{}\"\nhash_path = [\n  \"{}\",\n  \"{}\"\n]\nindex = \"{}\"\nsecret = \"{}\"\npassport_number = \"{}\"\ncountry_code = \"{}\"\nenter_date = \"{}\"\nexit_date = \"{}\"\n",
+            to_0x_hex(self.root),
+            to_0x_hex(self.hash_path[0]),
+            to_0x_hex(self.hash_path[1]),
+            self.index.to_short_hex(),
+            self.secret.to_short_hex(),
+            self.passport_number.to_short_hex(),
+            self.country_code,
+            self.enter_date,
+            self.exit_date,
+        )
+    }
+
+    /**
+     * @dev - The flattened value of each ABI parameter of the circuit (= the initial witness of CompiledCircuit#execute())
+     */
+    pub fn abi_values(&self) -> HashMap<String, Vec<FieldElement>> {
+        HashMap::from([
+            ("root".to_string(), vec![self.root]),
+            ("hash_path".to_string(), self.hash_path.to_vec()),
+            ("index".to_string(), vec![self.index]),
+            ("secret".to_string(), vec![self.secret]),
+            ("passport_number".to_string(), vec![self.passport_number]),
+            ("country_code".to_string(), vec![FieldElement::from(self.country_code as u128)]),
+            ("enter_date".to_string(), vec![FieldElement::from(self.enter_date as u128)]),
+            ("exit_date".to_string(), vec![FieldElement::from(self.exit_date as u128)]),
+        ])
+    }
+
+    /**
+     * @notice - The nullifier that the circuit returns for these inputs (= pedersen_hash([root, secret]))
+     */
+    pub fn expected_nullifier(&self) -> FieldElement {
+        hash::nullifier(self.root, self.secret)
+    }
+}
+
+fn to_0x_hex(value: FieldElement) -> String {
+    format!("0x{}", value.to_hex())
+}
+
+fn required<'a>(table: &'a toml::Table, name: &str) -> eyre::Result<&'a toml::Value> {
+    table.get(name).ok_or_else(|| eyre::eyre!("Missing input: {}", name))
+}
+
+fn field_of(value: &toml::Value, name: &str) -> eyre::Result<FieldElement> {
+    match value {
+        toml::Value::String(s) => parse_field(s).map_err(|e| eyre::eyre!("Invalid {}: {}", name, e)),
+        toml::Value::Integer(i) if *i >= 0 => Ok(FieldElement::from(*i as u128)),
+        _ => Err(eyre::eyre!("Invalid {}: {} is not a field element", name, value)),
+    }
+}
+
+fn u64_of(value: &toml::Value, name: &str) -> eyre::Result<u64> {
+    match value {
+        toml::Value::String(s) => s.trim().parse::<u64>().map_err(|e| eyre::eyre!("Invalid {} \"{}\": {}", name, s, e)),
+        toml::Value::Integer(i) => u64::try_from(*i).map_err(|e| eyre::eyre!("Invalid {} {}: {}", name, i, e)),
+        _ => Err(eyre::eyre!("Invalid {}: {} is not a u64", name, value)),
+    }
+}
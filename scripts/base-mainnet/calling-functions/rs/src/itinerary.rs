@@ -0,0 +1,360 @@
+// @dev - Noir / ACVM
+use acvm::{AcirField, FieldElement};
+
+use crate::country_codes::{self, Lookup};
+use crate::field::{field_to_bytes32, parse_field};
+use crate::hash::{note_commitment, pedersen_hash};
+use crate::inputs::TravelHistoryInputs;
+use crate::merkle::MerkleTree;
+use crate::mrz::encode_document_number;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::path::{Path, PathBuf};
+
+/**
+ * @notice - A row of a travel itinerary (CSV or JSON), which is held by a traveler or an agency
+ * @dev - `passport_number` is the document number of the MRZ, or an already encoded "0x"-prefixed `passport_number` (see parse_passport_number())
+ * @dev - `country` is a country name, an ISO 3166 alpha-2/alpha-3 code or a country code (i.e. "France", "FR", "FRA", "33" or "+33")
+ * @dev - `enter_date` and `exit_date` are ISO-8601 dates with a time zone (i.e. "2021-03-01T09:30:00+01:00"). A date without a time (i.e. "2021-03-01") is read as 00:00 UTC.
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct ItineraryRow {
+    #[serde(alias = "passport", deserialize_with = "string_or_number")]
+    pub passport_number: String,
+    #[serde(alias = "destination_country", alias = "destination", deserialize_with = "string_or_number")]
+    pub country: String,
+    #[serde(alias = "entry_date")]
+    pub enter_date: String,
+    pub exit_date: String,
+}
+
+/**
+ * @notice - A stay which is imported from a row of a travel itinerary
+ * @dev - `row` is the 1-based number of the row (= the header of a CSV is not counted)
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedStay {
+    pub row: usize,
+    pub passport_number: FieldElement,
+    pub country: Lookup,
+    pub enter_date: u64, // @dev - UNIX timestamp
+    pub exit_date: u64,  // @dev - UNIX timestamp
+    pub warnings: Vec<String>,
+}
+
+impl ImportedStay {
+    pub fn from_row(row: usize, itinerary_row: &ItineraryRow) -> eyre::Result<Self> {
+        let passport_number = parse_passport_number(&itinerary_row.passport_number)?;
+        let country = resolve_country(&itinerary_row.country)?;
+        let enter_date = parse_iso8601(&itinerary_row.enter_date).map_err(|e| eyre::eyre!("invalid enter_date: {}", e))?;
+        let exit_date = parse_iso8601(&itinerary_row.exit_date).map_err(|e| eyre::eyre!("invalid exit_date: {}", e))?;
+        if exit_date <= enter_date {
+            return Err(eyre::eyre!(
+                "exit_date ({}) must come after enter_date ({})",
+                itinerary_row.exit_date,
+                itinerary_row.enter_date
+            ));
+        }
+
+        let mut warnings = Vec::new();
+        if country.is_ambiguous() {
+            warnings.push(format!("The country code {} is shared by several countries", country));
+        }
+        Ok(Self { row, passport_number, country, enter_date, exit_date, warnings })
+    }
+}
+
+/**
+ * @notice - Load a travel itinerary from a CSV file (*.csv) or a JSON file (an array of rows)
+ * @dev - An error points to the offending row.
+ */
+pub fn load<P: AsRef<Path>>(path: P) -> eyre::Result<Vec<ImportedStay>> {
+    let path = path.as_ref();
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| eyre::eyre!("Failed to read the itinerary {}: {}", path.display(), e))?;
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("csv") => parse_csv(&content),
+        Some("json") => parse_json(&content),
+        _ => Err(eyre::eyre!("Unsupported itinerary format: {} (expected *.csv or *.json)", path.display())),
+    }
+}
+
+pub fn parse_csv(content: &str) -> eyre::Result<Vec<ImportedStay>> {
+    let mut reader = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(content.as_bytes());
+    let headers = reader.headers()?.clone();
+
+    let mut stays = Vec::new();
+    for (i, record) in reader.records().enumerate() {
+        let row = i + 1;
+        let record = record.map_err(|e| eyre::eyre!("row {}: {}", row, e))?;
+        let line = record.position().map(|position| position.line()).unwrap_or_default();
+        let itinerary_row: ItineraryRow = record
+            .deserialize(Some(&headers))
+            .map_err(|e| eyre::eyre!("row {} (line {}): {}", row, line, e))?;
+        stays.push(ImportedStay::from_row(row, &itinerary_row).map_err(|e| eyre::eyre!("row {} (line {}): {}", row, line, e))?);
+    }
+    Ok(stays)
+}
+
+pub fn parse_json(content: &str) -> eyre::Result<Vec<ImportedStay>> {
+    let values: Vec<serde_json::Value> = serde_json::from_str(content)?;
+
+    let mut stays = Vec::new();
+    for (i, value) in values.into_iter().enumerate() {
+        let row = i + 1;
+        let itinerary_row: ItineraryRow = serde_json::from_value(value).map_err(|e| eyre::eyre!("row {}: {}", row, e))?;
+        stays.push(ImportedStay::from_row(row, &itinerary_row).map_err(|e| eyre::eyre!("row {}: {}", row, e))?);
+    }
+    Ok(stays)
+}
+
+/**
+ * @dev - A document number (i.e. "L898902C3" or "123456789") is encoded via mrz::encode_document_number(), so that it yields the same
+ *        `passport_number` as the MRZ of the passport. A numeric document number is encoded as well (= it is not taken as a bare integer).
+ * @dev - Only a "0x"-prefixed hex value is taken as-is (= an already encoded `passport_number`).
+ */
+pub fn parse_passport_number(passport_number: &str) -> eyre::Result<FieldElement> {
+    let passport_number = passport_number.trim();
+    if passport_number.starts_with("0x") {
+        return parse_field(passport_number).map_err(|e| eyre::eyre!("invalid passport_number: {}", e));
+    }
+    encode_document_number(passport_number).map_err(|e| eyre::eyre!("invalid passport_number: {}", e))
+}
+
+/**
+ * @dev - Resolve a country (i.e. "France", "FR", "FRA", "33", "+33" or "+1-809") into a country code which is valid for the circuit
+ */
+pub fn resolve_country(country: &str) -> eyre::Result<Lookup> {
+    let country = country.trim();
+    let digits: String = country.chars().filter(|c| !matches!(c, '+' | '-' | ' ')).collect();
+    let country_code = if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+        digits.parse::<u64>().map_err(|e| eyre::eyre!("invalid country code \"{}\": {}", country, e))?
+    } else {
+        country_codes::find(country)
+            .ok_or_else(|| eyre::eyre!("unknown country \"{}\"", country))?
+            .country_code
+    };
+    country_codes::validate_for_circuit(country_code)
+}
+
+/**
+ * @dev - Parse an ISO-8601 date (with a time zone) into a UNIX timestamp
+ */
+pub fn parse_iso8601(value: &str) -> eyre::Result<u64> {
+    let value = value.trim();
+    let timestamp = if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+        date_time.timestamp()
+    } else if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        date.and_hms_opt(0, 0, 0).expect("00:00:00 is always valid").and_utc().timestamp()
+    } else if NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S").is_ok() {
+        return Err(eyre::eyre!("\"{}\" has no time zone (i.e. \"{}Z\" or \"{}+01:00\")", value, value, value));
+    } else {
+        return Err(eyre::eyre!("\"{}\" is not an ISO-8601 date", value));
+    };
+    u64::try_from(timestamp).map_err(|_| eyre::eyre!("\"{}\" is before the UNIX epoch", value))
+}
+
+fn string_or_number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        String(String),
+        Number(u64),
+    }
+    Ok(match StringOrNumber::deserialize(deserializer)? {
+        StringOrNumber::String(s) => s,
+        StringOrNumber::Number(n) => n.to_string(),
+    })
+}
+
+/**
+ * @notice - An entry of the manifest of an imported itinerary (= one Prover.toml per stay)
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub row: usize,
+    pub country_code: u64,
+    pub country: String,
+    pub enter_date: u64,
+    pub exit_date: u64,
+    pub prover_toml: PathBuf,
+    pub expected_nullifier: String,
+    pub warnings: Vec<String>,
+}
+
+/**
+ * @notice - Write one Prover.toml per stay (= <out_dir>/stay_<row>/Prover.toml) and the manifest (= <out_dir>/manifest.json)
+ * @dev - The nullifier of the circuit is pedersen_hash([root, secret]), so the stays sharing a note would share a nullifier.
+ *        Thus, the note secret of each stay is derived from the content of the stay (see stay_secret()) and is put into its own Merkle tree (at index 0),
+ *        so that each stay gets its own nullifier, which does not change when the rows of the itinerary are reordered or re-imported.
+ * @dev - The duplicated rows of a stay share a nullifier, so only the first one can be recorded. A warning is added to the others.
+ */
+pub fn write_batch<P: AsRef<Path>>(stays: &[ImportedStay], secret: FieldElement, out_dir: P) -> eyre::Result<Vec<ManifestEntry>> {
+    let out_dir = out_dir.as_ref();
+    let mut manifest: Vec<ManifestEntry> = Vec::new();
+    for stay in stays.iter() {
+        let stay_secret = stay_secret(secret, stay);
+        let tree = MerkleTree::new(&[note_commitment(stay_secret)])?;
+        let inputs = TravelHistoryInputs {
+            root: tree.root(),
+            hash_path: tree.hash_path(0)?,
+            index: FieldElement::zero(),
+            secret: stay_secret,
+            passport_number: stay.passport_number,
+            country_code: stay.country.country_code,
+            enter_date: stay.enter_date,
+            exit_date: stay.exit_date,
+        };
+
+        let stay_dir = out_dir.join(format!("stay_{:03}", stay.row));
+        std::fs::create_dir_all(&stay_dir)?;
+        let prover_toml = stay_dir.join("Prover.toml");
+        std::fs::write(&prover_toml, inputs.to_prover_toml())?;
+
+        let expected_nullifier = field_to_bytes32(inputs.expected_nullifier()).to_string();
+        let mut warnings = stay.warnings.clone();
+        if let Some(duplicated) = manifest.iter().find(|entry| entry.expected_nullifier == expected_nullifier) {
+            warnings.push(format!("The stay is a duplicate of the row {}, so they share a nullifier", duplicated.row));
+        }
+        manifest.push(ManifestEntry {
+            row: stay.row,
+            country_code: stay.country.country_code,
+            country: stay.country.to_string(),
+            enter_date: stay.enter_date,
+            exit_date: stay.exit_date,
+            prover_toml,
+            expected_nullifier,
+            warnings,
+        });
+    }
+
+    std::fs::write(out_dir.join("manifest.json"), serde_json::to_string_pretty(&manifest)?)?;
+    Ok(manifest)
+}
+
+/**
+ * @dev - The note secret of a stay = pedersen_hash([secret, passport_number, country_code, enter_date, exit_date])
+ */
+pub fn stay_secret(secret: FieldElement, stay: &ImportedStay) -> FieldElement {
+    pedersen_hash(&[
+        secret,
+        stay.passport_number,
+        FieldElement::from(stay.country.country_code as u128),
+        FieldElement::from(stay.enter_date as u128),
+        FieldElement::from(stay.exit_date as u128),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mrz::Mrz;
+
+    #[test]
+    fn resolves_a_country_by_its_name_alpha_codes_or_dial_code() {
+        for country in ["France", "FR", "fra", "33", "+33", " +33 "] {
+            assert_eq!(resolve_country(country).unwrap().country_code, 33, "{}", country);
+        }
+        assert_eq!(resolve_country("+1-809").unwrap().country_code, 1809);
+        assert_eq!(resolve_country("+1 876").unwrap().country_code, 1876);
+        assert_eq!(resolve_country("Jamaica").unwrap().country_code, 1876);
+        assert!(resolve_country("+1").unwrap().is_ambiguous());
+        assert!(resolve_country("Atlantis").unwrap_err().to_string().contains("unknown country \"Atlantis\""));
+        assert!(resolve_country("+2000").unwrap_err().to_string().contains("country_code < 2000"));
+    }
+
+    #[test]
+    fn parses_the_iso8601_dates_with_their_time_zones() {
+        assert_eq!(parse_iso8601("2021-03-01").unwrap(), 1_614_556_800);
+        assert_eq!(parse_iso8601("2021-03-01T00:00:00Z").unwrap(), 1_614_556_800);
+        assert_eq!(parse_iso8601("2021-03-01T09:30:00+01:00").unwrap(), 1_614_556_800 + 8 * 3_600 + 1_800);
+        assert_eq!(parse_iso8601("2021-02-28T19:00:00-05:00").unwrap(), 1_614_556_800);
+        assert!(parse_iso8601("2021-03-01T09:30:00").unwrap_err().to_string().contains("has no time zone"));
+        assert!(parse_iso8601("01/03/2021").unwrap_err().to_string().contains("is not an ISO-8601 date"));
+        assert!(parse_iso8601("1969-12-31").unwrap_err().to_string().contains("before the UNIX epoch"));
+    }
+
+    #[test]
+    fn imports_the_example_itinerary() {
+        let stays = load("config/itinerary.example.csv").unwrap();
+        assert_eq!(stays.iter().map(|stay| (stay.row, stay.country.country_code)).collect::<Vec<_>>(), vec![(1, 33), (2, 49), (3, 44)]);
+        assert_eq!(stays[0].enter_date, 1_614_587_400);
+        assert!(stays[0].warnings.is_empty());
+        assert!(!stays[2].warnings.is_empty()); // @dev - 44 is shared by the UK, Guernsey, Jersey and the Isle of Man
+    }
+
+    #[test]
+    fn points_an_error_to_the_offending_row() {
+        let csv = "passport_number,country,enter_date,exit_date\n\
+                   123456789,France,2021-03-01,2021-03-15\n\
+                   123456789,Atlantis,2021-04-01,2021-04-15\n";
+        assert_eq!(parse_csv(csv).unwrap_err().to_string(), "row 2 (line 3): unknown country \"Atlantis\"");
+
+        let csv = "passport_number,country,enter_date\n123456789,France,2021-03-01\n";
+        assert!(parse_csv(csv).unwrap_err().to_string().starts_with("row 1 (line 2): "));
+
+        let json = r#"[
+            {"passport_number": 123456789, "country": 33, "enter_date": "2021-03-01", "exit_date": "2021-03-15"},
+            {"passport": "123456789", "destination": "FR", "entry_date": "2021-03-01T09:30:00", "exit_date": "2021-03-15"}
+        ]"#;
+        let error = parse_json(json).unwrap_err().to_string();
+        assert!(error.starts_with("row 2: invalid enter_date: "), "{}", error);
+    }
+
+    #[test]
+    fn rejects_an_exit_not_after_the_entry() {
+        let row = |enter_date: &str, exit_date: &str| ItineraryRow {
+            passport_number: "123456789".to_string(),
+            country: "FR".to_string(),
+            enter_date: enter_date.to_string(),
+            exit_date: exit_date.to_string(),
+        };
+        assert!(ImportedStay::from_row(1, &row("2021-03-01", "2021-03-02")).is_ok());
+        assert!(ImportedStay::from_row(1, &row("2021-03-01", "2021-03-01"))
+            .unwrap_err()
+            .to_string()
+            .contains("must come after enter_date"));
+        // @dev - 10:00+01:00 is before 10:00Z
+        assert!(ImportedStay::from_row(1, &row("2021-03-01T10:00:00Z", "2021-03-01T10:00:00+01:00")).is_err());
+    }
+
+    #[test]
+    fn derives_the_nullifiers_from_the_content_of_the_stays() {
+        let out_dir = std::env::temp_dir().join(format!("travel_history_itinerary_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&out_dir);
+        let secret = FieldElement::from(42u128);
+        let header = "passport_number,country,enter_date,exit_date\n";
+        let (france, germany) = ("123456789,FR,2021-03-01,2021-03-15\n", "123456789,DE,2021-04-01,2021-04-15\n");
+
+        let manifest = write_batch(&parse_csv(&format!("{}{}{}", header, france, germany)).unwrap(), secret, &out_dir).unwrap();
+        let reordered = write_batch(&parse_csv(&format!("{}{}{}", header, germany, france)).unwrap(), secret, &out_dir).unwrap();
+        assert_ne!(manifest[0].expected_nullifier, manifest[1].expected_nullifier);
+        assert_eq!(manifest[0].expected_nullifier, reordered[1].expected_nullifier);
+        assert_eq!(manifest[1].expected_nullifier, reordered[0].expected_nullifier);
+
+        let duplicated = write_batch(&parse_csv(&format!("{}{}{}", header, france, france)).unwrap(), secret, &out_dir).unwrap();
+        assert!(duplicated[0].warnings.is_empty());
+        assert_eq!(duplicated[1].warnings, vec!["The stay is a duplicate of the row 1, so they share a nullifier".to_string()]);
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+
+    #[test]
+    fn encodes_a_passport_number_in_the_same_way_as_the_mrz() {
+        let mrz = Mrz::parse(
+            "P<UTOERIKSSON<<ANNA<MARIA<<<<<<<<<<<<<<<<<<<\nL898902C36UTO7408122F1204159ZE184226B<<<<<10",
+        )
+        .unwrap();
+        assert_eq!(parse_passport_number("L898902C3").unwrap(), mrz.passport_number().unwrap());
+        assert_eq!(parse_passport_number(" L898902C3 ").unwrap(), mrz.passport_number().unwrap());
+
+        // @dev - A numeric document number is encoded as well, so "0012" and "12" are distinct
+        assert_eq!(parse_passport_number("13003286").unwrap(), encode_document_number("13003286").unwrap());
+        assert_ne!(parse_passport_number("13003286").unwrap(), FieldElement::from(13_003_286u128));
+        assert_ne!(parse_passport_number("0012").unwrap(), parse_passport_number("12").unwrap());
+
+        // @dev - An already encoded passport_number is taken as-is
+        assert_eq!(parse_passport_number("0xc66a16").unwrap(), FieldElement::from(13_003_286u128));
+        assert!(parse_passport_number("l898902c3").unwrap_err().to_string().contains("invalid character"));
+    }
+}
@@ -0,0 +1,34 @@
+// @dev - Shared modules for the SC scripts of the TravelHistoryManager / TravelBookingManager / StakingPool contracts
+pub mod artifact;
+pub mod artifact_registry;
+pub mod batch;
+pub mod bytecode;
+pub mod chain;
+pub mod checkpoint_run;
+pub mod circuit;
+pub mod contracts;
+pub mod country_codes;
+pub mod create2;
+pub mod deployment;
+pub mod dynamic_call;
+pub mod field;
+pub mod fixtures;
+pub mod fork;
+pub mod hash;
+pub mod inputs;
+pub mod itinerary;
+pub mod local_anvil;
+pub mod local_verifier;
+pub mod merkle;
+pub mod migration;
+pub mod mrz;
+pub mod nullifier;
+pub mod policy;
+pub mod proof_bundle;
+pub mod proof_store;
+pub mod prover_toml;
+pub mod public_inputs;
+pub mod relayer;
+pub mod test_vectors;
+pub mod travel_status;
+pub mod vk;
@@ -0,0 +1,318 @@
+// @dev - Alloy
+use alloy::{
+    network::{AnyNetwork, Network, ReceiptResponse, TransactionBuilder},
+    primitives::{address, keccak256, Address, Bytes, TxHash, B256},
+    providers::{DynProvider, Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+    transports::http::reqwest::Url,
+};
+use alloy_node_bindings::{Anvil, AnvilInstance};
+
+use crate::artifact_registry::ArtifactRegistry;
+use crate::chain::LOCAL_ANVIL;
+use crate::deployment::{deploy_contract, DeployedContract, DeploymentManifest};
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// @notice - The local-Anvil mode: spawn Anvil, install a mock TravelHistoryProofVerifier, deploy the TravelHistoryManager / TravelBookingManager / StakingPool,
+//           and write the deployment manifest of the local-anvil chain profile (= deployments/local-anvil.json), so that the commands run with `--chain local-anvil` as on mainnet.
+
+/// @dev - The address of the mock verifier injected via `anvil_setCode`
+pub const MOCK_VERIFIER_ADDRESS: Address = address!("0000000000000000000000000000000000007e57");
+
+/// @dev - The tag of the mock verifier in the deployment manifest (= it has no artifact)
+pub const MOCK_TAG: &str = "mock";
+
+/// @dev - The contracts of the local-Anvil mode, which are deployed after the mock verifier
+pub const LOCAL_CONTRACTS: [&str; 3] = ["TravelHistoryManager", "TravelBookingManager", "StakingPool"];
+
+/**
+ * @notice - A mock TravelHistoryProofVerifier, whose verifyTravelHistoryProof(bytes,bytes32[]) returns:
+ * @dev - AlwaysTrue / AlwaysFalse: true / false for any call.
+ *        Allowlist: true for the proofs whose keccak256 is set to 1 in its storage (= anvil_setStorageAt(verifier, keccak256(proof), 1)), and false otherwise.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MockVerifier {
+    AlwaysTrue,
+    AlwaysFalse,
+    Allowlist(Vec<Bytes>),
+}
+
+impl MockVerifier {
+    /**
+     * @notice - The runtime code of the mock verifier
+     */
+    pub fn runtime_code(&self) -> Bytes {
+        match self {
+            // @dev - PUSH1 1 PUSH1 0 MSTORE PUSH1 32 PUSH1 0 RETURN
+            MockVerifier::AlwaysTrue => Bytes::from_static(&[0x60, 0x01, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3]),
+            // @dev - PUSH1 0 PUSH1 0 MSTORE PUSH1 32 PUSH1 0 RETURN
+            MockVerifier::AlwaysFalse => Bytes::from_static(&[0x60, 0x00, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3]),
+            // @dev - Copy the `proof` of the calldata (= its offset is at 0x04) into the memory, and return SLOAD(keccak256(proof)):
+            //        PUSH1 4 CALLDATALOAD PUSH1 4 ADD DUP1 CALLDATALOAD SWAP1 PUSH1 32 ADD DUP2 SWAP1 PUSH1 0 CALLDATACOPY
+            //        PUSH1 0 SHA3 SLOAD PUSH1 0 MSTORE PUSH1 32 PUSH1 0 RETURN
+            MockVerifier::Allowlist(_) => Bytes::from_static(&[
+                0x60, 0x04, 0x35, 0x60, 0x04, 0x01, 0x80, 0x35, 0x90, 0x60, 0x20, 0x01, 0x81, 0x90, 0x60, 0x00, 0x37,
+                0x60, 0x00, 0x20, 0x54, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3,
+            ]),
+        }
+    }
+
+    /**
+     * @dev - The creation code of the mock verifier, which returns its runtime code (= PUSH1 <size> DUP1 PUSH1 11 PUSH1 0 CODECOPY PUSH1 0 RETURN <runtime code>)
+     */
+    pub fn creation_code(&self) -> Bytes {
+        let runtime_code = self.runtime_code();
+        let mut creation_code = vec![0x60, runtime_code.len() as u8, 0x80, 0x60, 0x0b, 0x60, 0x00, 0x39, 0x60, 0x00, 0xf3];
+        creation_code.extend_from_slice(&runtime_code);
+        Bytes::from(creation_code)
+    }
+
+    /**
+     * @dev - The storage slots of the allowlisted proofs (= keccak256(proof))
+     */
+    pub fn allowlist_slots(&self) -> Vec<B256> {
+        match self {
+            MockVerifier::Allowlist(proofs) => proofs.iter().map(keccak256).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MockVerifier::AlwaysTrue => "always-true",
+            MockVerifier::AlwaysFalse => "always-false",
+            MockVerifier::Allowlist(_) => "allowlist",
+        }
+    }
+}
+
+impl fmt::Display for MockVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MockVerifier::Allowlist(proofs) => write!(f, "allowlist ({} proofs)", proofs.len()),
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+/**
+ * @notice - How the mock verifier is injected: deployed via a transaction, or set via `anvil_setCode` at MOCK_VERIFIER_ADDRESS
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockInjection {
+    Deploy,
+    SetCode,
+}
+
+impl FromStr for MockInjection {
+    type Err = eyre::Report;
+
+    fn from_str(value: &str) -> eyre::Result<Self> {
+        match value {
+            "deploy" => Ok(MockInjection::Deploy),
+            "set-code" => Ok(MockInjection::SetCode),
+            _ => Err(eyre::eyre!("Unknown mock injection: {} (expected one of: deploy, set-code)", value)),
+        }
+    }
+}
+
+/**
+ * @notice - Install a mock verifier, and return its entry of the deployment manifest (= tagged with MOCK_TAG)
+ */
+pub async fn install_mock_verifier<P: Provider<AnyNetwork>>(
+    provider: &P,
+    mock_verifier: &MockVerifier,
+    injection: MockInjection,
+) -> eyre::Result<DeployedContract> {
+    let (address, tx_hash, block_number) = match injection {
+        MockInjection::Deploy => {
+            let deploy_tx = <AnyNetwork as Network>::TransactionRequest::default().with_deploy_code(mock_verifier.creation_code());
+            let receipt = provider.send_transaction(deploy_tx).await?.get_receipt().await?;
+            let address = receipt
+                .contract_address()
+                .filter(|_| receipt.status())
+                .ok_or_else(|| eyre::eyre!("The deployment of the mock verifier has failed (tx: {})", receipt.transaction_hash()))?;
+            (address, receipt.transaction_hash(), receipt.block_number())
+        }
+        MockInjection::SetCode => {
+            provider.raw_request::<_, ()>("anvil_setCode".into(), (MOCK_VERIFIER_ADDRESS, mock_verifier.runtime_code())).await?;
+            (MOCK_VERIFIER_ADDRESS, TxHash::ZERO, None)
+        }
+    };
+    for slot in mock_verifier.allowlist_slots() {
+        provider.raw_request::<_, ()>("anvil_setStorageAt".into(), (address, slot, B256::with_last_byte(1))).await?;
+    }
+
+    Ok(DeployedContract {
+        contract: "TravelHistoryProofVerifier".to_string(),
+        label: "TravelHistoryProofVerifier".to_string(),
+        address,
+        tx_hash,
+        block_number,
+        artifact: PathBuf::from(format!("{}/{}", MOCK_TAG, mock_verifier.name())),
+        artifact_hash: keccak256(mock_verifier.runtime_code()),
+        constructor_args: Bytes::new(),
+        deployed_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        tags: vec![MOCK_TAG.to_string()],
+        salt: None,
+    })
+}
+
+/**
+ * @notice - Whether Anvil is spawned (= on a port, i.e. 8545 which is the RPC of the local-anvil chain profile), or already running
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnvilMode {
+    Spawn { port: Option<u16> },
+    Attach,
+}
+
+/**
+ * @notice - A local Anvil with the contracts deployed
+ * @dev - `anvil` is None when attached to an Anvil which is already running (= LOCAL_ANVIL_RPC). A spawned Anvil is killed when this is dropped.
+ */
+pub struct LocalAnvil {
+    pub anvil: Option<AnvilInstance>,
+    pub rpc_url: Url,
+    pub provider: DynProvider<AnyNetwork>,
+    pub signers: Vec<PrivateKeySigner>,
+    pub manifest: DeploymentManifest,
+}
+
+impl LocalAnvil {
+    /**
+     * @notice - Spawn Anvil, or attach to the one of the local-anvil chain profile (= LOCAL_ANVIL_RPC, i.e. `anvil` in another terminal)
+     */
+    pub fn start(mode: AnvilMode) -> eyre::Result<(Option<AnvilInstance>, Url, Vec<PrivateKeySigner>)> {
+        match mode {
+            AnvilMode::Spawn { port } => {
+                let mut anvil = Anvil::new();
+                if let Some(port) = port {
+                    anvil = anvil.port(port);
+                }
+                let anvil = anvil.try_spawn().map_err(|e| eyre::eyre!("Failed to spawn Anvil (install it via `foundryup`): {}", e))?;
+                let signers = anvil.keys().iter().map(|key| key.clone().into()).collect();
+                let rpc_url = anvil.endpoint_url();
+                Ok((Some(anvil), rpc_url, signers))
+            }
+            AnvilMode::Attach => {
+                // @dev - The well-known development accounts of Anvil (= `test test ... junk`)
+                let signers = ANVIL_DEV_KEYS.iter().map(|key| key.parse()).collect::<Result<Vec<PrivateKeySigner>, _>>()?;
+                Ok((None, LOCAL_ANVIL.rpc_url()?, signers))
+            }
+        }
+    }
+
+    /**
+     * @notice - Install the mock verifier and deploy the contracts from the latest artifacts of the registry, and write the deployment manifest of the local-anvil chain profile
+     */
+    pub async fn deploy(
+        mode: AnvilMode,
+        registry: &ArtifactRegistry,
+        deployments_dir: &Path,
+        mock_verifier: &MockVerifier,
+        injection: MockInjection,
+    ) -> eyre::Result<Self> {
+        let (anvil, rpc_url, signers) = Self::start(mode)?;
+        let deployer = signers.first().cloned().ok_or_else(|| eyre::eyre!("Anvil has no development account"))?;
+        let provider = ProviderBuilder::new()
+            .with_gas_estimation()
+            .network::<AnyNetwork>()
+            .wallet(deployer)
+            .connect_http(rpc_url.clone())
+            .erased();
+
+        // @dev - A fresh manifest: the previous local deployments are gone with their Anvil
+        let mut manifest = DeploymentManifest::load(deployments_dir, &LOCAL_ANVIL)?;
+        manifest.contracts.clear();
+
+        for deployed in deploy_local_contracts(&provider, registry, mock_verifier, injection).await? {
+            manifest.add(deployed);
+        }
+        manifest.save()?;
+
+        Ok(Self { anvil, rpc_url, provider, signers, manifest })
+    }
+}
+
+/**
+ * @notice - Install the mock verifier, and deploy the TravelHistoryManager (with the mock verifier) / TravelBookingManager / StakingPool from the latest artifacts of the registry
+ */
+pub async fn deploy_local_contracts<P: Provider<AnyNetwork>>(
+    provider: &P,
+    registry: &ArtifactRegistry,
+    mock_verifier: &MockVerifier,
+    injection: MockInjection,
+) -> eyre::Result<Vec<DeployedContract>> {
+    let verifier = install_mock_verifier(provider, mock_verifier, injection).await?;
+    let verifier_address = verifier.address;
+    let mut deployed_contracts = vec![verifier];
+    for contract in LOCAL_CONTRACTS.iter() {
+        let artifact = registry.latest(contract)?;
+        let constructor_args = match *contract {
+            "TravelHistoryManager" => Bytes::from(verifier_address.into_word().to_vec()),
+            _ => Bytes::new(),
+        };
+        deployed_contracts.push(deploy_contract(provider, &artifact, contract, contract, constructor_args).await?);
+    }
+    Ok(deployed_contracts)
+}
+
+/// @dev - The private keys of the first 5 development accounts of Anvil, which the mainnet runs use 5 private keys of (= PRIVATE_KEY_1..5)
+pub const ANVIL_DEV_KEYS: [&str; 5] = [
+    "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80",
+    "0x59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d",
+    "0x5de4111afa1a4b94908f83103eb1f1706367c2e68ca870fc3fb9a804cdab365a",
+    "0x7c852118294e51e653712a81e09800f419141751be58f605c371e15141b007a6",
+    "0x47e179ec197488593b187f80a00eb0da91f1b9d0b13f8733639f19c30a34926a",
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checkpoint_run::{run_checkpoint_plan, RunSigner, RunTarget};
+    use alloy::{primitives::U256, rpc::types::TransactionRequest, sol_types::SolCall};
+
+    #[test]
+    fn builds_the_creation_code_of_the_mock_verifiers() {
+        for mock_verifier in [MockVerifier::AlwaysTrue, MockVerifier::AlwaysFalse, MockVerifier::Allowlist(Vec::new())] {
+            let creation_code = mock_verifier.creation_code();
+            assert_eq!(creation_code[1] as usize, mock_verifier.runtime_code().len());
+            assert_eq!(&creation_code[11..], &mock_verifier.runtime_code()[..]);
+        }
+        let proof = Bytes::from_static(b"proof");
+        assert_eq!(MockVerifier::Allowlist(vec![proof.clone()]).allowlist_slots(), vec![keccak256(&proof)]);
+        assert!("set-code".parse::<MockInjection>().is_ok());
+        assert!("setCode".parse::<MockInjection>().is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires Anvil (install it via `foundryup`)"]
+    async fn runs_the_checkpoint_plan_with_the_mock_verifiers() {
+        let dir = std::env::temp_dir().join(format!("travel_history_local_anvil_{}", std::process::id()));
+        let registry = ArtifactRegistry::load(crate::artifact_registry::DEFAULT_ARTIFACT_REGISTRY_PATH).unwrap();
+        let allowed_proof = Bytes::from_static(b"allowed proof");
+        let mock_verifier = MockVerifier::Allowlist(vec![allowed_proof.clone()]);
+        let local = LocalAnvil::deploy(AnvilMode::Spawn { port: None }, &registry, &dir, &mock_verifier, MockInjection::SetCode).await.unwrap();
+        assert_eq!(local.manifest.contracts.len(), 4);
+
+        // @dev - The allowlist verifier accepts the allowlisted proof only
+        let verifier = local.manifest.latest("TravelHistoryProofVerifier").unwrap().address;
+        for (proof, expected) in [(allowed_proof, true), (Bytes::from_static(b"other proof"), false)] {
+            let call = crate::contracts::TravelHistoryProofVerifier::verifyTravelHistoryProofCall { proof, publicInput: vec![B256::ZERO; 5] };
+            let tx = TransactionRequest::default().with_to(verifier).with_input(call.abi_encode());
+            let output = local.provider.call(tx.into()).await.unwrap();
+            assert_eq!(U256::from_be_slice(&output) == U256::from(1), expected);
+        }
+
+        let targets: Vec<RunTarget> = local.manifest.contracts.iter().filter(|deployed| !deployed.tags.contains(&MOCK_TAG.to_string())).map(RunTarget::from).collect();
+        let signers: Vec<RunSigner> = local.signers[..2].iter().cloned().map(RunSigner::Key).collect();
+        let results = run_checkpoint_plan(&local.rpc_url, &signers, &targets).await.unwrap();
+        assert_eq!(results.len(), 6);
+        assert!(results.iter().all(|result| result.success));
+    }
+}
@@ -0,0 +1,170 @@
+// @dev - Alloy
+use alloy::{
+    network::{Network, ReceiptResponse},
+    primitives::{Address, Bytes, FixedBytes},
+    providers::Provider,
+    sol_types::SolValue,
+};
+
+use crate::artifact::ContractArtifact;
+use crate::contracts::TravelHistoryProofVerifier;
+use crate::deployment::deploy_contract;
+
+use serde::Serialize;
+use std::fmt;
+use std::path::Path;
+
+/**
+ * @notice - The TravelHistoryProofVerifier (+ HonkVerifier), which is deployed on a local chain (i.e. Anvil) from the compiled artifacts
+ */
+#[derive(Debug, Clone)]
+pub struct LocalVerifier {
+    pub honk_verifier: Address,
+    pub travel_history_proof_verifier: Address,
+    honk_verifier_artifact: ContractArtifact,
+}
+
+/**
+ * @notice - The result of verifying a proof via TravelHistoryProofVerifier#verifyTravelHistoryProof()
+ * @dev - `gas_used` is the gas used by a transaction calling verifyTravelHistoryProof() (= incl. the intrinsic gas and the calldata), which is only available for a valid proof.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VerificationReport {
+    pub is_valid: bool,
+    pub gas_used: Option<u64>,
+    pub revert_reason: Option<String>,
+}
+
+impl fmt::Display for VerificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.is_valid, self.gas_used, &self.revert_reason) {
+            (true, Some(gas_used), _) => write!(f, "valid (gas used: {})", gas_used),
+            (true, None, _) => write!(f, "valid"),
+            (false, _, Some(revert_reason)) => write!(f, "invalid ({})", revert_reason),
+            (false, _, None) => write!(f, "invalid"),
+        }
+    }
+}
+
+impl LocalVerifier {
+    /**
+     * @notice - Load the artifacts of the HonkVerifier and the TravelHistoryProofVerifier
+     * @dev - The verifier artifacts are not committed (= they are generated from the vk of each circuit build), so a missing one points at the build steps.
+     */
+    pub fn load_artifacts<P: AsRef<Path>, Q: AsRef<Path>>(
+        honk_verifier_artifact: P,
+        travel_history_proof_verifier_artifact: Q,
+    ) -> eyre::Result<(ContractArtifact, ContractArtifact)> {
+        for path in [honk_verifier_artifact.as_ref(), travel_history_proof_verifier_artifact.as_ref()] {
+            if !path.exists() {
+                return Err(eyre::eyre!(
+                    "The verifier artifact {} does not exist. Build it with `circuits/build.sh` and `forge build`, and copy it from ./out \
+                     (see \"Verify a travel history proof offline\" in the README)",
+                    path.display()
+                ));
+            }
+        }
+        Ok((ContractArtifact::load(honk_verifier_artifact)?, ContractArtifact::load(travel_history_proof_verifier_artifact)?))
+    }
+
+    /**
+     * @notice - Deploy the HonkVerifier and the TravelHistoryProofVerifier(HonkVerifier)
+     */
+    pub async fn deploy<P: Provider<N>, N: Network>(
+        provider: &P,
+        honk_verifier_artifact: ContractArtifact,
+        travel_history_proof_verifier_artifact: &ContractArtifact,
+    ) -> eyre::Result<Self> {
+        let honk_verifier = deploy_contract(provider, &honk_verifier_artifact, "HonkVerifier", "HonkVerifier", Bytes::new()).await?.address;
+        let travel_history_proof_verifier = deploy_contract(
+            provider,
+            travel_history_proof_verifier_artifact,
+            "TravelHistoryProofVerifier",
+            "TravelHistoryProofVerifier",
+            Bytes::from(honk_verifier.abi_encode()),
+        )
+        .await?
+        .address;
+
+        Ok(Self { honk_verifier, travel_history_proof_verifier, honk_verifier_artifact })
+    }
+
+    /**
+     * @notice - Verify a proof via TravelHistoryProofVerifier#verifyTravelHistoryProof()
+     * @dev - A valid proof is also sent as a transaction to measure the gas used.
+     */
+    pub async fn verify<P: Provider<N>, N: Network>(
+        &self,
+        provider: &P,
+        proof: Bytes,
+        public_inputs: Vec<FixedBytes<32>>,
+    ) -> eyre::Result<VerificationReport> {
+        let travel_history_proof_verifier = TravelHistoryProofVerifier::new(self.travel_history_proof_verifier, provider);
+        let call = travel_history_proof_verifier.verifyTravelHistoryProof(proof, public_inputs);
+
+        match call.call().await {
+            Ok(true) => {}
+            Ok(false) => return Ok(VerificationReport { is_valid: false, gas_used: None, revert_reason: None }),
+            Err(e) => match e.as_revert_data() {
+                Some(data) => {
+                    return Ok(VerificationReport {
+                        is_valid: false,
+                        gas_used: None,
+                        revert_reason: Some(self.honk_verifier_artifact.decode_revert(&data)),
+                    })
+                }
+                None => return Err(e.into()),
+            },
+        }
+
+        let receipt = call.send().await?.get_receipt().await?;
+        Ok(VerificationReport { is_valid: true, gas_used: Some(receipt.gas_used()), revert_reason: None })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::{HONK_VERIFIER_ARTIFACT_PATH, TRAVEL_HISTORY_PROOF_VERIFIER_ARTIFACT_PATH};
+    use crate::proof_bundle::ProofBundle;
+
+    use alloy::{providers::ProviderBuilder, signers::local::PrivateKeySigner};
+    use alloy_node_bindings::Anvil;
+
+    /// @dev - The proof bundle of `circuits/build.sh` (= proven w/ the vk of the verifier artifacts)
+    const CIRCUIT_PROOF_BUNDLE_PATH: &str = "../../../../circuits/target";
+
+    #[test]
+    fn points_a_missing_artifact_at_the_build_steps() {
+        let error = LocalVerifier::load_artifacts("artifacts/missing/HonkVerifier.json", TRAVEL_HISTORY_PROOF_VERIFIER_ARTIFACT_PATH)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("artifacts/missing/HonkVerifier.json does not exist"), "{}", error);
+        assert!(error.contains("`circuits/build.sh` and `forge build`"), "{}", error);
+    }
+
+    #[tokio::test]
+    #[ignore = "requires Anvil, the verifier artifacts and the proof of `circuits/build.sh`"]
+    async fn reports_a_valid_and_an_invalid_proof() {
+        let (honk_verifier_artifact, verifier_artifact) =
+            LocalVerifier::load_artifacts(HONK_VERIFIER_ARTIFACT_PATH, TRAVEL_HISTORY_PROOF_VERIFIER_ARTIFACT_PATH).unwrap();
+        let proof_bundle = ProofBundle::load(CIRCUIT_PROOF_BUNDLE_PATH).unwrap();
+
+        let anvil = Anvil::new().arg("--disable-code-size-limit").try_spawn().unwrap();
+        let signer: PrivateKeySigner = anvil.keys()[0].clone().into();
+        let provider = ProviderBuilder::new().with_gas_estimation().wallet(signer).connect_http(anvil.endpoint_url());
+        let local_verifier = LocalVerifier::deploy(&provider, honk_verifier_artifact, &verifier_artifact).await.unwrap();
+
+        let report = local_verifier.verify(&provider, proof_bundle.proof.clone(), proof_bundle.public_inputs.clone()).await.unwrap();
+        assert!(report.is_valid, "{}", report);
+        assert!(report.gas_used.unwrap() > 0);
+
+        // @dev - A tampered publicInput (= the exit_date) must be rejected w/ a decoded revert reason
+        let mut public_inputs = proof_bundle.public_inputs.clone();
+        public_inputs[3] = FixedBytes::with_last_byte(1);
+        let report = local_verifier.verify(&provider, proof_bundle.proof.clone(), public_inputs).await.unwrap();
+        assert!(!report.is_valid);
+        assert_eq!(report.gas_used, None);
+        assert!(report.revert_reason.is_some(), "{}", report);
+    }
+}
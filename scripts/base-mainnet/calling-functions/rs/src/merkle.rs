@@ -0,0 +1,63 @@
+// @dev - Noir / ACVM
+use acvm::{AcirField, FieldElement};
+
+use crate::hash::pedersen_hash;
+use crate::inputs::HASH_PATH_LENGTH;
+
+/**
+ * @notice - The Merkle tree of the note commitments (= pedersen_hash([secret])), whose depth is the length of the `hash_path` of the travel_history circuit.
+ * @dev - A parent node is pedersen_hash([left, right]), which is identical to the `std::merkle::compute_merkle_root()` of Noir.
+ * @dev - The empty leaves are filled with zero.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleTree {
+    layers: Vec<Vec<FieldElement>>, // @dev - layers[0] = the leaves, layers[HASH_PATH_LENGTH] = [root]
+}
+
+impl MerkleTree {
+    pub const CAPACITY: usize = 1 << HASH_PATH_LENGTH;
+
+    pub fn new(leaves: &[FieldElement]) -> eyre::Result<Self> {
+        if leaves.len() > Self::CAPACITY {
+            return Err(eyre::eyre!("Too many leaves: {} (the capacity of the tree is {})", leaves.len(), Self::CAPACITY));
+        }
+        let mut layer = leaves.to_vec();
+        layer.resize(Self::CAPACITY, FieldElement::zero());
+
+        let mut layers = vec![layer];
+        for _ in 0..HASH_PATH_LENGTH {
+            let parent = layers
+                .last()
+                .expect("The leaves layer always exists")
+                .chunks(2)
+                .map(|pair| pedersen_hash(&[pair[0], pair[1]]))
+                .collect();
+            layers.push(parent);
+        }
+        Ok(Self { layers })
+    }
+
+    pub fn root(&self) -> FieldElement {
+        self.layers[HASH_PATH_LENGTH][0]
+    }
+
+    pub fn leaf(&self, index: usize) -> FieldElement {
+        self.layers[0][index]
+    }
+
+    /**
+     * @dev - The siblings from the leaf at `index` up to the root (= the `hash_path` of the circuit)
+     */
+    pub fn hash_path(&self, index: usize) -> eyre::Result<[FieldElement; HASH_PATH_LENGTH]> {
+        if index >= Self::CAPACITY {
+            return Err(eyre::eyre!("Invalid leaf index: {} (the capacity of the tree is {})", index, Self::CAPACITY));
+        }
+        let mut hash_path = [FieldElement::zero(); HASH_PATH_LENGTH];
+        let mut position = index;
+        for (level, sibling) in hash_path.iter_mut().enumerate() {
+            *sibling = self.layers[level][position ^ 1];
+            position >>= 1;
+        }
+        Ok(hash_path)
+    }
+}
@@ -0,0 +1,577 @@
+// @dev - Alloy
+use alloy::{
+    network::{AnyNetwork, ReceiptResponse},
+    primitives::{Address, FixedBytes, TxHash, B256, U256},
+    providers::{DynProvider, PendingTransactionBuilder, Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+    transports::http::reqwest::Url,
+};
+
+use crate::contracts::{TravelBookingManager, TravelHistoryManager};
+use crate::proof_store::ProofStore;
+use crate::public_inputs::TravelPublicInputs;
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// @notice - The migration of the registrations of an old TravelHistoryManager / TravelBookingManager instance to a newly deployed version:
+//           the role mappings (= travelers, bookers, property owners, OTAs) and the room listings of a known set of addresses / room ids are snapshotted from the old instance,
+//           and re-created on the new instance by self-registration of the owning signer (= the contracts have no admin function which registers an account).
+// @dev - Whatever needs the key of an account which is unavailable, or cannot be re-created at all, is reported instead:
+//        i.e. a booking w/ an escrowed amount, and the spent nullifiers (= nullifiersByWalletAddresses) and the recorded proofs (= of the local proof store) of the travelers.
+//        The new instance does not know the spent nullifiers, so a proof w/ one of them can be recorded there again (= double-spent) until the old instance is retired.
+
+/// @dev - The default migration set (= the known addresses and room ids), relative to the root directory (= /rs)
+pub const DEFAULT_MIGRATION_SET_PATH: &str = "config/migration.toml";
+
+/// @dev - The default directory of the migration reports
+pub const DEFAULT_MIGRATION_REPORTS_PATH: &str = "data/migrations";
+
+/**
+ * @notice - The contracts whose registrations are migrated
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MigratedContract {
+    TravelHistoryManager,
+    TravelBookingManager,
+}
+
+impl MigratedContract {
+    pub fn roles(&self) -> &'static [Role] {
+        match self {
+            MigratedContract::TravelHistoryManager => &[Role::Traveler],
+            MigratedContract::TravelBookingManager => &[Role::Booker, Role::PropertyOwner, Role::Ota],
+        }
+    }
+}
+
+impl FromStr for MigratedContract {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "TravelHistoryManager" => Ok(MigratedContract::TravelHistoryManager),
+            "TravelBookingManager" => Ok(MigratedContract::TravelBookingManager),
+            other => Err(eyre::eyre!("Unknown contract to migrate: {} (expected one of: TravelHistoryManager, TravelBookingManager)", other)),
+        }
+    }
+}
+
+impl fmt::Display for MigratedContract {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/**
+ * @notice - A role mapping, which an account registers itself into (= registerAs<Role>())
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Role {
+    Traveler,
+    Booker,
+    PropertyOwner,
+    Ota,
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Role::Traveler => write!(f, "traveler"),
+            Role::Booker => write!(f, "booker"),
+            Role::PropertyOwner => write!(f, "property owner"),
+            Role::Ota => write!(f, "OTA"),
+        }
+    }
+}
+
+/**
+ * @notice - The known set of addresses and room ids which are snapshotted (= config/migration.toml)
+ * @dev - The contract does not record who has listed a room, so `owner` tells which property owner re-lists it on the new instance.
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct MigrationSet {
+    #[serde(default)]
+    pub addresses: Vec<Address>,
+    #[serde(default)]
+    pub rooms: Vec<KnownRoom>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct KnownRoom {
+    pub room_id: u64,
+    pub owner: Option<Address>,
+}
+
+impl MigrationSet {
+    pub fn load<P: AsRef<Path>>(path: P) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let config = std::fs::read_to_string(path).map_err(|e| eyre::eyre!("Failed to read the migration set {}: {}", path.display(), e))?;
+        Self::from_toml_str(&config)
+    }
+
+    pub fn from_toml_str(config: &str) -> eyre::Result<Self> {
+        Ok(toml::from_str(config)?)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoleRegistration {
+    pub account: Address,
+    pub role: Role,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoomListing {
+    pub room_id: u64,
+    pub price_wei: U256,
+    pub owner: Option<Address>,
+    pub booker: Option<Address>, // @dev - bookerOfRooms (= the booking and its escrowed amount stay on the old instance)
+}
+
+/**
+ * @dev - The latest nullifier which an account has spent (= nullifiersByWalletAddresses)
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpentNullifier {
+    pub account: Address,
+    pub nullifier: FixedBytes<32>,
+}
+
+/**
+ * @dev - A proof of the local proof store, which is recorded on the old instance under `account` (= `msg.sender`)
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofRecord {
+    pub account: Address,
+    pub proof_hash: B256,
+    pub nullifier: FixedBytes<32>,
+}
+
+/**
+ * @notice - The registrations of the known set on the old instance
+ * @dev - `nullifiers` and `proofs` are the state of the travelers (= TravelHistoryManager only), which cannot be re-created on the new instance.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MigrationSnapshot {
+    pub contract: MigratedContract,
+    pub address: Address,
+    pub block_number: u64,
+    pub registrations: Vec<RoleRegistration>,
+    pub rooms: Vec<RoomListing>,
+    #[serde(default)]
+    pub nullifiers: Vec<SpentNullifier>,
+    #[serde(default)]
+    pub proofs: Vec<ProofRecord>,
+}
+
+impl MigrationSnapshot {
+    /**
+     * @notice - Snapshot the role mappings and the room listings of the known set from an instance
+     * @dev - For a TravelHistoryManager, the spent nullifiers of the known set, and their proofs in the local proof store (if any) which are recorded on the instance, are snapshotted as well.
+     */
+    pub async fn take<P: Provider<AnyNetwork>>(
+        provider: &P,
+        contract: MigratedContract,
+        address: Address,
+        set: &MigrationSet,
+        proof_store: Option<&ProofStore>,
+    ) -> eyre::Result<Self> {
+        let block_number = provider.get_block_number().await?;
+        let mut snapshot = Self { contract, address, block_number, registrations: Vec::new(), rooms: Vec::new(), nullifiers: Vec::new(), proofs: Vec::new() };
+        for account in set.addresses.iter() {
+            for role in contract.roles().iter() {
+                if has_role(provider, address, *account, *role).await? {
+                    snapshot.registrations.push(RoleRegistration { account: *account, role: *role });
+                }
+            }
+        }
+        if contract == MigratedContract::TravelHistoryManager {
+            let travel_history_manager = TravelHistoryManager::new(address, provider);
+            for account in set.addresses.iter() {
+                let nullifier = travel_history_manager.nullifiersByWalletAddresses(*account).call().await?;
+                if !nullifier.is_zero() {
+                    snapshot.nullifiers.push(SpentNullifier { account: *account, nullifier });
+                }
+            }
+            if let Some(proof_store) = proof_store {
+                for stored_proof in proof_store.all()? {
+                    let Ok(public_inputs) = TravelPublicInputs::from_bytes32s(&stored_proof.public_inputs) else {
+                        continue;
+                    };
+                    // @dev - A relayed proof is recorded under the relayer wallet (= its sender), which may be in the known set as well as the traveler
+                    let mut senders: Vec<Address> = stored_proof
+                        .submissions
+                        .iter()
+                        .filter(|submission| submission.contract == address)
+                        .filter(|submission| set.addresses.contains(&submission.traveler) || set.addresses.contains(&submission.sender()))
+                        .map(|submission| submission.sender())
+                        .collect();
+                    senders.dedup();
+                    for sender in senders {
+                        if travel_history_manager.isTravelHistoryProofRecorded(sender, stored_proof.proof.clone()).call().await? {
+                            snapshot.proofs.push(ProofRecord { account: sender, proof_hash: stored_proof.proof_hash, nullifier: public_inputs.nullifier_hash });
+                        }
+                    }
+                }
+            }
+        }
+        if contract == MigratedContract::TravelBookingManager {
+            let travel_booking_manager = TravelBookingManager::new(address, provider);
+            for room in set.rooms.iter() {
+                if !travel_booking_manager.listedRoomes(U256::from(room.room_id)).call().await? {
+                    continue;
+                }
+                let booker = travel_booking_manager.bookerOfRooms(U256::from(room.room_id)).call().await?;
+                snapshot.rooms.push(RoomListing {
+                    room_id: room.room_id,
+                    price_wei: travel_booking_manager.roomPrices(U256::from(room.room_id)).call().await?,
+                    owner: room.owner,
+                    booker: (booker != Address::ZERO).then_some(booker),
+                });
+            }
+        }
+        Ok(snapshot)
+    }
+
+    /**
+     * @notice - The migration plan of this snapshot: the role registrations first (= a property owner re-lists its rooms once registered), then the room listings,
+     *           and the recorded proofs and the spent nullifiers (= a spent nullifier w/o a recorded proof of the local proof store)
+     */
+    pub fn plan(&self) -> Vec<MigrationStep> {
+        let mut steps: Vec<MigrationStep> = self.registrations.iter().map(|registration| MigrationStep::Register(registration.clone())).collect();
+        steps.extend(self.proofs.iter().map(|proof| MigrationStep::RecordedProof(proof.clone())));
+        for spent in self.nullifiers.iter() {
+            if !self.proofs.iter().any(|proof| proof.nullifier == spent.nullifier) {
+                steps.push(MigrationStep::SpentNullifier(spent.clone()));
+            }
+        }
+        for room in self.rooms.iter() {
+            steps.push(MigrationStep::ListRoom(room.clone()));
+            if let Some(booker) = room.booker {
+                steps.push(MigrationStep::Booking { room_id: room.room_id, booker });
+            }
+        }
+        steps
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MigrationStep {
+    Register(RoleRegistration),
+    ListRoom(RoomListing),
+    Booking { room_id: u64, booker: Address },
+    RecordedProof(ProofRecord),
+    SpentNullifier(SpentNullifier),
+}
+
+impl MigrationStep {
+    /**
+     * @dev - The nullifier which is spent on the old instance by this step (if any)
+     */
+    pub fn nullifier(&self) -> Option<FixedBytes<32>> {
+        match self {
+            MigrationStep::RecordedProof(proof) => Some(proof.nullifier),
+            MigrationStep::SpentNullifier(spent) => Some(spent.nullifier),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MigrationStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationStep::Register(registration) => write!(f, "{:?} as a {}", registration.account, registration.role),
+            MigrationStep::ListRoom(room) => write!(f, "room {} listed at {} wei", room.room_id, room.price_wei),
+            MigrationStep::Booking { room_id, booker } => write!(f, "room {} booked by {:?}", room_id, booker),
+            MigrationStep::RecordedProof(proof) => write!(f, "proof {} recorded by {:?}", proof.proof_hash, proof.account),
+            MigrationStep::SpentNullifier(spent) => write!(f, "nullifier {} spent by {:?}", spent.nullifier, spent.account),
+        }
+    }
+}
+
+/**
+ * @notice - The outcome of a migration step
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MigrationOutcome {
+    Planned { account: Address }, // @dev - A dry run
+    Migrated { account: Address, tx_hash: TxHash },
+    AlreadyMigrated,
+    NeedsOwnerKey { account: Address },
+    Unmigratable { reason: String },
+    Failed { account: Address, error: String },
+}
+
+impl MigrationOutcome {
+    pub fn is_pending(&self) -> bool {
+        !matches!(self, MigrationOutcome::Planned { .. } | MigrationOutcome::Migrated { .. } | MigrationOutcome::AlreadyMigrated)
+    }
+}
+
+impl fmt::Display for MigrationOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationOutcome::Planned { account } => write!(f, "🔄 to be sent by {:?}", account),
+            MigrationOutcome::Migrated { account, tx_hash } => write!(f, "✅ migrated by {:?} (tx: {})", account, tx_hash),
+            MigrationOutcome::AlreadyMigrated => write!(f, "✅ already on the new instance"),
+            MigrationOutcome::NeedsOwnerKey { account } => write!(f, "🔑 needs the key of {:?}", account),
+            MigrationOutcome::Unmigratable { reason } => write!(f, "⛔ cannot be migrated: {}", reason),
+            MigrationOutcome::Failed { account, error } => write!(f, "❌ failed to be sent by {:?}: {}", account, error),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MigrationItem {
+    pub step: MigrationStep,
+    pub outcome: MigrationOutcome,
+}
+
+/**
+ * @notice - The report of a migration (= data/migrations/<contract>-<started_at>.json)
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationReport {
+    pub contract: MigratedContract,
+    pub from: Address,
+    pub to: Address,
+    pub dry_run: bool,
+    pub started_at: u64, // @dev - UNIX timestamp
+    pub snapshot: MigrationSnapshot,
+    pub items: Vec<MigrationItem>,
+}
+
+impl MigrationReport {
+    /**
+     * @notice - Re-create the snapshot on the new instance w/ the keys of the owning signers. Nothing is sent in a dry run.
+     */
+    pub async fn migrate(rpc_url: &Url, snapshot: MigrationSnapshot, to: Address, signers: &[PrivateKeySigner], dry_run: bool) -> eyre::Result<Self> {
+        let started_at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let keys: BTreeMap<Address, &PrivateKeySigner> = signers.iter().map(|signer| (signer.address(), signer)).collect();
+        let reader = ProviderBuilder::new().network::<AnyNetwork>().connect_http(rpc_url.clone());
+        let property_owners: Vec<Address> =
+            snapshot.registrations.iter().filter(|registration| registration.role == Role::PropertyOwner).map(|registration| registration.account).collect();
+
+        let mut items: Vec<MigrationItem> = Vec::new();
+        for step in snapshot.plan() {
+            let (account, already_migrated) = match &step {
+                MigrationStep::Register(registration) => (registration.account, has_role(&reader, to, registration.account, registration.role).await?),
+                MigrationStep::ListRoom(room) => {
+                    let travel_booking_manager = TravelBookingManager::new(to, &reader);
+                    let listed = travel_booking_manager.listedRoomes(U256::from(room.room_id)).call().await?
+                        && travel_booking_manager.roomPrices(U256::from(room.room_id)).call().await? == room.price_wei;
+                    if listed {
+                        items.push(MigrationItem { step, outcome: MigrationOutcome::AlreadyMigrated });
+                        continue;
+                    }
+                    let outcome = match room.owner {
+                        None => Some("no owner is known (= set `owner` of the room in the migration set)".to_string()),
+                        Some(owner) if !property_owners.contains(&owner) => Some(format!("{:?} is not a property owner on the old instance", owner)),
+                        Some(_) => None,
+                    };
+                    if let Some(reason) = outcome {
+                        items.push(MigrationItem { step, outcome: MigrationOutcome::Unmigratable { reason } });
+                        continue;
+                    }
+                    (room.owner.unwrap_or_default(), false)
+                }
+                MigrationStep::Booking { .. } => {
+                    let reason = "the booking and its escrowed amount stay on the old instance (= cancelBooking() there, and bookBooking() on the new one)".to_string();
+                    items.push(MigrationItem { step, outcome: MigrationOutcome::Unmigratable { reason } });
+                    continue;
+                }
+                MigrationStep::RecordedProof(_) | MigrationStep::SpentNullifier(_) => {
+                    // @dev - The nullifier is spent on the new instance once the traveler has re-recorded the proof there
+                    let nullifier = step.nullifier().unwrap_or_default();
+                    let outcome = if TravelHistoryManager::new(to, &reader).nullifiers(nullifier).call().await? {
+                        MigrationOutcome::AlreadyMigrated
+                    } else {
+                        let reason = "the new instance does not know the spent nullifier, so a proof w/ it can be recorded there again (= double-spent) until the traveler re-records it or the old instance is retired".to_string();
+                        MigrationOutcome::Unmigratable { reason }
+                    };
+                    items.push(MigrationItem { step, outcome });
+                    continue;
+                }
+            };
+
+            let outcome = match (already_migrated, keys.get(&account)) {
+                (true, _) => MigrationOutcome::AlreadyMigrated,
+                (false, None) => MigrationOutcome::NeedsOwnerKey { account },
+                (false, Some(_)) if dry_run => MigrationOutcome::Planned { account },
+                (false, Some(signer)) => {
+                    let provider = ProviderBuilder::new().with_gas_estimation().network::<AnyNetwork>().wallet((*signer).clone()).connect_http(rpc_url.clone()).erased();
+                    match send_step(&provider, to, &step).await {
+                        Ok(tx_hash) => MigrationOutcome::Migrated { account, tx_hash },
+                        Err(e) => MigrationOutcome::Failed { account, error: e.to_string() },
+                    }
+                }
+            };
+            items.push(MigrationItem { step, outcome });
+        }
+
+        Ok(Self { contract: snapshot.contract, from: snapshot.address, to, dry_run, started_at, snapshot, items })
+    }
+
+    pub fn pending(&self) -> Vec<&MigrationItem> {
+        self.items.iter().filter(|item| item.outcome.is_pending()).collect()
+    }
+
+    pub fn write<P: AsRef<Path>>(&self, reports_dir: P) -> eyre::Result<PathBuf> {
+        std::fs::create_dir_all(reports_dir.as_ref())?;
+        let path = reports_dir.as_ref().join(format!("{}-{}.json", self.contract, self.started_at));
+        std::fs::write(&path, serde_json::to_string_pretty(self)? + "\n").map_err(|e| eyre::eyre!("Failed to write {}: {}", path.display(), e))?;
+        Ok(path)
+    }
+}
+
+impl fmt::Display for MigrationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} {:?} -> {:?} (snapshot at block {}){}", self.contract, self.from, self.to, self.snapshot.block_number, if self.dry_run { " [dry run]" } else { "" })?;
+        for item in self.items.iter() {
+            writeln!(f, "{}: {}", item.step, item.outcome)?;
+        }
+        write!(f, "{} of {} items are migrated (or to be migrated), {} are left behind", self.items.len() - self.pending().len(), self.items.len(), self.pending().len())
+    }
+}
+
+async fn has_role<P: Provider<AnyNetwork>>(provider: &P, contract: Address, account: Address, role: Role) -> eyre::Result<bool> {
+    let has_role = match role {
+        Role::Traveler => TravelHistoryManager::new(contract, provider).travelers(account).call().await?,
+        Role::Booker => TravelBookingManager::new(contract, provider).bookers(account).call().await?,
+        Role::PropertyOwner => TravelBookingManager::new(contract, provider).propertyOwners(account).call().await?,
+        Role::Ota => TravelBookingManager::new(contract, provider).OTAs(account).call().await?,
+    };
+    Ok(has_role)
+}
+
+async fn send_step(provider: &DynProvider<AnyNetwork>, contract: Address, step: &MigrationStep) -> eyre::Result<TxHash> {
+    let pending_tx: PendingTransactionBuilder<AnyNetwork> = match step {
+        MigrationStep::Register(RoleRegistration { role: Role::Traveler, .. }) => TravelHistoryManager::new(contract, provider).registerAsTraveler().send().await?,
+        MigrationStep::Register(RoleRegistration { role: Role::Booker, .. }) => TravelBookingManager::new(contract, provider).registerAsBooker().send().await?,
+        MigrationStep::Register(RoleRegistration { role: Role::PropertyOwner, .. }) => TravelBookingManager::new(contract, provider).registerAsPropertyOwner().send().await?,
+        MigrationStep::Register(RoleRegistration { role: Role::Ota, .. }) => TravelBookingManager::new(contract, provider).registerAsOTA().send().await?,
+        MigrationStep::ListRoom(room) => TravelBookingManager::new(contract, provider).listAvailableRooms(U256::from(room.room_id), room.price_wei).send().await?,
+        MigrationStep::Booking { .. } => return Err(eyre::eyre!("A booking cannot be migrated")),
+        MigrationStep::RecordedProof(_) | MigrationStep::SpentNullifier(_) => return Err(eyre::eyre!("A recorded proof cannot be migrated")),
+    };
+    let receipt = pending_tx.get_receipt().await?;
+    if !receipt.status() {
+        return Err(eyre::eyre!("reverted (tx: {})", receipt.transaction_hash()));
+    }
+    Ok(receipt.transaction_hash())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact_registry::{ArtifactRegistry, DEFAULT_ARTIFACT_REGISTRY_PATH};
+    use crate::deployment::deploy_contract;
+    use crate::fixtures::{Fixture, FixtureSpec};
+
+    #[test]
+    fn plans_the_migration_of_a_snapshot() {
+        let set = MigrationSet::from_toml_str(
+            r#"
+            addresses = ["0x1111111111111111111111111111111111111111", "0x2222222222222222222222222222222222222222"]
+
+            [[rooms]]
+            room_id = 1
+            owner = "0x1111111111111111111111111111111111111111"
+
+            [[rooms]]
+            room_id = 2
+            "#,
+        )
+        .unwrap();
+        assert_eq!(set.addresses.len(), 2);
+        assert_eq!(set.rooms[1], KnownRoom { room_id: 2, owner: None });
+
+        let owner = Address::repeat_byte(0x11);
+        let snapshot = MigrationSnapshot {
+            contract: MigratedContract::TravelBookingManager,
+            address: Address::repeat_byte(0xaa),
+            block_number: 1,
+            registrations: vec![RoleRegistration { account: owner, role: Role::PropertyOwner }],
+            rooms: vec![RoomListing { room_id: 1, price_wei: U256::from(100), owner: Some(owner), booker: Some(Address::repeat_byte(0x22)) }],
+            nullifiers: Vec::new(),
+            proofs: Vec::new(),
+        };
+        let steps = snapshot.plan();
+        assert_eq!(steps.len(), 3);
+        assert!(matches!(steps[0], MigrationStep::Register(_)));
+        assert!(matches!(steps[1], MigrationStep::ListRoom(_)));
+        assert_eq!(steps[2], MigrationStep::Booking { room_id: 1, booker: Address::repeat_byte(0x22) });
+        assert_eq!(steps[2].to_string(), format!("room 1 booked by {:?}", Address::repeat_byte(0x22)));
+        assert!("StakingPool".parse::<MigratedContract>().is_err());
+    }
+
+    #[test]
+    fn plans_the_recorded_proofs_and_the_spent_nullifiers_of_the_travelers() {
+        let (alice, bob) = (Address::repeat_byte(0x11), Address::repeat_byte(0x22));
+        let snapshot = MigrationSnapshot {
+            contract: MigratedContract::TravelHistoryManager,
+            address: Address::repeat_byte(0xaa),
+            block_number: 1,
+            registrations: vec![RoleRegistration { account: alice, role: Role::Traveler }, RoleRegistration { account: bob, role: Role::Traveler }],
+            rooms: Vec::new(),
+            nullifiers: vec![
+                SpentNullifier { account: alice, nullifier: FixedBytes::repeat_byte(1) },
+                SpentNullifier { account: bob, nullifier: FixedBytes::repeat_byte(2) },
+            ],
+            proofs: vec![ProofRecord { account: alice, proof_hash: B256::repeat_byte(3), nullifier: FixedBytes::repeat_byte(1) }],
+        };
+        let steps = snapshot.plan();
+        assert_eq!(steps.len(), 4);
+        assert_eq!(steps[2], MigrationStep::RecordedProof(snapshot.proofs[0].clone()));
+        assert_eq!(steps[2].nullifier(), Some(FixedBytes::repeat_byte(1)));
+        // @dev - The nullifier of alice is covered by her recorded proof, so only the one of bob is planned on its own
+        assert_eq!(steps[3], MigrationStep::SpentNullifier(snapshot.nullifiers[1].clone()));
+        assert_eq!(steps[3].to_string(), format!("nullifier {} spent by {:?}", FixedBytes::<32>::repeat_byte(2), bob));
+        assert_eq!(steps[0].nullifier(), None);
+
+        // @dev - A report of the previous format (= w/o the nullifiers and the proofs) can still be read
+        let mut json = serde_json::to_value(&snapshot).unwrap();
+        json.as_object_mut().unwrap().retain(|key, _| key != "nullifiers" && key != "proofs");
+        assert!(serde_json::from_value::<MigrationSnapshot>(json).unwrap().proofs.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires Anvil (install it via `foundryup`)"]
+    async fn migrates_the_registrations_with_the_available_keys() {
+        let registry = ArtifactRegistry::load(DEFAULT_ARTIFACT_REGISTRY_PATH).unwrap();
+        let fixture = Fixture::build("migration", &FixtureSpec::default(), &registry).await.unwrap();
+        let loaded = fixture.spawn().await.unwrap();
+        let old = loaded.manifest.latest("TravelBookingManager").unwrap().address;
+        let artifact = registry.latest("TravelBookingManager").unwrap();
+        let new = deploy_contract(&loaded.provider, &artifact, "TravelBookingManager", "TravelBookingManager-v2", Default::default()).await.unwrap().address;
+
+        // @dev - The key of the first property owner is available, but not the one of the second
+        let owners = &fixture.accounts.property_owners;
+        let mut set = MigrationSet { addresses: owners.iter().chain(fixture.accounts.otas.iter()).copied().collect(), rooms: Vec::new() };
+        for room in fixture.rooms.iter() {
+            set.rooms.push(KnownRoom { room_id: room.room_id.to::<u64>(), owner: Some(room.owner) });
+        }
+        let signers: Vec<PrivateKeySigner> = loaded.signers.iter().filter(|signer| signer.address() != owners[1]).cloned().collect();
+
+        let snapshot = MigrationSnapshot::take(&loaded.provider, MigratedContract::TravelBookingManager, old, &set, None).await.unwrap();
+        assert_eq!(snapshot.registrations.len(), 3);
+        assert_eq!(snapshot.rooms.len(), 4);
+
+        let rpc_url = loaded.anvil.endpoint_url();
+        let report = MigrationReport::migrate(&rpc_url, snapshot.clone(), new, &signers, false).await.unwrap();
+        let needs_owner_key: Vec<&MigrationItem> =
+            report.items.iter().filter(|item| item.outcome == MigrationOutcome::NeedsOwnerKey { account: owners[1] }).collect();
+        assert_eq!(needs_owner_key.len(), 3); // @dev - The registration + 2 rooms of the second property owner
+        assert_eq!(report.pending().len(), 3);
+        assert!(TravelBookingManager::new(new, &loaded.provider).propertyOwners(owners[0]).call().await.unwrap());
+
+        // @dev - A second migration has nothing left to send
+        let report = MigrationReport::migrate(&rpc_url, snapshot, new, &signers, false).await.unwrap();
+        assert_eq!(report.items.iter().filter(|item| item.outcome == MigrationOutcome::AlreadyMigrated).count(), 4);
+    }
+}
@@ -0,0 +1,396 @@
+// @dev - Noir / ACVM
+use acvm::{AcirField, FieldElement};
+
+use chrono::{Datelike, NaiveDate};
+use std::fmt;
+
+// @notice - The parser of the Machine Readable Zone (MRZ) of a passport (= ICAO 9303 TD3: 2 lines of 44 characters)
+// @dev - Line 1: document code (2) | issuing state (3) | name (39)
+//        Line 2: document number (9) + check digit | nationality (3) | birth date (YYMMDD) + check digit | sex (1)
+//                | expiry date (YYMMDD) + check digit | personal number (14) + check digit | composite check digit
+
+/// @dev - The length of each line of a TD3 MRZ
+pub const TD3_LINE_LENGTH: usize = 44;
+
+/// @dev - The maximum length of a document number of a TD3 MRZ
+pub const DOCUMENT_NUMBER_LENGTH: usize = 9;
+
+/// @dev - The maximum number of bytes which can be packed into a BN254 field element without overflowing the modulus
+pub const MAX_ENCODED_LENGTH: usize = 31;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sex {
+    Male,
+    Female,
+    Unspecified,
+}
+
+impl Sex {
+    fn from_mrz(c: char) -> eyre::Result<Self> {
+        match c {
+            'M' => Ok(Sex::Male),
+            'F' => Ok(Sex::Female),
+            '<' | 'X' => Ok(Sex::Unspecified),
+            _ => Err(eyre::eyre!("Invalid sex: '{}'", c)),
+        }
+    }
+
+    fn to_mrz(self) -> char {
+        match self {
+            Sex::Male => 'M',
+            Sex::Female => 'F',
+            Sex::Unspecified => '<',
+        }
+    }
+}
+
+/**
+ * @notice - The fields of a passport MRZ (TD3), whose check digits have been validated
+ * @dev - The filler characters ("<") are stripped from the fields. In the names, a filler is read as a space.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mrz {
+    pub document_code: String, // @dev - i.e. "P"
+    pub issuing_state: String, // @dev - ISO 3166-1 alpha-3 (or an ICAO code, i.e. "D" for Germany)
+    pub surname: String,
+    pub given_names: String,
+    pub document_number: String,
+    pub nationality: String,
+    pub birth_date: NaiveDate,
+    pub sex: Sex,
+    pub expiry_date: NaiveDate,
+    pub personal_number: String,
+}
+
+impl Mrz {
+    /**
+     * @notice - Parse a TD3 MRZ (= 2 lines of 44 characters, which are separated by a line break) and validate its check digits
+     */
+    pub fn parse(mrz: &str) -> eyre::Result<Self> {
+        let lines: Vec<&str> = mrz.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+        let [line1, line2] = lines[..] else {
+            return Err(eyre::eyre!("A TD3 MRZ must have 2 lines, got {}", lines.len()));
+        };
+        Self::from_lines(line1, line2)
+    }
+
+    pub fn from_lines(line1: &str, line2: &str) -> eyre::Result<Self> {
+        for (i, line) in [line1, line2].iter().enumerate() {
+            if line.chars().count() != TD3_LINE_LENGTH {
+                return Err(eyre::eyre!("Line {} of a TD3 MRZ must be {} characters, got {}", i + 1, TD3_LINE_LENGTH, line.chars().count()));
+            }
+            if let Some(c) = line.chars().find(|c| !is_mrz_char(*c)) {
+                return Err(eyre::eyre!("Line {} of the MRZ contains an invalid character: '{}'", i + 1, c));
+            }
+        }
+        if !line1.starts_with('P') {
+            return Err(eyre::eyre!("Not a passport MRZ: the document code must start with \"P\", got \"{}\"", &line1[0..2]));
+        }
+
+        // @dev - Line 1
+        let (surname, given_names) = match line1[5..].split_once("<<") {
+            Some((surname, given_names)) => (surname, given_names),
+            None => (&line1[5..], ""),
+        };
+
+        // @dev - Line 2
+        verify_check_digit("document number", &line2[0..9], &line2[9..10])?;
+        verify_check_digit("birth date", &line2[13..19], &line2[19..20])?;
+        verify_check_digit("expiry date", &line2[21..27], &line2[27..28])?;
+        let personal_number = &line2[28..42];
+        if !(personal_number.chars().all(|c| c == '<') && &line2[42..43] == "<") {
+            verify_check_digit("personal number", personal_number, &line2[42..43])?;
+        }
+        let composite = format!("{}{}{}", &line2[0..10], &line2[13..20], &line2[21..43]);
+        verify_check_digit("composite", &composite, &line2[43..44])?;
+
+        let expiry_date = parse_yymmdd(&line2[21..27], |yy| 2000 + yy).map_err(|e| eyre::eyre!("Invalid expiry date: {}", e))?;
+        // @dev - The century of a birth date is not in the MRZ. A birth date cannot be after the expiry date, so 19YY is chosen if 20YY would be.
+        let birth_date = parse_yymmdd(&line2[13..19], |yy| if 2000 + yy <= expiry_date.year() { 2000 + yy } else { 1900 + yy })
+            .map_err(|e| eyre::eyre!("Invalid birth date: {}", e))?;
+
+        Ok(Self {
+            document_code: strip_filler(&line1[0..2]),
+            issuing_state: strip_filler(&line1[2..5]),
+            surname: to_name(surname),
+            given_names: to_name(given_names),
+            document_number: strip_filler(&line2[0..9]),
+            nationality: strip_filler(&line2[10..13]),
+            birth_date,
+            sex: Sex::from_mrz(line2.chars().nth(20).expect("the length of the line is checked above"))?,
+            expiry_date,
+            personal_number: strip_filler(personal_number),
+        })
+    }
+
+    /**
+     * @notice - Format the fields into the 2 lines of a TD3 MRZ (w/ the check digits)
+     */
+    pub fn to_lines(&self) -> eyre::Result<(String, String)> {
+        let name = format!("{}<<{}", self.surname.replace(' ', "<"), self.given_names.replace(' ', "<"));
+        let line1 = format!(
+            "{}{}{}",
+            pad(&self.document_code, 2, "document code")?,
+            pad(&self.issuing_state, 3, "issuing state")?,
+            pad(&name, TD3_LINE_LENGTH - 5, "name")?,
+        );
+
+        let document_number = pad(&self.document_number, DOCUMENT_NUMBER_LENGTH, "document number")?;
+        let birth_date = self.birth_date.format("%y%m%d").to_string();
+        let expiry_date = self.expiry_date.format("%y%m%d").to_string();
+        let personal_number = pad(&self.personal_number, 14, "personal number")?;
+        let personal_number_check_digit = if self.personal_number.is_empty() { '<' } else { check_digit(&personal_number)? };
+
+        let document_number_field = format!("{}{}", document_number, check_digit(&document_number)?);
+        let birth_date_field = format!("{}{}", birth_date, check_digit(&birth_date)?);
+        let expiry_date_field = format!("{}{}", expiry_date, check_digit(&expiry_date)?);
+        let personal_number_field = format!("{}{}", personal_number, personal_number_check_digit);
+        let composite = format!("{}{}{}", document_number_field, birth_date_field, expiry_date_field) + &personal_number_field;
+
+        let line2 = format!(
+            "{}{}{}{}{}{}{}",
+            document_number_field,
+            pad(&self.nationality, 3, "nationality")?,
+            birth_date_field,
+            self.sex.to_mrz(),
+            expiry_date_field,
+            personal_number_field,
+            check_digit(&composite)?,
+        );
+        Ok((line1, line2))
+    }
+
+    /**
+     * @notice - The `passport_number` input of the travel_history circuit (= the encoded document number)
+     */
+    pub fn passport_number(&self) -> eyre::Result<FieldElement> {
+        encode_document_number(&self.document_number)
+    }
+}
+
+impl fmt::Display for Mrz {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.to_lines() {
+            Ok((line1, line2)) => write!(f, "{}\n{}", line1, line2),
+            Err(_) => write!(f, "{:?}", self),
+        }
+    }
+}
+
+/**
+ * @notice - Encode an alphanumeric document number (i.e. "L898902C3") into a BN254 field element (= the `passport_number` input of the circuit)
+ * @dev - Encoding: the ASCII bytes of the document number (w/o the filler "<") are read as a big-endian integer.
+ *        i.e. "AB12" = 0x41423132. The encoding is injective because a document number never contains a NUL byte,
+ *        so the leading zeros are distinguishable (i.e. "0012" != "12"), and up to 31 bytes (< 2^248) always fit in the field.
+ * @dev - A numeric document number is encoded in the same way (i.e. "13003286" = 0x3133303033323836), so it differs from the bare integer 13003286.
+ */
+pub fn encode_document_number(document_number: &str) -> eyre::Result<FieldElement> {
+    let document_number = strip_filler(document_number);
+    if document_number.is_empty() {
+        return Err(eyre::eyre!("The document number is empty"));
+    }
+    if document_number.len() > MAX_ENCODED_LENGTH {
+        return Err(eyre::eyre!("The document number \"{}\" is longer than {} characters", document_number, MAX_ENCODED_LENGTH));
+    }
+    if let Some(c) = document_number.chars().find(|c| !(c.is_ascii_digit() || c.is_ascii_uppercase())) {
+        return Err(eyre::eyre!("The document number \"{}\" contains an invalid character: '{}'", document_number, c));
+    }
+    Ok(FieldElement::from_be_bytes_reduce(document_number.as_bytes()))
+}
+
+/**
+ * @notice - Decode a `passport_number` field element into the document number (= the inverse of encode_document_number())
+ */
+pub fn decode_document_number(passport_number: FieldElement) -> eyre::Result<String> {
+    let bytes = passport_number.to_be_bytes();
+    let bytes: Vec<u8> = bytes.into_iter().skip_while(|byte| *byte == 0).collect();
+    let document_number = String::from_utf8(bytes).map_err(|_| eyre::eyre!("{} is not an encoded document number", passport_number.to_hex()))?;
+    if document_number.is_empty() || !document_number.chars().all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()) {
+        return Err(eyre::eyre!("0x{} is not an encoded document number", passport_number.to_hex()));
+    }
+    Ok(document_number)
+}
+
+/**
+ * @notice - The check digit of ICAO 9303 (= the weighted sum (7, 3, 1) of the values of the characters, modulo 10)
+ * @dev - The value of "0"-"9" is 0-9, "A"-"Z" is 10-35 and "<" is 0.
+ */
+pub fn check_digit(value: &str) -> eyre::Result<char> {
+    const WEIGHTS: [u32; 3] = [7, 3, 1];
+    let mut sum = 0;
+    for (i, c) in value.chars().enumerate() {
+        let char_value = match c {
+            '0'..='9' => c as u32 - '0' as u32,
+            'A'..='Z' => c as u32 - 'A' as u32 + 10,
+            '<' => 0,
+            _ => return Err(eyre::eyre!("Invalid MRZ character: '{}'", c)),
+        };
+        sum += char_value * WEIGHTS[i % 3];
+    }
+    Ok(char::from_digit(sum % 10, 10).expect("sum % 10 is a digit"))
+}
+
+fn verify_check_digit(field: &str, value: &str, expected: &str) -> eyre::Result<()> {
+    let actual = check_digit(value)?;
+    if expected != actual.to_string() {
+        return Err(eyre::eyre!("Invalid check digit of the {} \"{}\": expected {}, got {}", field, value, actual, expected));
+    }
+    Ok(())
+}
+
+fn parse_yymmdd(value: &str, century: impl Fn(i32) -> i32) -> eyre::Result<NaiveDate> {
+    let parse = |range: std::ops::Range<usize>| value[range].parse::<u32>().map_err(|_| eyre::eyre!("\"{}\" is not YYMMDD", value));
+    let year = century(parse(0..2)? as i32);
+    NaiveDate::from_ymd_opt(year, parse(2..4)?, parse(4..6)?).ok_or_else(|| eyre::eyre!("\"{}\" is not a valid date", value))
+}
+
+fn is_mrz_char(c: char) -> bool {
+    c.is_ascii_digit() || c.is_ascii_uppercase() || c == '<'
+}
+
+fn strip_filler(value: &str) -> String {
+    value.trim_end_matches('<').to_string()
+}
+
+fn to_name(value: &str) -> String {
+    value.split('<').filter(|part| !part.is_empty()).collect::<Vec<_>>().join(" ")
+}
+
+fn pad(value: &str, length: usize, field: &str) -> eyre::Result<String> {
+    if value.len() > length {
+        return Err(eyre::eyre!("The {} \"{}\" is longer than {} characters", field, value, length));
+    }
+    if let Some(c) = value.chars().find(|c| !is_mrz_char(*c)) {
+        return Err(eyre::eyre!("The {} \"{}\" contains an invalid character: '{}'", field, value, c));
+    }
+    Ok(format!("{:<<width$}", value, width = length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // @dev - The specimen of ICAO 9303 Part 4 (Appendix A)
+    const SPECIMEN_LINE1: &str = "P<UTOERIKSSON<<ANNA<MARIA<<<<<<<<<<<<<<<<<<<";
+    const SPECIMEN_LINE2: &str = "L898902C36UTO7408122F1204159ZE184226B<<<<<10";
+
+    #[test]
+    fn parses_the_icao_specimen() {
+        let mrz = Mrz::parse(&format!("{}\n{}\n", SPECIMEN_LINE1, SPECIMEN_LINE2)).unwrap();
+        assert_eq!(mrz.document_code, "P");
+        assert_eq!(mrz.issuing_state, "UTO");
+        assert_eq!(mrz.surname, "ERIKSSON");
+        assert_eq!(mrz.given_names, "ANNA MARIA");
+        assert_eq!(mrz.document_number, "L898902C3");
+        assert_eq!(mrz.nationality, "UTO");
+        assert_eq!(mrz.birth_date, NaiveDate::from_ymd_opt(1974, 8, 12).unwrap());
+        assert_eq!(mrz.sex, Sex::Female);
+        assert_eq!(mrz.expiry_date, NaiveDate::from_ymd_opt(2012, 4, 15).unwrap());
+        assert_eq!(mrz.personal_number, "ZE184226B");
+    }
+
+    #[test]
+    fn formats_back_into_the_same_lines() {
+        let mrz = Mrz::from_lines(SPECIMEN_LINE1, SPECIMEN_LINE2).unwrap();
+        assert_eq!(mrz.to_lines().unwrap(), (SPECIMEN_LINE1.to_string(), SPECIMEN_LINE2.to_string()));
+        assert_eq!(Mrz::parse(&mrz.to_string()).unwrap(), mrz);
+    }
+
+    #[test]
+    fn round_trips_a_passport_without_a_personal_number() {
+        let mrz = Mrz {
+            document_code: "P".to_string(),
+            issuing_state: "D".to_string(),
+            surname: "MUSTERMANN".to_string(),
+            given_names: "ERIKA".to_string(),
+            document_number: "C01X00T47".to_string(),
+            nationality: "D".to_string(),
+            birth_date: NaiveDate::from_ymd_opt(1964, 8, 12).unwrap(),
+            sex: Sex::Female,
+            expiry_date: NaiveDate::from_ymd_opt(2027, 10, 31).unwrap(),
+            personal_number: String::new(),
+        };
+        let (line1, line2) = mrz.to_lines().unwrap();
+        assert_eq!(line2.len(), TD3_LINE_LENGTH);
+        assert_eq!(&line2[28..43], "<<<<<<<<<<<<<<<");
+        assert_eq!(Mrz::from_lines(&line1, &line2).unwrap(), mrz);
+    }
+
+    #[test]
+    fn resolves_the_century_of_a_birth_date_from_the_expiry_date() {
+        let mut mrz = Mrz::from_lines(SPECIMEN_LINE1, SPECIMEN_LINE2).unwrap();
+        mrz.birth_date = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap();
+        mrz.expiry_date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let (line1, line2) = mrz.to_lines().unwrap();
+        assert_eq!(Mrz::from_lines(&line1, &line2).unwrap().birth_date.year(), 2010);
+    }
+
+    #[test]
+    fn rejects_an_invalid_check_digit() {
+        for (position, field) in [(9, "document number"), (19, "birth date"), (27, "expiry date"), (42, "personal number"), (43, "composite")] {
+            let mut line2 = SPECIMEN_LINE2.to_string();
+            let digit = line2.chars().nth(position).unwrap().to_digit(10).unwrap();
+            line2.replace_range(position..position + 1, &((digit + 1) % 10).to_string());
+            let error = Mrz::from_lines(SPECIMEN_LINE1, &line2).unwrap_err().to_string();
+            assert!(error.contains(field), "{}: {}", field, error);
+        }
+    }
+
+    #[test]
+    fn rejects_a_malformed_mrz() {
+        assert!(Mrz::parse(SPECIMEN_LINE1).is_err());
+        assert!(Mrz::from_lines(SPECIMEN_LINE1, &SPECIMEN_LINE2[..43]).is_err());
+        assert!(Mrz::from_lines(&SPECIMEN_LINE1.replace("ANNA", "anna"), SPECIMEN_LINE2).is_err());
+        assert!(Mrz::from_lines(&SPECIMEN_LINE1.replacen('P', "V", 1), SPECIMEN_LINE2).is_err());
+    }
+
+    #[test]
+    fn computes_the_check_digits_of_icao_9303() {
+        assert_eq!(check_digit("L898902C3").unwrap(), '6');
+        assert_eq!(check_digit("740812").unwrap(), '2');
+        assert_eq!(check_digit("120415").unwrap(), '9');
+        assert_eq!(check_digit("<<<<<<").unwrap(), '0');
+        assert!(check_digit("l898902c3").is_err());
+    }
+
+    #[test]
+    fn encodes_a_document_number_as_big_endian_ascii() {
+        assert_eq!(encode_document_number("AB12").unwrap(), FieldElement::from(0x41423132u128));
+        assert_eq!(encode_document_number("L898902C3<").unwrap(), encode_document_number("L898902C3").unwrap());
+        assert_ne!(encode_document_number("13003286").unwrap(), FieldElement::from(13003286u128));
+        assert_eq!(
+            Mrz::from_lines(SPECIMEN_LINE1, SPECIMEN_LINE2).unwrap().passport_number().unwrap(),
+            encode_document_number("L898902C3").unwrap()
+        );
+    }
+
+    #[test]
+    fn round_trips_document_numbers() {
+        let max_length = "Z".repeat(MAX_ENCODED_LENGTH);
+        for document_number in ["L898902C3", "C01X00T47", "13003286", "000000001", "1", "0", "ZZZZZZZZZ", max_length.as_str()] {
+            let encoded = encode_document_number(document_number).unwrap();
+            assert_eq!(decode_document_number(encoded).unwrap(), document_number);
+        }
+    }
+
+    #[test]
+    fn distinguishes_leading_zeros() {
+        assert_ne!(encode_document_number("0012").unwrap(), encode_document_number("12").unwrap());
+    }
+
+    #[test]
+    fn rejects_an_invalid_document_number() {
+        assert!(encode_document_number("").is_err());
+        assert!(encode_document_number("<<<").is_err());
+        assert!(encode_document_number("ab123").is_err());
+        assert!(encode_document_number("A-123").is_err());
+        assert!(encode_document_number(&"1".repeat(MAX_ENCODED_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn rejects_a_field_element_which_is_not_an_encoded_document_number() {
+        assert!(decode_document_number(FieldElement::zero()).is_err());
+        assert!(decode_document_number(FieldElement::from(13003286u128)).is_err());
+        assert!(decode_document_number(-FieldElement::one()).is_err());
+    }
+}
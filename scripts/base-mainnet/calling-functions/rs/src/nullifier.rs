@@ -0,0 +1,212 @@
+// @dev - Alloy
+use alloy::{
+    network::Network,
+    primitives::{Address, FixedBytes, TxHash},
+    providers::Provider,
+};
+
+use crate::contracts::TravelHistoryManager::TravelHistoryManagerInstance;
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// @dev - The default path of the local nullifier registry (relative to the root directory (= /rs))
+pub const DEFAULT_NULLIFIER_REGISTRY_PATH: &str = "data/nullifiers.json";
+
+/**
+ * @notice - A nullifier, which has been recorded on-chain via TravelHistoryManager#recordTravelHistoryProof()
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NullifierRecord {
+    pub nullifier: FixedBytes<32>,
+    pub chain: String,
+    pub contract: Address,
+    pub tx_hash: Option<TxHash>,
+    pub recorded_at: u64, // @dev - UNIX timestamp
+}
+
+/**
+ * @notice - The local registry of the nullifiers per traveler, which is stored as a JSON file.
+ */
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NullifierRegistry {
+    #[serde(skip)]
+    path: PathBuf,
+    pub travelers: BTreeMap<Address, Vec<NullifierRecord>>,
+}
+
+impl NullifierRegistry {
+    /**
+     * @dev - Load the registry from a JSON file. An empty registry is returned if the file does not exist yet.
+     */
+    pub fn load<P: AsRef<Path>>(path: P) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let mut registry: NullifierRegistry = if path.exists() {
+            serde_json::from_str(&std::fs::read_to_string(path)?)?
+        } else {
+            NullifierRegistry::default()
+        };
+        registry.path = path.to_path_buf();
+        Ok(registry)
+    }
+
+    pub fn save(&self) -> eyre::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn record(&mut self, traveler: Address, record: NullifierRecord) {
+        self.travelers.entry(traveler).or_default().push(record);
+    }
+
+    pub fn records_of(&self, traveler: Address) -> &[NullifierRecord] {
+        self.travelers.get(&traveler).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /**
+     * @dev - Find the traveler and the record of a given nullifier on a given chain
+     */
+    pub fn find(&self, chain: &str, nullifier: FixedBytes<32>) -> Option<(Address, &NullifierRecord)> {
+        self.travelers.iter().find_map(|(traveler, records)| {
+            records
+                .iter()
+                .find(|record| record.chain == chain && record.nullifier == nullifier)
+                .map(|record| (*traveler, record))
+        })
+    }
+}
+
+/**
+ * @notice - The result of the pre-submission check of a nullifier.
+ * @dev - `current_nullifier` is the `nullifiersByWalletAddresses(traveler)` on-chain, which is zero if no proof has been recorded yet.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NullifierCheck {
+    pub nullifier: FixedBytes<32>,
+    pub is_spent_on_chain: bool,
+    pub current_nullifier: FixedBytes<32>,
+    pub recorded_locally_by: Option<Address>,
+}
+
+impl NullifierCheck {
+    /**
+     * @dev - The nullifier of the traveler that will be overwritten in `nullifiersByWalletAddresses` by a new proof (if any)
+     */
+    pub fn overwritten_nullifier(&self) -> Option<FixedBytes<32>> {
+        (!self.current_nullifier.is_zero() && self.current_nullifier != self.nullifier).then_some(self.current_nullifier)
+    }
+
+    /**
+     * @dev - Return an error if the nullifier has already been spent (= double-spending)
+     */
+    pub fn ensure_unspent(&self) -> eyre::Result<()> {
+        if self.is_spent_on_chain {
+            return Err(eyre::eyre!("The nullifier {} has already been spent on-chain", self.nullifier));
+        }
+        if let Some(traveler) = self.recorded_locally_by {
+            return Err(eyre::eyre!("The nullifier {} has already been recorded by {} (local registry)", self.nullifier, traveler));
+        }
+        Ok(())
+    }
+}
+
+/**
+ * @notice - Check a nullifier against the on-chain `nullifiers(bytes32)` and `nullifiersByWalletAddresses(address)` and the local registry, before submitting a proof.
+ */
+pub async fn check_nullifier<P: Provider<N>, N: Network>(
+    travel_history_manager: &TravelHistoryManagerInstance<P, N>,
+    registry: &NullifierRegistry,
+    chain: &str,
+    traveler: Address,
+    nullifier: FixedBytes<32>,
+) -> eyre::Result<NullifierCheck> {
+    let is_spent_on_chain = travel_history_manager.nullifiers(nullifier).call().await?;
+    let current_nullifier = travel_history_manager.nullifiersByWalletAddresses(traveler).call().await?;
+
+    Ok(NullifierCheck {
+        nullifier,
+        is_spent_on_chain,
+        current_nullifier,
+        recorded_locally_by: registry.find(chain, nullifier).map(|(traveler, _)| traveler),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: Address = Address::repeat_byte(0xa1);
+    const BOB: Address = Address::repeat_byte(0xb0);
+
+    fn record(nullifier: u8, chain: &str) -> NullifierRecord {
+        NullifierRecord {
+            nullifier: FixedBytes::repeat_byte(nullifier),
+            chain: chain.to_string(),
+            contract: Address::repeat_byte(0xcc),
+            tx_hash: None,
+            recorded_at: 1_700_000_000,
+        }
+    }
+
+    fn check(nullifier: u8, current_nullifier: FixedBytes<32>) -> NullifierCheck {
+        NullifierCheck {
+            nullifier: FixedBytes::repeat_byte(nullifier),
+            is_spent_on_chain: false,
+            current_nullifier,
+            recorded_locally_by: None,
+        }
+    }
+
+    #[test]
+    fn finds_a_nullifier_per_chain_and_the_records_of_a_traveler() {
+        let mut registry = NullifierRegistry::default();
+        registry.record(ALICE, record(1, "base-mainnet"));
+        registry.record(ALICE, record(2, "base-mainnet"));
+        registry.record(BOB, record(3, "celo-mainnet"));
+
+        assert_eq!(registry.records_of(ALICE).len(), 2);
+        assert_eq!(registry.records_of(BOB), &[record(3, "celo-mainnet")]);
+        assert!(registry.records_of(Address::ZERO).is_empty());
+
+        let (traveler, found) = registry.find("base-mainnet", FixedBytes::repeat_byte(2)).unwrap();
+        assert_eq!((traveler, found), (ALICE, &record(2, "base-mainnet")));
+        assert_eq!(registry.find("celo-mainnet", FixedBytes::repeat_byte(3)).unwrap().0, BOB);
+        // @dev - The same nullifier on another chain is not a double-spending
+        assert!(registry.find("base-mainnet", FixedBytes::repeat_byte(3)).is_none());
+    }
+
+    #[test]
+    fn saves_and_loads_the_registry() {
+        let path = std::env::temp_dir().join(format!("travel_history_nullifiers_{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut registry = NullifierRegistry::load(&path).unwrap();
+        assert!(registry.travelers.is_empty());
+
+        registry.record(ALICE, record(1, "base-mainnet"));
+        registry.save().unwrap();
+        assert_eq!(NullifierRegistry::load(&path).unwrap().records_of(ALICE), &[record(1, "base-mainnet")]);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn reports_the_nullifier_overwritten_by_a_new_proof() {
+        assert_eq!(check(1, FixedBytes::ZERO).overwritten_nullifier(), None);
+        assert_eq!(check(1, FixedBytes::repeat_byte(1)).overwritten_nullifier(), None);
+        assert_eq!(check(1, FixedBytes::repeat_byte(2)).overwritten_nullifier(), Some(FixedBytes::repeat_byte(2)));
+    }
+
+    #[test]
+    fn rejects_a_nullifier_spent_on_chain_or_recorded_locally() {
+        assert!(check(1, FixedBytes::ZERO).ensure_unspent().is_ok());
+
+        let spent = NullifierCheck { is_spent_on_chain: true, ..check(1, FixedBytes::ZERO) };
+        assert!(spent.ensure_unspent().unwrap_err().to_string().contains("already been spent on-chain"));
+
+        let recorded = NullifierCheck { recorded_locally_by: Some(BOB), ..check(1, FixedBytes::ZERO) };
+        assert!(recorded.ensure_unspent().unwrap_err().to_string().contains(&format!("{} (local registry)", BOB)));
+    }
+}
@@ -0,0 +1,538 @@
+// @dev - Alloy
+use alloy::primitives::FixedBytes;
+
+use crate::country_codes::{self, Region};
+use crate::public_inputs::TravelPublicInputs;
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::Path;
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// @dev - The default path of the stay-policy rules (relative to the root directory (= /rs))
+pub const DEFAULT_STAY_POLICY_PATH: &str = "config/stay-policy.toml";
+
+/**
+ * @notice - A stay of a traveler in a country, decoded from the publicInputs of a travel history proof.
+ * @dev - The publicInputs are ordered as [root, country_code, enter_date, exit_date, nullifierHash] (= DataType.PublicInput)
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Stay {
+    pub country_code: u64,
+    pub enter_date: u64, // @dev - UNIX timestamp
+    pub exit_date: u64,  // @dev - UNIX timestamp
+}
+
+impl Stay {
+    pub fn new(country_code: u64, enter_date: u64, exit_date: u64) -> eyre::Result<Self> {
+        if exit_date < enter_date {
+            return Err(eyre::eyre!("exit_date ({}) is before enter_date ({})", exit_date, enter_date));
+        }
+        Ok(Self { country_code, enter_date, exit_date })
+    }
+
+    /**
+     * @dev - Decode a stay from the 5 publicInputs of a travel history proof.
+     */
+    pub fn from_public_inputs(public_inputs: &[FixedBytes<32>]) -> eyre::Result<Self> {
+        TravelPublicInputs::from_bytes32s(public_inputs)?.stay()
+    }
+
+    /**
+     * @dev - The day numbers (= days since the UNIX epoch) of this stay. Both the entry day and the exit day are counted as a full day.
+     */
+    pub fn day_range(&self) -> std::ops::RangeInclusive<u64> {
+        (self.enter_date / SECONDS_PER_DAY)..=(self.exit_date / SECONDS_PER_DAY)
+    }
+
+    /**
+     * @dev - The number of days of this stay (incl. the entry day and the exit day)
+     */
+    pub fn days(&self) -> u64 {
+        self.exit_date / SECONDS_PER_DAY - self.enter_date / SECONDS_PER_DAY + 1
+    }
+}
+
+/**
+ * @notice - The stay-policy of a border authority, which is loaded from a TOML config file (i.e. config/stay-policy.toml)
+ * @dev - `groups` maps a group name (i.e. "nordic") to a list of country codes (= calling codes without "+").
+ * @dev - The regions of the country code catalogue ("schengen", "eu", "eea") can be used as a group without defining it in `groups`.
+ */
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StayPolicy {
+    #[serde(default)]
+    pub groups: BTreeMap<String, Vec<u64>>,
+    #[serde(default)]
+    pub rules: Vec<StayRule>,
+}
+
+/**
+ * @notice - A rule of the stay-policy, which is applied to the stays in the given countries and country groups.
+ * @dev - A rule with `visa` is only applied to travelers who hold that visa.
+ * @dev - A rule is skipped for travelers who hold one of the visas in `waived_by`.
+ */
+#[derive(Debug, Clone, Deserialize)]
+pub struct StayRule {
+    pub name: String,
+    #[serde(default)]
+    pub countries: Vec<u64>,
+    #[serde(default)]
+    pub groups: Vec<String>,
+    #[serde(flatten)]
+    pub limit: StayLimit,
+    #[serde(default)]
+    pub visa: Option<String>,
+    #[serde(default)]
+    pub waived_by: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StayLimit {
+    /// @dev - i.e. Schengen 90/180: up to `max_days` days within any `window_days`-day period
+    RollingWindow { max_days: u64, window_days: u64 },
+    /// @dev - i.e. UK: up to `max_days` days per visit
+    PerVisit { max_days: u64 },
+}
+
+impl StayLimit {
+    pub fn max_days(&self) -> u64 {
+        match self {
+            StayLimit::RollingWindow { max_days, .. } => *max_days,
+            StayLimit::PerVisit { max_days } => *max_days,
+        }
+    }
+}
+
+impl StayPolicy {
+    /**
+     * @dev - Load a stay-policy from a TOML config file.
+     */
+    pub fn load<P: AsRef<Path>>(path: P) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let config = std::fs::read_to_string(path)
+            .map_err(|e| eyre::eyre!("Failed to read the stay-policy file {}: {}", path.display(), e))?;
+        Self::from_toml_str(&config)
+    }
+
+    pub fn from_toml_str(config: &str) -> eyre::Result<Self> {
+        let policy: StayPolicy = toml::from_str(config)?;
+        policy.validate()?;
+        Ok(policy)
+    }
+
+    fn validate(&self) -> eyre::Result<()> {
+        for rule in self.rules.iter() {
+            for group in rule.groups.iter() {
+                if !self.groups.contains_key(group) && group.parse::<Region>().is_err() {
+                    return Err(eyre::eyre!("Rule \"{}\" refers to an unknown country group \"{}\"", rule.name, group));
+                }
+            }
+            if self.country_codes_of(rule).is_empty() {
+                return Err(eyre::eyre!("Rule \"{}\" does not apply to any country", rule.name));
+            }
+            match rule.limit {
+                StayLimit::RollingWindow { max_days, window_days } if max_days == 0 || window_days < max_days => {
+                    return Err(eyre::eyre!("Rule \"{}\" has an invalid rolling window: {}/{} days", rule.name, max_days, window_days));
+                }
+                StayLimit::PerVisit { max_days: 0 } => {
+                    return Err(eyre::eyre!("Rule \"{}\" has max_days = 0", rule.name));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /**
+     * @dev - The country codes that a given rule is applied to (= its `countries` + the members of its `groups`)
+     */
+    pub fn country_codes_of(&self, rule: &StayRule) -> BTreeSet<u64> {
+        let mut country_codes: BTreeSet<u64> = rule.countries.iter().copied().collect();
+        for group in rule.groups.iter() {
+            if let Some(members) = self.groups.get(group) {
+                country_codes.extend(members.iter().copied());
+            } else if let Ok(region) = group.parse::<Region>() {
+                country_codes.extend(country_codes::country_codes_in(region));
+            }
+        }
+        country_codes
+    }
+
+    /**
+     * @notice - Evaluate the stays of a traveler against all rules of this stay-policy.
+     * @param visas - The visas that the traveler holds (i.e. "UK-Standard-Visitor")
+     */
+    pub fn evaluate(&self, stays: &[Stay], visas: &[String]) -> Verdict {
+        let mut outcomes = Vec::new();
+        let mut covered = BTreeSet::new();
+
+        for rule in self.rules.iter() {
+            let max_days = rule.limit.max_days();
+            if let Some(visa) = &rule.visa {
+                if !visas.contains(visa) {
+                    outcomes.push(RuleOutcome::not_applicable(rule, format!("the traveler does not hold the \"{}\" visa", visa)));
+                    continue;
+                }
+            }
+            if let Some(visa) = rule.waived_by.iter().find(|v| visas.contains(v)) {
+                outcomes.push(RuleOutcome::not_applicable(rule, format!("waived by the \"{}\" visa", visa)));
+                continue;
+            }
+
+            let country_codes = self.country_codes_of(rule);
+            let matching: Vec<(usize, &Stay)> = stays
+                .iter()
+                .enumerate()
+                .filter(|(_, stay)| country_codes.contains(&stay.country_code))
+                .collect();
+            covered.extend(matching.iter().map(|(index, _)| *index));
+
+            let outcome = match rule.limit {
+                StayLimit::RollingWindow { window_days, .. } => evaluate_rolling_window(rule, &matching, max_days, window_days),
+                StayLimit::PerVisit { .. } => evaluate_per_visit(rule, &matching, max_days),
+            };
+            outcomes.push(outcome);
+        }
+
+        let uncovered = (0..stays.len()).filter(|index| !covered.contains(index)).collect();
+        Verdict { outcomes, uncovered }
+    }
+}
+
+fn evaluate_rolling_window(rule: &StayRule, matching: &[(usize, &Stay)], max_days: u64, window_days: u64) -> RuleOutcome {
+    // @dev - The days spent in the countries of this rule. Overlapping stays are only counted once.
+    let days: BTreeSet<u64> = matching.iter().flat_map(|(_, stay)| stay.day_range()).collect();
+
+    // @dev - Find the window (ending on a day of presence) that contains the most days of presence.
+    let mut worst: Option<(u64, u64, u64)> = None; // (first_day, last_day, days_used)
+    for last_day in days.iter() {
+        let first_day = last_day.saturating_sub(window_days - 1);
+        let days_used = days.range(first_day..=*last_day).count() as u64;
+        if worst.is_none_or(|(_, _, used)| days_used > used) {
+            worst = Some((first_day, *last_day, days_used));
+        }
+    }
+
+    let (window, days_used, counted) = match worst {
+        Some((first_day, last_day, days_used)) => {
+            let counted = matching
+                .iter()
+                .filter_map(|(index, stay)| {
+                    let range = stay.day_range();
+                    let start = (*range.start()).max(first_day);
+                    let end = (*range.end()).min(last_day);
+                    (start <= end).then(|| CountedStay { index: *index, stay: **stay, days: end - start + 1 })
+                })
+                .collect();
+            (Some((first_day, last_day)), days_used, counted)
+        }
+        None => (None, 0, Vec::new()),
+    };
+
+    RuleOutcome {
+        rule: rule.name.clone(),
+        status: if days_used > max_days { RuleStatus::Breached } else { RuleStatus::Compliant },
+        max_days,
+        days_used,
+        window,
+        counted,
+    }
+}
+
+fn evaluate_per_visit(rule: &StayRule, matching: &[(usize, &Stay)], max_days: u64) -> RuleOutcome {
+    let counted: Vec<CountedStay> = matching
+        .iter()
+        .map(|(index, stay)| CountedStay { index: *index, stay: **stay, days: stay.days() })
+        .collect();
+    let days_used = counted.iter().map(|c| c.days).max().unwrap_or(0);
+
+    RuleOutcome {
+        rule: rule.name.clone(),
+        status: if days_used > max_days { RuleStatus::Breached } else { RuleStatus::Compliant },
+        max_days,
+        days_used,
+        window: None,
+        counted,
+    }
+}
+
+/**
+ * @notice - The explainable verdict of a stay-policy evaluation.
+ * @dev - `uncovered` lists the indices of the stays that no rule was applied to.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Verdict {
+    pub outcomes: Vec<RuleOutcome>,
+    pub uncovered: Vec<usize>,
+}
+
+impl Verdict {
+    pub fn is_breaching(&self) -> bool {
+        self.outcomes.iter().any(|outcome| outcome.status == RuleStatus::Breached)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleStatus {
+    Compliant,
+    Breached,
+    NotApplicable(String),
+}
+
+/**
+ * @dev - `days_used` is the days within the worst window (RollingWindow) or the days of the longest visit (PerVisit).
+ * @dev - `window` is the worst window as the (first, last) day numbers (RollingWindow only).
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuleOutcome {
+    pub rule: String,
+    pub status: RuleStatus,
+    pub max_days: u64,
+    pub days_used: u64,
+    pub window: Option<(u64, u64)>,
+    pub counted: Vec<CountedStay>,
+}
+
+impl RuleOutcome {
+    fn not_applicable(rule: &StayRule, reason: String) -> Self {
+        Self {
+            rule: rule.name.clone(),
+            status: RuleStatus::NotApplicable(reason),
+            max_days: rule.limit.max_days(),
+            days_used: 0,
+            window: None,
+            counted: Vec::new(),
+        }
+    }
+}
+
+/**
+ * @dev - A stay (= the `index`-th stay given to StayPolicy#evaluate()) counted toward a rule, with the number of days counted.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CountedStay {
+    pub index: usize,
+    pub stay: Stay,
+    pub days: u64,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for outcome in self.outcomes.iter() {
+            write!(f, "{}", outcome)?;
+        }
+        for index in self.uncovered.iter() {
+            writeln!(f, "[UNCOVERED] stay #{} is not covered by any rule", index)?;
+        }
+        write!(f, "Verdict: {}", if self.is_breaching() { "BREACHING" } else { "COMPLIANT" })
+    }
+}
+
+impl fmt::Display for RuleOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.status {
+            RuleStatus::NotApplicable(reason) => return writeln!(f, "[N/A] {}: {}", self.rule, reason),
+            RuleStatus::Compliant => write!(f, "[OK] {}: {}/{} days", self.rule, self.days_used, self.max_days)?,
+            RuleStatus::Breached => write!(f, "[BREACHED] {}: {}/{} days", self.rule, self.days_used, self.max_days)?,
+        }
+        match self.window {
+            Some((first_day, last_day)) => writeln!(f, " in {} .. {}", format_day(first_day), format_day(last_day))?,
+            None => writeln!(f)?,
+        }
+        for counted in self.counted.iter() {
+            writeln!(
+                f,
+                "  - stay #{} (country_code: {}, {} .. {}): {} days counted",
+                counted.index,
+                country_codes::lookup(counted.stay.country_code),
+                format_day(counted.stay.enter_date / SECONDS_PER_DAY),
+                format_day(counted.stay.exit_date / SECONDS_PER_DAY),
+                counted.days
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/**
+ * @dev - Format a day number (= days since the UNIX epoch) as YYYY-MM-DD.
+ * @dev - See the "civil_from_days" algorithm: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+ */
+pub fn format_day(day: u64) -> String {
+    let z = day as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + if m <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", y, m, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRANCE: u64 = 33;
+    const UK: u64 = 44;
+    const USA: u64 = 1;
+    const JAPAN: u64 = 81;
+
+    /// @dev - A stay from the day `first_day` to the day `last_day` (= days since the UNIX epoch)
+    fn stay(country_code: u64, first_day: u64, last_day: u64) -> Stay {
+        Stay::new(country_code, first_day * SECONDS_PER_DAY + 3_600, last_day * SECONDS_PER_DAY + 7_200).unwrap()
+    }
+
+    fn outcome<'a>(verdict: &'a Verdict, rule: &str) -> &'a RuleOutcome {
+        verdict.outcomes.iter().find(|outcome| outcome.rule == rule).unwrap()
+    }
+
+    fn default_policy() -> StayPolicy {
+        StayPolicy::load(DEFAULT_STAY_POLICY_PATH).unwrap()
+    }
+
+    #[test]
+    fn rejects_a_stay_exiting_before_its_entry() {
+        assert!(Stay::new(FRANCE, 2 * SECONDS_PER_DAY, SECONDS_PER_DAY).is_err());
+        assert_eq!(stay(FRANCE, 10, 10).days(), 1);
+        assert_eq!(stay(FRANCE, 10, 19).day_range(), 10..=19);
+    }
+
+    #[test]
+    fn counts_the_rolling_window_up_to_its_boundaries() {
+        let policy = default_policy();
+
+        // @dev - 90 days: the limit is reached, but not exceeded
+        let verdict = policy.evaluate(&[stay(FRANCE, 0, 89)], &[]);
+        let schengen = outcome(&verdict, "Schengen 90/180");
+        assert_eq!(schengen.status, RuleStatus::Compliant);
+        assert_eq!(schengen.days_used, 90);
+
+        // @dev - The day 179 is still within the 180-day window starting on the day 0
+        let verdict = policy.evaluate(&[stay(FRANCE, 0, 89), stay(FRANCE, 179, 179)], &[]);
+        let schengen = outcome(&verdict, "Schengen 90/180");
+        assert_eq!(schengen.status, RuleStatus::Breached);
+        assert_eq!(schengen.days_used, 91);
+        assert_eq!(schengen.window, Some((0, 179)));
+        assert_eq!(schengen.counted.iter().map(|c| (c.index, c.days)).collect::<Vec<_>>(), vec![(0, 90), (1, 1)]);
+
+        // @dev - The day 180 is not, so the day 0 falls out of the window
+        let verdict = policy.evaluate(&[stay(FRANCE, 0, 89), stay(FRANCE, 180, 180)], &[]);
+        let schengen = outcome(&verdict, "Schengen 90/180");
+        assert_eq!(schengen.status, RuleStatus::Compliant);
+        assert_eq!(schengen.days_used, 90);
+        assert!(!verdict.is_breaching());
+    }
+
+    #[test]
+    fn counts_overlapping_stays_only_once() {
+        let verdict = default_policy().evaluate(&[stay(FRANCE, 0, 59), stay(FRANCE, 30, 89)], &[]);
+        let schengen = outcome(&verdict, "Schengen 90/180");
+        assert_eq!(schengen.days_used, 90);
+        assert_eq!(schengen.status, RuleStatus::Compliant);
+    }
+
+    #[test]
+    fn limits_the_longest_visit_of_a_per_visit_rule() {
+        let policy = default_policy();
+
+        let verdict = policy.evaluate(&[stay(UK, 0, 179), stay(UK, 200, 209)], &[]);
+        let uk = outcome(&verdict, "UK 180 days per visit");
+        assert_eq!(uk.status, RuleStatus::Compliant);
+        assert_eq!(uk.days_used, 180);
+        assert_eq!(uk.window, None);
+        assert_eq!(uk.counted.len(), 2);
+
+        let verdict = policy.evaluate(&[stay(UK, 0, 180)], &[]);
+        let uk = outcome(&verdict, "UK 180 days per visit");
+        assert_eq!(uk.status, RuleStatus::Breached);
+        assert_eq!(uk.days_used, 181);
+        assert!(verdict.is_breaching());
+    }
+
+    #[test]
+    fn applies_a_visa_rule_only_to_its_holders() {
+        let policy = default_policy();
+        let stays = [stay(USA, 0, 199)];
+
+        let verdict = policy.evaluate(&stays, &[]);
+        assert!(matches!(outcome(&verdict, "US B1/B2 visa").status, RuleStatus::NotApplicable(_)));
+        assert_eq!(verdict.uncovered, vec![0]);
+
+        let verdict = policy.evaluate(&stays, &["US-B1/B2".to_string()]);
+        assert_eq!(outcome(&verdict, "US B1/B2 visa").status, RuleStatus::Breached);
+        assert!(verdict.uncovered.is_empty());
+    }
+
+    #[test]
+    fn skips_a_rule_waived_by_a_visa() {
+        let stays = [stay(FRANCE, 0, 119)];
+        let policy = default_policy();
+        assert!(policy.evaluate(&stays, &[]).is_breaching());
+
+        let verdict = policy.evaluate(&stays, &["Schengen-National-Long-Stay".to_string()]);
+        assert_eq!(
+            outcome(&verdict, "Schengen 90/180").status,
+            RuleStatus::NotApplicable("waived by the \"Schengen-National-Long-Stay\" visa".to_string())
+        );
+        assert!(!verdict.is_breaching());
+    }
+
+    #[test]
+    fn reports_the_stays_not_covered_by_any_rule() {
+        let verdict = default_policy().evaluate(&[stay(FRANCE, 0, 9), stay(JAPAN, 10, 19)], &[]);
+        assert_eq!(verdict.uncovered, vec![1]);
+        assert!(verdict.to_string().contains("[UNCOVERED] stay #1"));
+    }
+
+    #[test]
+    fn resolves_the_custom_groups_and_the_regions() {
+        let policy = default_policy();
+        let rule = |groups: &[&str]| StayRule {
+            name: "test".to_string(),
+            countries: vec![JAPAN],
+            groups: groups.iter().map(|g| g.to_string()).collect(),
+            limit: StayLimit::PerVisit { max_days: 30 },
+            visa: None,
+            waived_by: Vec::new(),
+        };
+        assert_eq!(policy.country_codes_of(&rule(&["nordic"])), BTreeSet::from([45, 46, 47, JAPAN, 354, 358]));
+        assert!(policy.country_codes_of(&rule(&["schengen"])).contains(&FRANCE));
+        assert!(!policy.country_codes_of(&rule(&["schengen"])).contains(&UK));
+    }
+
+    #[test]
+    fn rejects_an_invalid_config() {
+        let error = |config: &str| StayPolicy::from_toml_str(config).unwrap_err().to_string();
+
+        assert!(error("[[rules]]\nname = \"x\"\ngroups = [\"benelux\"]\nkind = \"per_visit\"\nmax_days = 30\n")
+            .contains("unknown country group \"benelux\""));
+        assert!(error("[[rules]]\nname = \"x\"\nkind = \"per_visit\"\nmax_days = 30\n").contains("does not apply to any country"));
+        assert!(error("[[rules]]\nname = \"x\"\ncountries = [33]\nkind = \"rolling_window\"\nmax_days = 90\nwindow_days = 60\n")
+            .contains("invalid rolling window: 90/60 days"));
+        assert!(error("[[rules]]\nname = \"x\"\ncountries = [33]\nkind = \"rolling_window\"\nmax_days = 0\nwindow_days = 60\n")
+            .contains("invalid rolling window"));
+        assert!(error("[[rules]]\nname = \"x\"\ncountries = [33]\nkind = \"per_visit\"\nmax_days = 0\n").contains("max_days = 0"));
+        assert!(StayPolicy::from_toml_str("[[rules]]\nname = \"x\"\ncountries = [33]\nkind = \"daily\"\n").is_err());
+    }
+
+    #[test]
+    fn formats_the_days_around_leap_years() {
+        assert_eq!(format_day(0), "1970-01-01");
+        assert_eq!(format_day(11_016), "2000-02-29"); // @dev - 2000 is a leap year (divisible by 400)
+        assert_eq!(format_day(11_017), "2000-03-01");
+        assert_eq!(format_day(19_416), "2023-02-28");
+        assert_eq!(format_day(19_417), "2023-03-01");
+        assert_eq!(format_day(19_782), "2024-02-29");
+        assert_eq!(format_day(19_783), "2024-03-01");
+        assert_eq!(format_day(19_722), "2023-12-31");
+        assert_eq!(format_day(47_540), "2100-02-28"); // @dev - 2100 is not a leap year (divisible by 100)
+        assert_eq!(format_day(47_541), "2100-03-01");
+    }
+}
@@ -0,0 +1,127 @@
+// @dev - Alloy
+use alloy::primitives::{Bytes, FixedBytes};
+
+use crate::circuit::{CompiledCircuit, DEFAULT_CIRCUIT_PATH};
+use crate::field::field_to_bytes32;
+use crate::inputs::TravelHistoryInputs;
+use crate::public_inputs::TravelPublicInputs;
+
+use std::path::{Path, PathBuf};
+
+/**
+ * @notice - A travel history proof and its publicInputs, which are generated by `bb prove` (i.e. circuits/build.sh)
+ * @dev - The directory of a proof bundle contains the `proof` file and the `public_inputs` file (= the concatenated 32-byte publicInputs), like ./target of the circuit.
+ * @dev - If the directory contains a Prover.toml, its path is kept so that the nullifier can be computed from the witness.
+ * @dev - If the directory contains a `vk` (= `bb write_vk`), its path is kept so that it can be checked against the deployed verifier.
+ * @dev - If the directory (or /circuits/target) contains the compiled circuit (= `nargo compile`), its path is kept so that the witness is executed against the circuit's constraints.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofBundle {
+    pub proof: Bytes,
+    pub public_inputs: Vec<FixedBytes<32>>,
+    pub prover_toml: Option<PathBuf>,
+    pub vk: Option<PathBuf>,
+    pub circuit: Option<PathBuf>,
+}
+
+impl ProofBundle {
+    pub fn load<P: AsRef<Path>>(dir: P) -> eyre::Result<Self> {
+        let dir = dir.as_ref();
+        let proof = std::fs::read(dir.join("proof"))
+            .map_err(|e| eyre::eyre!("Failed to read the proof in {}: {}", dir.display(), e))?;
+        let public_inputs = std::fs::read(dir.join("public_inputs"))
+            .map_err(|e| eyre::eyre!("Failed to read the public_inputs in {}: {}", dir.display(), e))?;
+        if public_inputs.len() % 32 != 0 {
+            return Err(eyre::eyre!("The public_inputs in {} is not a multiple of 32 bytes", dir.display()));
+        }
+
+        let prover_toml = dir.join("Prover.toml");
+        let vk = dir.join("vk");
+        let circuit = [dir.join("travel_history.json"), PathBuf::from(DEFAULT_CIRCUIT_PATH)].into_iter().find(|path| path.exists());
+        Ok(Self {
+            proof: Bytes::from(proof),
+            public_inputs: public_inputs.chunks(32).map(FixedBytes::<32>::from_slice).collect(),
+            prover_toml: prover_toml.exists().then_some(prover_toml),
+            vk: vk.exists().then_some(vk),
+            circuit,
+        })
+    }
+
+    /**
+     * @dev - The typed publicInputs of the proof
+     */
+    pub fn travel_public_inputs(&self) -> eyre::Result<TravelPublicInputs> {
+        TravelPublicInputs::from_bytes32s(&self.public_inputs)
+    }
+
+    /**
+     * @dev - The nullifierHash (= the 5th publicInput), which is returned by the circuit
+     */
+    pub fn nullifier_hash(&self) -> eyre::Result<FixedBytes<32>> {
+        Ok(self.travel_public_inputs()?.nullifier_hash)
+    }
+
+    /**
+     * @notice - Check the publicInputs of the proof against the ones computed from its witness (= Prover.toml), incl. the nullifier returned by the circuit
+     * @dev - If the compiled circuit is present, the witness is executed against it (= its constraints, i.e. `country_code < 2000`, are checked too), and its return value is the expected nullifier.
+     *        Otherwise, the nullifier is computed by the Rust reimplementation of the circuit (= hash.rs).
+     */
+    pub fn check_against_witness<P: AsRef<Path>>(&self, prover_toml: P) -> eyre::Result<TravelPublicInputs> {
+        let prover_toml = prover_toml.as_ref();
+        let public_inputs = self.travel_public_inputs()?;
+        let inputs = TravelHistoryInputs::load(prover_toml)?;
+        let mut expected_public_inputs = TravelPublicInputs::from_inputs(&inputs);
+        if let Some(circuit) = self.circuit.as_ref() {
+            let execution = CompiledCircuit::load(circuit)?
+                .execute(&inputs)
+                .map_err(|e| eyre::eyre!("The witness {} is rejected by the circuit {}: {}", prover_toml.display(), circuit.display(), e))?;
+            expected_public_inputs.nullifier_hash = field_to_bytes32(execution.nullifier);
+        }
+        if expected_public_inputs != public_inputs {
+            return Err(eyre::eyre!(
+                "The publicInputs of the proof ({}) do not match the ones computed from {} ({})",
+                public_inputs,
+                prover_toml.display(),
+                expected_public_inputs
+            ));
+        }
+        Ok(expected_public_inputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::circuit::CIRCUIT_FIXTURE_PATH;
+    use crate::test_vectors::{self, INVALID_COUNTRY_CODE};
+
+    fn write_bundle(dir: &Path, inputs: &TravelHistoryInputs) -> ProofBundle {
+        std::fs::create_dir_all(dir).unwrap();
+        let public_inputs = TravelPublicInputs::from_inputs(inputs);
+        std::fs::write(dir.join("proof"), [0u8; 32]).unwrap();
+        std::fs::write(dir.join("public_inputs"), public_inputs.to_bytes32s().iter().flat_map(|bytes32| bytes32.0).collect::<Vec<u8>>()).unwrap();
+        std::fs::write(dir.join("Prover.toml"), inputs.to_prover_toml()).unwrap();
+        std::fs::copy(CIRCUIT_FIXTURE_PATH, dir.join("travel_history.json")).unwrap();
+        ProofBundle::load(dir).unwrap()
+    }
+
+    #[test]
+    fn executes_the_witness_against_the_compiled_circuit() {
+        let dir = std::env::temp_dir().join(format!("proof_bundle_{}", std::process::id()));
+        let cases = test_vectors::generate(1, 0).unwrap();
+
+        let valid = cases.iter().find(|case| case.should_fail_with.is_none()).unwrap();
+        let bundle = write_bundle(&dir.join("valid"), &valid.inputs);
+        assert_eq!(bundle.circuit, Some(dir.join("valid").join("travel_history.json")));
+        let public_inputs = bundle.check_against_witness(bundle.prover_toml.as_ref().unwrap()).unwrap();
+        assert_eq!(public_inputs.nullifier_hash, field_to_bytes32(valid.expected_nullifier));
+
+        // @dev - The Rust reimplementation computes the same publicInputs, but only the circuit rejects the country code 2000
+        let invalid = cases.iter().find(|case| case.should_fail_with == Some(INVALID_COUNTRY_CODE)).unwrap();
+        let bundle = write_bundle(&dir.join("invalid"), &invalid.inputs);
+        let error = bundle.check_against_witness(bundle.prover_toml.as_ref().unwrap()).unwrap_err().to_string();
+        assert!(error.contains(INVALID_COUNTRY_CODE), "{}", error);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
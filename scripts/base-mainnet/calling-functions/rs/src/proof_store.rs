@@ -0,0 +1,230 @@
+// @dev - Alloy
+use alloy::primitives::{keccak256, Address, Bytes, FixedBytes, TxHash, B256};
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// @dev - The default directory of the local proof store (relative to the root directory (= /rs))
+pub const DEFAULT_PROOF_STORE_PATH: &str = "data/proofs";
+
+/**
+ * @notice - A submission of a proof via TravelHistoryManager#recordTravelHistoryProof()
+ * @dev - `relayer` is the relayer wallet which submitted the proof on behalf of the `traveler` (if any).
+ *        The proof is recorded on-chain under that wallet (= `msg.sender`), so the on-chain views must be called w/ sender().
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofSubmission {
+    pub chain: String,
+    pub contract: Address,
+    pub traveler: Address,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relayer: Option<Address>,
+    pub tx_hash: Option<TxHash>,
+    pub recorded_at: u64, // @dev - UNIX timestamp
+}
+
+impl ProofSubmission {
+    /**
+     * @dev - The account which the proof is recorded under on-chain (= the relayer wallet of a relayed proof, or the traveler)
+     */
+    pub fn sender(&self) -> Address {
+        self.relayer.unwrap_or(self.traveler)
+    }
+}
+
+/**
+ * @notice - A proof in the store, with its publicInputs and its submissions
+ * @dev - `proof_hash` is keccak256(proof), which is the key of the proof in the store.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoredProof {
+    pub proof_hash: B256,
+    pub proof: Bytes,
+    pub public_inputs: Vec<FixedBytes<32>>,
+    pub submissions: Vec<ProofSubmission>,
+}
+
+impl StoredProof {
+    pub fn submissions_of(&self, traveler: Address) -> impl Iterator<Item = &ProofSubmission> {
+        self.submissions.iter().filter(move |submission| submission.traveler == traveler)
+    }
+}
+
+/**
+ * @notice - The content-addressed local store of the travel history proofs, so that the on-chain views keyed by the full proof bytes
+ *           (i.e. getPublicInputsOfTravelHistoryProof()) can be called without digging up the proof files.
+ * @dev - Each proof is stored as <dir>/<keccak256(proof)>.json.
+ */
+#[derive(Debug, Clone)]
+pub struct ProofStore {
+    dir: PathBuf,
+}
+
+impl ProofStore {
+    pub fn open<P: AsRef<Path>>(dir: P) -> eyre::Result<Self> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir).map_err(|e| eyre::eyre!("Failed to create the proof store {}: {}", dir.display(), e))?;
+        Ok(Self { dir: dir.to_path_buf() })
+    }
+
+    pub fn path_of(&self, proof_hash: B256) -> PathBuf {
+        self.dir.join(format!("{:x}.json", proof_hash))
+    }
+
+    /**
+     * @notice - Put a proof with its publicInputs into the store, and return its key (= keccak256(proof))
+     * @dev - Putting the same proof again is a no-op, but with different publicInputs is an error.
+     */
+    pub fn put(&self, proof: &Bytes, public_inputs: &[FixedBytes<32>]) -> eyre::Result<B256> {
+        let proof_hash = keccak256(proof);
+        match self.get(proof_hash)? {
+            Some(stored_proof) if stored_proof.public_inputs != public_inputs => Err(eyre::eyre!(
+                "The proof {} is already stored with different publicInputs",
+                proof_hash
+            )),
+            Some(_) => Ok(proof_hash),
+            None => {
+                self.write(&StoredProof {
+                    proof_hash,
+                    proof: proof.clone(),
+                    public_inputs: public_inputs.to_vec(),
+                    submissions: Vec::new(),
+                })?;
+                Ok(proof_hash)
+            }
+        }
+    }
+
+    /**
+     * @notice - Record a submission of a stored proof
+     */
+    pub fn add_submission(&self, proof_hash: B256, submission: ProofSubmission) -> eyre::Result<()> {
+        let mut stored_proof = self
+            .get(proof_hash)?
+            .ok_or_else(|| eyre::eyre!("The proof {} is not in the store", proof_hash))?;
+        if !stored_proof.submissions.contains(&submission) {
+            stored_proof.submissions.push(submission);
+            self.write(&stored_proof)?;
+        }
+        Ok(())
+    }
+
+    /**
+     * @dev - Get a proof by its key. The content is checked against the key (= keccak256(proof)).
+     */
+    pub fn get(&self, proof_hash: B256) -> eyre::Result<Option<StoredProof>> {
+        let path = self.path_of(proof_hash);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let stored_proof = read(&path)?;
+        if stored_proof.proof_hash != proof_hash {
+            return Err(eyre::eyre!("{} is corrupted: keccak256(proof) is {}", path.display(), stored_proof.proof_hash));
+        }
+        Ok(Some(stored_proof))
+    }
+
+    /**
+     * @dev - All the proofs in the store (sorted by the key)
+     */
+    pub fn all(&self) -> eyre::Result<Vec<StoredProof>> {
+        let mut paths: Vec<PathBuf> = std::fs::read_dir(&self.dir)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|extension| extension == "json"))
+            .collect();
+        paths.sort();
+        paths.iter().map(|path| read(path)).collect()
+    }
+
+    /**
+     * @notice - The proofs which have been submitted by a traveler (on a given chain)
+     */
+    pub fn proofs_of(&self, traveler: Address, chain: Option<&str>) -> eyre::Result<Vec<StoredProof>> {
+        Ok(self
+            .all()?
+            .into_iter()
+            .filter(|stored_proof| {
+                stored_proof
+                    .submissions_of(traveler)
+                    .any(|submission| chain.is_none_or(|chain| submission.chain == chain))
+            })
+            .collect())
+    }
+
+    fn write(&self, stored_proof: &StoredProof) -> eyre::Result<()> {
+        let path = self.path_of(stored_proof.proof_hash);
+        std::fs::write(&path, serde_json::to_string_pretty(stored_proof)?)
+            .map_err(|e| eyre::eyre!("Failed to write {}: {}", path.display(), e))
+    }
+}
+
+/**
+ * @dev - Read a stored proof, whose key is recomputed from the proof bytes (= a stored file cannot lie about its key)
+ */
+fn read(path: &Path) -> eyre::Result<StoredProof> {
+    let json = std::fs::read_to_string(path).map_err(|e| eyre::eyre!("Failed to read {}: {}", path.display(), e))?;
+    let mut stored_proof: StoredProof = serde_json::from_str(&json).map_err(|e| eyre::eyre!("Invalid {}: {}", path.display(), e))?;
+    stored_proof.proof_hash = keccak256(&stored_proof.proof);
+    Ok(stored_proof)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store(name: &str) -> ProofStore {
+        let dir = std::env::temp_dir().join(format!("travel_history_proof_store_{}_{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        ProofStore::open(dir).unwrap()
+    }
+
+    fn submission(traveler: Address, chain: &str) -> ProofSubmission {
+        ProofSubmission { chain: chain.to_string(), contract: Address::repeat_byte(0xaa), traveler, relayer: None, tx_hash: None, recorded_at: 1 }
+    }
+
+    #[test]
+    fn stores_a_proof_by_its_keccak_hash() {
+        let store = temp_store("put");
+        let proof = Bytes::from(vec![1u8; 64]);
+        let public_inputs = vec![FixedBytes::<32>::repeat_byte(2); 5];
+
+        let proof_hash = store.put(&proof, &public_inputs).unwrap();
+        assert_eq!(proof_hash, keccak256(&proof));
+        assert_eq!(store.put(&proof, &public_inputs).unwrap(), proof_hash);
+        assert!(store.put(&proof, &public_inputs[..4]).is_err());
+
+        let stored_proof = store.get(proof_hash).unwrap().unwrap();
+        assert_eq!(stored_proof.proof, proof);
+        assert_eq!(stored_proof.public_inputs, public_inputs);
+        assert!(store.get(B256::ZERO).unwrap().is_none());
+    }
+
+    #[test]
+    fn finds_the_proofs_of_a_traveler() {
+        let store = temp_store("query");
+        let (alice, bob) = (Address::repeat_byte(1), Address::repeat_byte(2));
+        let proof_1 = store.put(&Bytes::from(vec![1u8; 32]), &[]).unwrap();
+        let proof_2 = store.put(&Bytes::from(vec![2u8; 32]), &[]).unwrap();
+        store.add_submission(proof_1, submission(alice, "base-mainnet")).unwrap();
+        store.add_submission(proof_1, submission(alice, "base-mainnet")).unwrap();
+        store.add_submission(proof_2, submission(alice, "celo-mainnet")).unwrap();
+        store.add_submission(proof_2, submission(bob, "celo-mainnet")).unwrap();
+
+        assert_eq!(store.get(proof_1).unwrap().unwrap().submissions.len(), 1);
+        assert_eq!(store.proofs_of(alice, None).unwrap().len(), 2);
+        assert_eq!(store.proofs_of(alice, Some("base-mainnet")).unwrap()[0].proof_hash, proof_1);
+        assert_eq!(store.proofs_of(bob, None).unwrap()[0].proof_hash, proof_2);
+        assert!(store.proofs_of(bob, Some("base-mainnet")).unwrap().is_empty());
+        assert!(store.add_submission(B256::ZERO, submission(bob, "base-mainnet")).is_err());
+    }
+
+    #[test]
+    fn detects_a_corrupted_proof() {
+        let store = temp_store("corrupted");
+        let proof_hash = store.put(&Bytes::from(vec![1u8; 32]), &[]).unwrap();
+        let path = store.path_of(proof_hash);
+        let json = std::fs::read_to_string(&path).unwrap().replace("0x0101", "0x0202");
+        std::fs::write(&path, json).unwrap();
+        assert!(store.get(proof_hash).is_err());
+    }
+}
@@ -0,0 +1,317 @@
+// @dev - Alloy
+use alloy::primitives::U256;
+
+use crate::circuit::{Abi, AbiType};
+use crate::field::BN254_MODULUS;
+
+use std::fmt;
+use std::path::Path;
+
+/**
+ * @notice - An issue of a Prover.toml against the ABI of the compiled circuit
+ * @dev - `path` is the key of the value (i.e. "hash_path[1]"). It is empty for an issue of the whole file (i.e. a TOML syntax error).
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+/**
+ * @notice - The result of validating a Prover.toml, which lists all the issues (instead of stopping at the first one)
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub fn ensure_valid(&self) -> eyre::Result<()> {
+        if self.is_valid() {
+            return Ok(());
+        }
+        Err(eyre::eyre!("Invalid Prover.toml ({} issue(s)):\n{}", self.issues.len(), self))
+    }
+
+    fn push(&mut self, path: &str, message: String) {
+        self.issues.push(ValidationIssue { path: path.to_string(), message });
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, issue) in self.issues.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "  - {}", issue)?;
+        }
+        Ok(())
+    }
+}
+
+/**
+ * @notice - Validate a Prover.toml file against the ABI of the compiled circuit (= target/travel_history.json)
+ */
+pub fn validate_file<P: AsRef<Path>>(abi: &Abi, path: P) -> eyre::Result<ValidationReport> {
+    let path = path.as_ref();
+    let prover_toml = std::fs::read_to_string(path).map_err(|e| eyre::eyre!("Failed to read {}: {}", path.display(), e))?;
+    Ok(validate(abi, &prover_toml))
+}
+
+/**
+ * @notice - Validate a Prover.toml against the ABI of the compiled circuit, before it is passed to `nargo execute` / `bb prove`
+ * @dev - The checks are: missing and extra keys, the length of arrays (i.e. `hash_path`) and strings, non-numeric values (i.e. `index = ""`),
+ *        the range of integers (i.e. u64 overflow), and the BN254 field modulus.
+ */
+pub fn validate(abi: &Abi, prover_toml: &str) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    let table: toml::Table = match toml::from_str(prover_toml) {
+        Ok(table) => table,
+        Err(e) => {
+            report.push("", format!("Invalid TOML: {}", e.message()));
+            return report;
+        }
+    };
+
+    for parameter in abi.parameters.iter() {
+        match table.get(&parameter.name) {
+            Some(value) => validate_value(&mut report, &parameter.name, &parameter.typ, value),
+            None => report.push(&parameter.name, format!("Missing input ({})", describe(&parameter.typ))),
+        }
+    }
+    for key in table.keys() {
+        if !abi.parameters.iter().any(|parameter| &parameter.name == key) {
+            report.push(key, "Unknown input, which is not a parameter of the circuit".to_string());
+        }
+    }
+    report
+}
+
+fn validate_value(report: &mut ValidationReport, path: &str, typ: &AbiType, value: &toml::Value) {
+    match typ {
+        AbiType::Field => {
+            if let Err(message) = parse_unsigned(value).and_then(|parsed| {
+                if parsed >= BN254_MODULUS {
+                    Err(format!("{:#x} overflows the BN254 field modulus", parsed))
+                } else {
+                    Ok(())
+                }
+            }) {
+                report.push(path, message);
+            }
+        }
+        AbiType::Boolean => match value {
+            toml::Value::Boolean(_) => {}
+            toml::Value::String(s) if ["0", "1", "true", "false"].contains(&s.trim()) => {}
+            _ => report.push(path, format!("{} is not a bool", value)),
+        },
+        AbiType::Integer { sign, width } if sign == "unsigned" => {
+            if let Err(message) = parse_unsigned(value).and_then(|parsed| {
+                if *width < 256 && parsed >= U256::from(1) << *width {
+                    Err(format!("{} overflows u{}", parsed, width))
+                } else {
+                    Ok(())
+                }
+            }) {
+                report.push(path, message);
+            }
+        }
+        AbiType::Integer { width, .. } => {
+            if let Err(message) = parse_signed(value).and_then(|(negative, magnitude)| {
+                let limit = U256::from(1) << (*width - 1);
+                if (negative && magnitude > limit) || (!negative && magnitude >= limit) {
+                    Err(format!("{}{} overflows i{}", if negative { "-" } else { "" }, magnitude, width))
+                } else {
+                    Ok(())
+                }
+            }) {
+                report.push(path, message);
+            }
+        }
+        AbiType::Array { length, typ } => match value {
+            toml::Value::Array(values) if values.len() == *length as usize => {
+                for (i, value) in values.iter().enumerate() {
+                    validate_value(report, &format!("{}[{}]", path, i), typ, value);
+                }
+            }
+            toml::Value::Array(values) => report.push(path, format!("Expected an array of {} element(s), got {}", length, values.len())),
+            _ => report.push(path, format!("Expected an array of {} element(s), got {}", length, value)),
+        },
+        AbiType::String { length } => match value {
+            toml::Value::String(s) if s.len() == *length as usize => {}
+            toml::Value::String(s) => report.push(path, format!("Expected a string of {} byte(s), got {}", length, s.len())),
+            _ => report.push(path, format!("{} is not a string", value)),
+        },
+        AbiType::Struct { fields, .. } => match value {
+            toml::Value::Table(table) => {
+                for (name, typ) in fields.iter() {
+                    let field_path = format!("{}.{}", path, name);
+                    match table.get(name) {
+                        Some(value) => validate_value(report, &field_path, typ, value),
+                        None => report.push(&field_path, format!("Missing input ({})", describe(typ))),
+                    }
+                }
+                for key in table.keys().filter(|key| !fields.iter().any(|(name, _)| name == *key)) {
+                    report.push(&format!("{}.{}", path, key), "Unknown field of the struct".to_string());
+                }
+            }
+            _ => report.push(path, format!("{} is not a table", value)),
+        },
+        AbiType::Tuple { fields } => match value {
+            toml::Value::Array(values) if values.len() == fields.len() => {
+                for (i, (typ, value)) in fields.iter().zip(values.iter()).enumerate() {
+                    validate_value(report, &format!("{}.{}", path, i), typ, value);
+                }
+            }
+            _ => report.push(path, format!("Expected a tuple of {} element(s), got {}", fields.len(), value)),
+        },
+    }
+}
+
+/**
+ * @dev - Parse a non-negative numeric value of Prover.toml: a TOML integer, or a decimal / "0x"-prefixed hex string
+ */
+fn parse_unsigned(value: &toml::Value) -> Result<U256, String> {
+    match parse_signed(value)? {
+        (true, magnitude) if !magnitude.is_zero() => Err(format!("-{} is negative", magnitude)),
+        (_, magnitude) => Ok(magnitude),
+    }
+}
+
+/**
+ * @dev - Parse a numeric value of Prover.toml into (= is negative, magnitude)
+ */
+fn parse_signed(value: &toml::Value) -> Result<(bool, U256), String> {
+    match value {
+        toml::Value::Integer(i) => Ok((*i < 0, U256::from(i.unsigned_abs()))),
+        toml::Value::String(s) => {
+            let s = s.trim();
+            if s.is_empty() {
+                return Err("The value is empty".to_string());
+            }
+            let (negative, s) = match s.strip_prefix('-') {
+                Some(s) => (true, s),
+                None => (false, s),
+            };
+            let parsed = match s.strip_prefix("0x") {
+                Some(hex) => U256::from_str_radix(hex, 16),
+                None => U256::from_str_radix(s, 10),
+            };
+            parsed.map(|magnitude| (negative, magnitude)).map_err(|_| format!("\"{}\" is not a number", value.as_str().unwrap_or(s)))
+        }
+        _ => Err(format!("{} is not a number", value)),
+    }
+}
+
+fn describe(typ: &AbiType) -> String {
+    match typ {
+        AbiType::Field => "Field".to_string(),
+        AbiType::Boolean => "bool".to_string(),
+        AbiType::Integer { sign, width } => format!("{}{}", if sign == "unsigned" { "u" } else { "i" }, width),
+        AbiType::Array { length, typ } => format!("[{}; {}]", describe(typ), length),
+        AbiType::String { length } => format!("str<{}>", length),
+        AbiType::Struct { path, .. } => path.clone(),
+        AbiType::Tuple { fields } => format!("({})", fields.iter().map(describe).collect::<Vec<_>>().join(", ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_vectors;
+
+    /// @dev - The ABI of circuits/target/travel_history.json (= `nargo compile` of circuits/src/main.nr)
+    fn abi() -> Abi {
+        serde_json::from_str(
+            r#"{
+                "parameters": [
+                    { "name": "root", "type": { "kind": "field" }, "visibility": "public" },
+                    { "name": "hash_path", "type": { "kind": "array", "length": 2, "type": { "kind": "field" } }, "visibility": "private" },
+                    { "name": "index", "type": { "kind": "field" }, "visibility": "private" },
+                    { "name": "secret", "type": { "kind": "field" }, "visibility": "private" },
+                    { "name": "passport_number", "type": { "kind": "field" }, "visibility": "private" },
+                    { "name": "country_code", "type": { "kind": "integer", "sign": "unsigned", "width": 64 }, "visibility": "public" },
+                    { "name": "enter_date", "type": { "kind": "integer", "sign": "unsigned", "width": 64 }, "visibility": "public" },
+                    { "name": "exit_date", "type": { "kind": "integer", "sign": "unsigned", "width": 64 }, "visibility": "public" }
+                ],
+                "return_type": { "abi_type": { "kind": "field" }, "visibility": "public" },
+                "error_types": {}
+            }"#,
+        )
+        .unwrap()
+    }
+
+    fn valid_prover_toml() -> String {
+        test_vectors::generate(1, 0).unwrap()[0].inputs.to_prover_toml()
+    }
+
+    fn paths(report: &ValidationReport) -> Vec<&str> {
+        report.issues.iter().map(|issue| issue.path.as_str()).collect()
+    }
+
+    #[test]
+    fn accepts_a_valid_prover_toml() {
+        let report = validate(&abi(), &valid_prover_toml());
+        assert!(report.is_valid(), "{}", report);
+    }
+
+    #[test]
+    fn rejects_the_placeholders_of_prover_example_toml() {
+        let report = validate(&abi(), include_str!("../../../../../circuits/Prover.example.toml"));
+        assert_eq!(
+            paths(&report),
+            vec!["root", "hash_path[0]", "hash_path[1]", "index", "secret", "passport_number", "country_code", "enter_date", "exit_date"]
+        );
+        assert_eq!(report.issues[3].message, "The value is empty");
+    }
+
+    #[test]
+    fn rejects_missing_and_extra_keys() {
+        let prover_toml = valid_prover_toml().replace("secret =", "name =");
+        let report = validate(&abi(), &prover_toml);
+        assert_eq!(paths(&report), vec!["secret", "name"]);
+        assert!(report.ensure_valid().is_err());
+    }
+
+    #[test]
+    fn rejects_a_wrong_hash_path_length() {
+        let prover_toml = valid_prover_toml().replace("hash_path = [\n", "hash_path = [\n  \"0x01\",\n");
+        let report = validate(&abi(), &prover_toml);
+        assert_eq!(paths(&report), vec!["hash_path"]);
+        assert_eq!(report.issues[0].message, "Expected an array of 2 element(s), got 3");
+    }
+
+    #[test]
+    fn rejects_a_u64_overflow() {
+        let prover_toml = valid_prover_toml().replace("country_code = \"1999\"", "country_code = \"18446744073709551616\"");
+        let report = validate(&abi(), &prover_toml);
+        assert_eq!(paths(&report), vec!["country_code"]);
+        assert!(report.issues[0].message.contains("overflows u64"), "{}", report);
+
+        let prover_toml = valid_prover_toml().replace("country_code = \"1999\"", "country_code = -1");
+        assert_eq!(paths(&validate(&abi(), &prover_toml)), vec!["country_code"]);
+    }
+
+    #[test]
+    fn rejects_a_field_overflow() {
+        let prover_toml = valid_prover_toml().replace("index = \"0x01\"", &format!("index = \"{:#x}\"", BN254_MODULUS));
+        let report = validate(&abi(), &prover_toml);
+        assert_eq!(paths(&report), vec!["index"]);
+        assert!(report.issues[0].message.contains("overflows the BN254 field modulus"), "{}", report);
+    }
+}
@@ -0,0 +1,237 @@
+// @dev - Alloy
+use alloy::primitives::{FixedBytes, U256};
+
+use crate::contracts::DataType;
+use crate::country_codes;
+use crate::field::{bytes32_to_field, field_to_bytes32};
+use crate::inputs::TravelHistoryInputs;
+use crate::policy::{format_day, Stay, SECONDS_PER_DAY};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// @dev - The number of the publicInputs of a travel history proof (= root, country_code, enter_date, exit_date, nullifierHash)
+pub const PUBLIC_INPUTS_LENGTH: usize = 5;
+
+/**
+ * @notice - The typed publicInputs of a travel history proof, which is the single source of truth of their encoding.
+ * @dev - On-chain, they are a bytes32[5] (= the input of recordTravelHistoryProof()) or a DataType.PublicInput (= the output of getPublicInputsOfTravelHistoryProof()), in the order of:
+ *        [root, country_code, enter_date, exit_date, nullifierHash]
+ * @dev - `root` and `nullifier_hash` are BN254 field elements (< the modulus). `country_code`, `enter_date` and `exit_date` are u64s, which are packed big-endian in a field element.
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "UncheckedTravelPublicInputs")]
+pub struct TravelPublicInputs {
+    pub root: FixedBytes<32>,
+    pub country_code: u64,
+    pub enter_date: u64, // @dev - UNIX timestamp
+    pub exit_date: u64,  // @dev - UNIX timestamp
+    pub nullifier_hash: FixedBytes<32>,
+}
+
+impl TravelPublicInputs {
+    pub fn new(root: FixedBytes<32>, country_code: u64, enter_date: u64, exit_date: u64, nullifier_hash: FixedBytes<32>) -> eyre::Result<Self> {
+        bytes32_to_field(root).map_err(|e| eyre::eyre!("Invalid root: {}", e))?;
+        bytes32_to_field(nullifier_hash).map_err(|e| eyre::eyre!("Invalid nullifierHash: {}", e))?;
+        Ok(Self { root, country_code, enter_date, exit_date, nullifier_hash })
+    }
+
+    /**
+     * @notice - The publicInputs that a proof of the given inputs has (= incl. the nullifier returned by the circuit)
+     */
+    pub fn from_inputs(inputs: &TravelHistoryInputs) -> Self {
+        Self {
+            root: field_to_bytes32(inputs.root),
+            country_code: inputs.country_code,
+            enter_date: inputs.enter_date,
+            exit_date: inputs.exit_date,
+            nullifier_hash: field_to_bytes32(inputs.expected_nullifier()),
+        }
+    }
+
+    /**
+     * @dev - Decode the bytes32[5] publicInputs (i.e. the `public_inputs` of a proof bundle)
+     */
+    pub fn from_bytes32s(public_inputs: &[FixedBytes<32>]) -> eyre::Result<Self> {
+        let [root, country_code, enter_date, exit_date, nullifier_hash] = public_inputs else {
+            return Err(eyre::eyre!(
+                "Invalid number of public inputs: expected {}, got {}",
+                PUBLIC_INPUTS_LENGTH,
+                public_inputs.len()
+            ));
+        };
+        Self::new(
+            *root,
+            bytes32_to_u64(country_code, "country_code")?,
+            bytes32_to_u64(enter_date, "enter_date")?,
+            bytes32_to_u64(exit_date, "exit_date")?,
+            *nullifier_hash,
+        )
+    }
+
+    /**
+     * @dev - Encode into the bytes32[5] publicInputs (= the input of recordTravelHistoryProof())
+     */
+    pub fn to_bytes32s(&self) -> Vec<FixedBytes<32>> {
+        vec![
+            self.root,
+            u64_to_bytes32(self.country_code),
+            u64_to_bytes32(self.enter_date),
+            u64_to_bytes32(self.exit_date),
+            self.nullifier_hash,
+        ]
+    }
+
+    /**
+     * @dev - Whether or not all the publicInputs are zero (= getPublicInputsOfTravelHistoryProof() of a proof that is not recorded)
+     */
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /**
+     * @notice - The stay of this proof (= country_code, enter_date, exit_date)
+     */
+    pub fn stay(&self) -> eyre::Result<Stay> {
+        Stay::new(self.country_code, self.enter_date, self.exit_date)
+    }
+}
+
+impl TryFrom<&[FixedBytes<32>]> for TravelPublicInputs {
+    type Error = eyre::Report;
+
+    fn try_from(public_inputs: &[FixedBytes<32>]) -> Result<Self, Self::Error> {
+        Self::from_bytes32s(public_inputs)
+    }
+}
+
+impl TryFrom<DataType::PublicInput> for TravelPublicInputs {
+    type Error = eyre::Report;
+
+    fn try_from(public_input: DataType::PublicInput) -> Result<Self, Self::Error> {
+        Self::from_bytes32s(&[
+            public_input.root,
+            public_input.country_code,
+            public_input.enter_date,
+            public_input.exit_date,
+            public_input.nullifierHash,
+        ])
+    }
+}
+
+impl From<TravelPublicInputs> for DataType::PublicInput {
+    fn from(public_inputs: TravelPublicInputs) -> Self {
+        Self {
+            root: public_inputs.root,
+            country_code: u64_to_bytes32(public_inputs.country_code),
+            enter_date: u64_to_bytes32(public_inputs.enter_date),
+            exit_date: u64_to_bytes32(public_inputs.exit_date),
+            nullifierHash: public_inputs.nullifier_hash,
+        }
+    }
+}
+
+impl From<TravelPublicInputs> for Vec<FixedBytes<32>> {
+    fn from(public_inputs: TravelPublicInputs) -> Self {
+        public_inputs.to_bytes32s()
+    }
+}
+
+impl fmt::Display for TravelPublicInputs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "country_code: {}, enter_date: {} ({}), exit_date: {} ({}), root: {}, nullifierHash: {}",
+            country_codes::lookup(self.country_code),
+            self.enter_date,
+            format_day(self.enter_date / SECONDS_PER_DAY),
+            self.exit_date,
+            format_day(self.exit_date / SECONDS_PER_DAY),
+            self.root,
+            self.nullifier_hash
+        )
+    }
+}
+
+/**
+ * @dev - The deserialized TravelPublicInputs before the range checks of the field elements
+ */
+#[derive(Deserialize)]
+struct UncheckedTravelPublicInputs {
+    root: FixedBytes<32>,
+    country_code: u64,
+    enter_date: u64,
+    exit_date: u64,
+    nullifier_hash: FixedBytes<32>,
+}
+
+impl TryFrom<UncheckedTravelPublicInputs> for TravelPublicInputs {
+    type Error = eyre::Report;
+
+    fn try_from(unchecked: UncheckedTravelPublicInputs) -> Result<Self, Self::Error> {
+        Self::new(unchecked.root, unchecked.country_code, unchecked.enter_date, unchecked.exit_date, unchecked.nullifier_hash)
+    }
+}
+
+fn bytes32_to_u64(value: &FixedBytes<32>, name: &str) -> eyre::Result<u64> {
+    u64::try_from(U256::from_be_bytes(value.0)).map_err(|_| eyre::eyre!("{} does not fit into u64: {}", name, value))
+}
+
+fn u64_to_bytes32(value: u64) -> FixedBytes<32> {
+    FixedBytes::from(U256::from(value).to_be_bytes::<32>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::BN254_MODULUS;
+
+    fn public_inputs() -> TravelPublicInputs {
+        TravelPublicInputs::new(FixedBytes::repeat_byte(0x01), 33, 1_614_587_400, 1_615_827_600, FixedBytes::repeat_byte(0x02)).unwrap()
+    }
+
+    #[test]
+    fn round_trips_the_bytes32_array() {
+        let bytes32s = public_inputs().to_bytes32s();
+        assert_eq!(bytes32s.len(), PUBLIC_INPUTS_LENGTH);
+        assert_eq!(bytes32s[1], FixedBytes::<32>::left_padding_from(&[33]));
+        assert_eq!(TravelPublicInputs::from_bytes32s(&bytes32s).unwrap(), public_inputs());
+    }
+
+    #[test]
+    fn round_trips_the_data_type_public_input() {
+        let public_input: DataType::PublicInput = public_inputs().into();
+        assert_eq!(public_input.nullifierHash, FixedBytes::repeat_byte(0x02));
+        assert_eq!(TravelPublicInputs::try_from(public_input).unwrap(), public_inputs());
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let json = serde_json::to_string(&public_inputs()).unwrap();
+        assert_eq!(serde_json::from_str::<TravelPublicInputs>(&json).unwrap(), public_inputs());
+    }
+
+    #[test]
+    fn rejects_a_u64_overflow() {
+        let mut bytes32s = public_inputs().to_bytes32s();
+        bytes32s[2] = FixedBytes::from(U256::from(u64::MAX).saturating_add(U256::from(1)).to_be_bytes::<32>());
+        let error = TravelPublicInputs::from_bytes32s(&bytes32s).unwrap_err().to_string();
+        assert!(error.contains("enter_date"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_a_field_overflow() {
+        let modulus = FixedBytes::from(BN254_MODULUS.to_be_bytes::<32>());
+        let mut bytes32s = public_inputs().to_bytes32s();
+        bytes32s[4] = modulus;
+        assert!(TravelPublicInputs::from_bytes32s(&bytes32s).is_err());
+
+        let json = serde_json::to_string(&public_inputs()).unwrap().replace(&public_inputs().root.to_string(), &modulus.to_string());
+        assert!(serde_json::from_str::<TravelPublicInputs>(&json).is_err());
+    }
+
+    #[test]
+    fn rejects_an_invalid_length() {
+        assert!(TravelPublicInputs::from_bytes32s(&public_inputs().to_bytes32s()[..4]).is_err());
+    }
+}
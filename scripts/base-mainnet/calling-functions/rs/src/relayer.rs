@@ -0,0 +1,701 @@
+// @dev - Alloy
+use alloy::{
+    eips::BlockId,
+    network::{AnyNetwork, ReceiptResponse},
+    primitives::{keccak256, Address, Bytes, FixedBytes, Signature, TxHash, B256, U256},
+    providers::{DynProvider, Provider, ProviderBuilder},
+    signers::{local::PrivateKeySigner, SignerSync},
+    transports::http::reqwest::Url,
+};
+use alloy_node_bindings::{Anvil, AnvilInstance};
+
+// @dev - HTTP
+use axum::{
+    extract::{Path as UrlPath, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+
+use crate::artifact::ContractArtifact;
+use crate::contracts::TravelHistoryManager;
+use crate::local_verifier::{LocalVerifier, VerificationReport};
+use crate::proof_store::{ProofStore, ProofSubmission};
+use crate::public_inputs::TravelPublicInputs;
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::mpsc;
+
+/**
+ * @notice - The body of `POST /relay`: a proof bundle of a traveler, which is submitted by a relayer wallet on behalf of the traveler
+ * @dev - `traveler` is the wallet address of the traveler, which is kept in the status of the request (and in the local proof store).
+ *        `signature` is the EIP-191 signature (= personal_sign) of the traveler over keccak256(proof) (= the request id), which authenticates `traveler`.
+ */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelayRequest {
+    pub traveler: Address,
+    pub proof: Bytes,
+    pub public_inputs: Vec<FixedBytes<32>>,
+    pub signature: Bytes,
+}
+
+impl RelayRequest {
+    /**
+     * @notice - A relay request signed by the traveler (i.e. on the client side)
+     */
+    pub fn signed(traveler: &PrivateKeySigner, proof: Bytes, public_inputs: Vec<FixedBytes<32>>) -> eyre::Result<Self> {
+        let signature = traveler.sign_message_sync(keccak256(&proof).as_slice())?;
+        Ok(Self { traveler: traveler.address(), proof, public_inputs, signature: Bytes::from(signature.as_bytes()) })
+    }
+
+    /**
+     * @dev - The request id (= keccak256(proof)), which the traveler signs
+     */
+    pub fn id(&self) -> B256 {
+        keccak256(&self.proof)
+    }
+
+    /**
+     * @notice - Check that `signature` is signed by `traveler`, so that nobody can attribute a proof to another traveler
+     */
+    pub fn authenticate(&self) -> eyre::Result<()> {
+        let signature = Signature::from_raw(&self.signature).map_err(|e| eyre::eyre!("Invalid signature: {}", e))?;
+        let signer = signature.recover_address_from_msg(self.id()).map_err(|e| eyre::eyre!("Invalid signature: {}", e))?;
+        if signer != self.traveler {
+            return Err(eyre::eyre!("The signature is signed by {:?}, not by the traveler {:?}", signer, self.traveler));
+        }
+        Ok(())
+    }
+}
+
+/**
+ * @notice - The status of a relay request (= `GET /relay/{id}`)
+ * @dev - `reverted` and `failed` are final, so the same proof can be posted again to retry it.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum RelayStatus {
+    Queued,
+    Submitting { relayer: Address },
+    Recorded { relayer: Address, tx_hash: TxHash, block_number: Option<u64> },
+    Reverted { relayer: Address, tx_hash: Option<TxHash>, reason: String },
+    Failed { relayer: Address, error: String },
+}
+
+impl RelayStatus {
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, RelayStatus::Reverted { .. } | RelayStatus::Failed { .. })
+    }
+}
+
+/**
+ * @notice - A relay request, whose id is keccak256(proof) (= the key of the local proof store)
+ * @dev - `recorded_under` is the relayer wallet which the proof is recorded under on-chain (= `msg.sender`), once it is recorded.
+ *        `warnings` are the issues which do not fail the request (i.e. the proof could not be saved into the local proof store).
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct RelayJob {
+    pub id: B256,
+    pub traveler: Address,
+    pub public_inputs: TravelPublicInputs,
+    pub simulation: Option<VerificationReport>,
+    #[serde(flatten)]
+    pub status: RelayStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recorded_under: Option<RecordedUnder>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    pub requested_at: u64, // @dev - UNIX timestamp
+    pub updated_at: u64,   // @dev - UNIX timestamp
+    #[serde(skip)]
+    pub proof: Bytes,
+}
+
+/**
+ * @notice - The on-chain owner of a relayed proof: TravelHistoryManager records it under the relayer wallet (= `msg.sender`), not under the traveler
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RecordedUnder {
+    pub wallet: Address,
+    pub notice: String,
+}
+
+impl RecordedUnder {
+    pub fn relayer(wallet: Address) -> Self {
+        let notice = format!(
+            "The proof is recorded on-chain under the relayer wallet {:?}, not under the traveler: query isTravelHistoryProofRecorded() and getPublicInputsOfTravelHistoryProof() w/ the relayer wallet. nullifiersByWalletAddresses({:?}) only keeps the nullifier of the latest proof relayed by the wallet.",
+            wallet, wallet
+        );
+        Self { wallet, notice }
+    }
+}
+
+/**
+ * @notice - The error of a relay request, which is returned as `{ "error": "..." }` with an HTTP status code
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelayError {
+    BadRequest(String),
+    Unauthorized(String),
+    Conflict(String),
+    InvalidProof(String),
+    NotFound(String),
+    Internal(String),
+}
+
+impl IntoResponse for RelayError {
+    fn into_response(self) -> Response {
+        let (status, error) = match self {
+            RelayError::BadRequest(error) => (StatusCode::BAD_REQUEST, error),
+            RelayError::Unauthorized(error) => (StatusCode::UNAUTHORIZED, error),
+            RelayError::Conflict(error) => (StatusCode::CONFLICT, error),
+            RelayError::InvalidProof(error) => (StatusCode::UNPROCESSABLE_ENTITY, error),
+            RelayError::NotFound(error) => (StatusCode::NOT_FOUND, error),
+            RelayError::Internal(error) => (StatusCode::INTERNAL_SERVER_ERROR, error),
+        };
+        (status, Json(serde_json::json!({ "error": error }))).into_response()
+    }
+}
+
+/**
+ * @notice - The config of a relayer service
+ * @dev - Each wallet of the pool must be funded with the native token of the chain (i.e. ETH on Base, CELO on Celo).
+ */
+#[derive(Debug, Clone)]
+pub struct RelayerConfig {
+    pub chain: String,
+    pub rpc_url: Url,
+    pub travel_history_manager: Address,
+    pub wallets: Vec<PrivateKeySigner>,
+    pub proof_store: Option<PathBuf>,
+}
+
+/**
+ * @notice - The simulation of a proof via a TravelHistoryProofVerifier (+ HonkVerifier) deployed on a local Anvil, before it is queued
+ */
+pub struct Simulator {
+    _anvil: AnvilInstance,
+    provider: DynProvider,
+    verifier: LocalVerifier,
+    lock: tokio::sync::Mutex<()>,
+}
+
+impl Simulator {
+    pub async fn spawn(honk_verifier_artifact: ContractArtifact, verifier_artifact: &ContractArtifact) -> eyre::Result<Self> {
+        // @dev - The HonkVerifier exceeds the contract size limit (EIP-170), so the limit is disabled.
+        let anvil = Anvil::new()
+            .arg("--disable-code-size-limit")
+            .try_spawn()
+            .map_err(|e| eyre::eyre!("Failed to spawn Anvil (install it via `foundryup`): {}", e))?;
+        let signer: PrivateKeySigner = anvil.keys()[0].clone().into();
+        let provider = ProviderBuilder::new().with_gas_estimation().wallet(signer).connect_http(anvil.endpoint_url()).erased();
+        let verifier = LocalVerifier::deploy(&provider, honk_verifier_artifact, verifier_artifact).await?;
+        Ok(Self { _anvil: anvil, provider, verifier, lock: tokio::sync::Mutex::new(()) })
+    }
+
+    pub async fn verify(&self, proof: Bytes, public_inputs: Vec<FixedBytes<32>>) -> eyre::Result<VerificationReport> {
+        // @dev - A valid proof is sent as a transaction (= to measure the gas), so the simulations are serialized to keep the nonces of the Anvil signer in order.
+        let _guard = self.lock.lock().await;
+        self.verifier.verify(&self.provider, proof, public_inputs).await
+    }
+}
+
+/**
+ * @dev - A wallet of the relayer pool, which submits the queued proofs one by one (= its nonces are used in order)
+ */
+struct RelayerWallet {
+    address: Address,
+    provider: DynProvider<AnyNetwork>,
+}
+
+/**
+ * @notice - The relayer service, which validates the proofs posted by travelers, queues them,
+ *           and submits recordTravelHistoryProof() from a pool of funded relayer wallets.
+ * @dev - TravelHistoryManager records a proof under `msg.sender`, so a relayed proof is recorded under the relayer wallet (not the traveler),
+ *        and each relay overwrites nullifiersByWalletAddresses() of the relayer wallet.
+ *        Each relayer wallet is registered as a traveler on start-up, which recordTravelHistoryProof() requires.
+ * @dev - `warnings` are the issues found on start-up (i.e. an unfunded relayer wallet), which are left to the caller to report.
+ */
+pub struct Relayer {
+    pub chain: String,
+    pub travel_history_manager: Address,
+    pub relayers: Vec<Address>,
+    pub warnings: Vec<String>,
+    provider: DynProvider<AnyNetwork>,
+    artifact: ContractArtifact,
+    simulator: Option<Simulator>,
+    proof_store: Option<ProofStore>,
+    jobs: Mutex<HashMap<B256, RelayJob>>,
+    queue: mpsc::UnboundedSender<B256>,
+}
+
+impl Relayer {
+    /**
+     * @notice - Start the relayer: register the wallets of the pool as travelers (if needed), and spawn a worker per wallet
+     */
+    pub async fn start(config: RelayerConfig, artifact: ContractArtifact, simulator: Option<Simulator>) -> eyre::Result<Arc<Self>> {
+        if config.wallets.is_empty() {
+            return Err(eyre::eyre!("The relayer wallet pool is empty"));
+        }
+        let provider = ProviderBuilder::new().network::<AnyNetwork>().connect_http(config.rpc_url.clone()).erased();
+
+        let mut wallets = Vec::new();
+        let mut warnings = Vec::new();
+        for signer in config.wallets.into_iter() {
+            let address = signer.address();
+            let wallet_provider = ProviderBuilder::new()
+                .with_gas_estimation()
+                .network::<AnyNetwork>()
+                .wallet(signer)
+                .connect_http(config.rpc_url.clone())
+                .erased();
+            let travel_history_manager = TravelHistoryManager::new(config.travel_history_manager, &wallet_provider);
+            if !travel_history_manager.travelers(address).call().await? {
+                let receipt = travel_history_manager.registerAsTraveler().send().await?.get_receipt().await?;
+                if !receipt.status() {
+                    return Err(eyre::eyre!("Failed to register the relayer wallet {:?} as a traveler", address));
+                }
+            }
+            if wallet_provider.get_balance(address).await? == U256::ZERO {
+                warnings.push(format!("The relayer wallet {:?} is not funded", address));
+            }
+            wallets.push(RelayerWallet { address, provider: wallet_provider });
+        }
+
+        let (queue, receiver) = mpsc::unbounded_channel();
+        let receiver = Arc::new(tokio::sync::Mutex::new(receiver));
+        let relayer = Arc::new(Self {
+            chain: config.chain,
+            travel_history_manager: config.travel_history_manager,
+            relayers: wallets.iter().map(|wallet| wallet.address).collect(),
+            warnings,
+            provider,
+            artifact,
+            simulator,
+            proof_store: config.proof_store.map(ProofStore::open).transpose()?,
+            jobs: Mutex::new(HashMap::new()),
+            queue,
+        });
+        for wallet in wallets.into_iter() {
+            tokio::spawn(work(relayer.clone(), wallet, receiver.clone()));
+        }
+        Ok(relayer)
+    }
+
+    /**
+     * @notice - Validate a proof and queue it: the signature of the traveler, the shape of the publicInputs, the nullifier is not spent (on-chain and by another request),
+     *           and the local verifier simulation (if any). Posting the same proof again returns the existing request, unless it has reverted or failed.
+     */
+    pub async fn submit(&self, request: RelayRequest) -> Result<RelayJob, RelayError> {
+        if request.proof.is_empty() {
+            return Err(RelayError::BadRequest("The proof is empty".to_string()));
+        }
+        request.authenticate().map_err(|e| RelayError::Unauthorized(e.to_string()))?;
+        let public_inputs = TravelPublicInputs::from_bytes32s(&request.public_inputs).map_err(|e| RelayError::BadRequest(e.to_string()))?;
+        let id = request.id();
+        let nullifier = public_inputs.nullifier_hash;
+        if let Some(job) = pending_job(&self.jobs.lock().expect("the relay jobs are not poisoned"), id, nullifier)? {
+            return Ok(job);
+        }
+
+        let travel_history_manager = TravelHistoryManager::new(self.travel_history_manager, &self.provider);
+        let is_spent = travel_history_manager.nullifiers(nullifier).call().await.map_err(|e| RelayError::Internal(e.to_string()))?;
+        if is_spent {
+            return Err(RelayError::Conflict(format!("The nullifier {} has already been spent on-chain", nullifier)));
+        }
+
+        let simulation = match self.simulator.as_ref() {
+            Some(simulator) => {
+                let report = simulator
+                    .verify(request.proof.clone(), request.public_inputs.clone())
+                    .await
+                    .map_err(|e| RelayError::Internal(format!("Failed to simulate the proof: {}", e)))?;
+                if !report.is_valid {
+                    return Err(RelayError::InvalidProof(format!("The proof is {}", report)));
+                }
+                Some(report)
+            }
+            None => None,
+        };
+
+        let now = now();
+        let job = RelayJob {
+            id,
+            traveler: request.traveler,
+            public_inputs,
+            simulation,
+            status: RelayStatus::Queued,
+            recorded_under: None,
+            warnings: Vec::new(),
+            requested_at: now,
+            updated_at: now,
+            proof: request.proof,
+        };
+        {
+            // @dev - Re-check under the lock, since the same proof or another proof w/ the same nullifier may have been posted concurrently
+            let mut jobs = self.jobs.lock().expect("the relay jobs are not poisoned");
+            if let Some(existing) = pending_job(&jobs, id, nullifier)? {
+                return Ok(existing);
+            }
+            jobs.insert(id, job.clone());
+        }
+        self.queue.send(id).map_err(|e| RelayError::Internal(e.to_string()))?;
+        Ok(job)
+    }
+
+    pub fn status(&self, id: B256) -> Option<RelayJob> {
+        self.jobs.lock().expect("the relay jobs are not poisoned").get(&id).cloned()
+    }
+
+    pub fn jobs(&self) -> Vec<RelayJob> {
+        self.jobs.lock().expect("the relay jobs are not poisoned").values().cloned().collect()
+    }
+
+    fn update(&self, id: B256, status: RelayStatus) -> Option<RelayJob> {
+        let mut jobs = self.jobs.lock().expect("the relay jobs are not poisoned");
+        let job = jobs.get_mut(&id)?;
+        job.recorded_under = match &status {
+            RelayStatus::Recorded { relayer, .. } => Some(RecordedUnder::relayer(*relayer)),
+            _ => None,
+        };
+        job.status = status;
+        job.updated_at = now();
+        Some(job.clone())
+    }
+
+    fn warn(&self, id: B256, warning: String) {
+        if let Some(job) = self.jobs.lock().expect("the relay jobs are not poisoned").get_mut(&id) {
+            job.warnings.push(warning);
+        }
+    }
+
+    /**
+     * @dev - Submit a queued proof via recordTravelHistoryProof() from a relayer wallet
+     */
+    async fn record(&self, wallet: &RelayerWallet, job: &RelayJob) -> RelayStatus {
+        let relayer = wallet.address;
+        let travel_history_manager = TravelHistoryManager::new(self.travel_history_manager, &wallet.provider);
+        let call = travel_history_manager.recordTravelHistoryProof(job.proof.clone(), job.public_inputs.to_bytes32s());
+        let pending_transaction = match call.send().await {
+            Ok(pending_transaction) => pending_transaction,
+            Err(e) => {
+                return match e.as_revert_data() {
+                    Some(data) => RelayStatus::Reverted { relayer, tx_hash: None, reason: self.artifact.decode_revert(&data) },
+                    None => RelayStatus::Failed { relayer, error: e.to_string() },
+                }
+            }
+        };
+        let tx_hash = *pending_transaction.tx_hash();
+        match pending_transaction.get_receipt().await {
+            Ok(receipt) if receipt.status() => RelayStatus::Recorded { relayer, tx_hash, block_number: receipt.block_number() },
+            Ok(receipt) => RelayStatus::Reverted { relayer, tx_hash: Some(tx_hash), reason: self.revert_reason(wallet, job, receipt.block_number()).await },
+            Err(e) => RelayStatus::Failed { relayer, error: format!("{} (tx: {})", e, tx_hash) },
+        }
+    }
+
+    /**
+     * @dev - The reason of a reverted transaction, which is decoded by replaying the call (= eth_call) on the state before its block
+     */
+    async fn revert_reason(&self, wallet: &RelayerWallet, job: &RelayJob, block_number: Option<u64>) -> String {
+        let travel_history_manager = TravelHistoryManager::new(self.travel_history_manager, &wallet.provider);
+        let mut replay = travel_history_manager.recordTravelHistoryProof(job.proof.clone(), job.public_inputs.to_bytes32s()).from(wallet.address);
+        if let Some(block_number) = block_number {
+            replay = replay.block(BlockId::number(block_number.saturating_sub(1)));
+        }
+        match replay.call().await {
+            Err(e) => match e.as_revert_data() {
+                Some(data) => self.artifact.decode_revert(&data),
+                None => "reverted on-chain".to_string(),
+            },
+            Ok(_) => "reverted on-chain (the replay on the state before its block succeeded)".to_string(),
+        }
+    }
+
+    /**
+     * @dev - Save a recorded proof into the local proof store (if any). The submission is keyed by the traveler of the request,
+     *        w/ the relayer wallet (= `msg.sender` on-chain) which the on-chain views of the proof are keyed by.
+     */
+    fn save(&self, job: &RelayJob) -> eyre::Result<()> {
+        let (Some(proof_store), RelayStatus::Recorded { relayer, tx_hash, .. }) = (self.proof_store.as_ref(), &job.status) else {
+            return Ok(());
+        };
+        let proof_hash = proof_store.put(&job.proof, &job.public_inputs.to_bytes32s())?;
+        proof_store.add_submission(
+            proof_hash,
+            ProofSubmission {
+                chain: self.chain.clone(),
+                contract: self.travel_history_manager,
+                traveler: job.traveler,
+                relayer: Some(*relayer),
+                tx_hash: Some(*tx_hash),
+                recorded_at: job.updated_at,
+            },
+        )
+    }
+}
+
+/**
+ * @dev - The worker of a relayer wallet, which takes the queued requests one by one
+ */
+async fn work(relayer: Arc<Relayer>, wallet: RelayerWallet, receiver: Arc<tokio::sync::Mutex<mpsc::UnboundedReceiver<B256>>>) {
+    loop {
+        let Some(id) = receiver.lock().await.recv().await else {
+            return;
+        };
+        let Some(job) = relayer.update(id, RelayStatus::Submitting { relayer: wallet.address }) else {
+            continue;
+        };
+        let status = relayer.record(&wallet, &job).await;
+        if let Some(job) = relayer.update(id, status) {
+            if let Err(e) = relayer.save(&job) {
+                relayer.warn(id, format!("Failed to save the proof into the local proof store: {}", e));
+            }
+        }
+    }
+}
+
+/**
+ * @dev - The existing request of the same proof (if it is not retryable), or an error if another pending request has the same nullifier
+ */
+fn pending_job(jobs: &HashMap<B256, RelayJob>, id: B256, nullifier: FixedBytes<32>) -> Result<Option<RelayJob>, RelayError> {
+    if let Some(job) = jobs.get(&id).filter(|job| !job.status.is_retryable()) {
+        return Ok(Some(job.clone()));
+    }
+    if let Some(job) = jobs.values().find(|job| job.id != id && job.public_inputs.nullifier_hash == nullifier && !job.status.is_retryable()) {
+        return Err(RelayError::Conflict(format!("The nullifier {} is already used by the request {}", nullifier, job.id)));
+    }
+    Ok(None)
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or_default()
+}
+
+/**
+ * @notice - The HTTP API of the relayer service:
+ *           - `POST /relay` (= RelayRequest, signed by the traveler) -> 202 + the relay request (incl. its `id`), or 401 if the signature is not the traveler's
+ *             (⚠️ the proof is recorded on-chain under the relayer wallet, not under the traveler)
+ *           - `GET /relay/{id}` -> the status of the relay request (incl. `recorded_under`, the relayer wallet which owns the on-chain record, once it is recorded)
+ *           - `GET /health` -> the chain, the contract and the relayer wallets
+ */
+pub fn router(relayer: Arc<Relayer>) -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/relay", post(relay))
+        .route("/relay/{id}", get(relay_status))
+        .with_state(relayer)
+}
+
+async fn health(State(relayer): State<Arc<Relayer>>) -> Json<serde_json::Value> {
+    let jobs = relayer.jobs();
+    Json(serde_json::json!({
+        "chain": relayer.chain,
+        "travel_history_manager": relayer.travel_history_manager,
+        "relayers": relayer.relayers,
+        "queued": jobs.iter().filter(|job| job.status == RelayStatus::Queued).count(),
+        "requests": jobs.len(),
+    }))
+}
+
+async fn relay(State(relayer): State<Arc<Relayer>>, Json(request): Json<RelayRequest>) -> Result<(StatusCode, Json<RelayJob>), RelayError> {
+    let job = relayer.submit(request).await?;
+    Ok((StatusCode::ACCEPTED, Json(job)))
+}
+
+async fn relay_status(State(relayer): State<Arc<Relayer>>, UrlPath(id): UrlPath<String>) -> Result<Json<RelayJob>, RelayError> {
+    let id: B256 = id.parse().map_err(|_| RelayError::BadRequest(format!("Invalid request id: {}", id)))?;
+    relayer.status(id).map(Json).ok_or_else(|| RelayError::NotFound(format!("Unknown request id: {}", id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::TRAVEL_HISTORY_MANAGER_ARTIFACT_PATH;
+    use crate::local_anvil::MockVerifier;
+    use crate::test_vectors;
+    use std::time::Duration;
+
+    #[test]
+    fn serializes_the_status_of_a_relay_request() {
+        let job = RelayJob {
+            id: B256::repeat_byte(1),
+            traveler: Address::repeat_byte(2),
+            public_inputs: TravelPublicInputs::from_inputs(&test_vectors::generate(1, 0).unwrap()[0].inputs),
+            simulation: None,
+            status: RelayStatus::Reverted { relayer: Address::repeat_byte(3), tx_hash: None, reason: "Travel History Proof is not valid".to_string() },
+            recorded_under: None,
+            warnings: Vec::new(),
+            requested_at: 1,
+            updated_at: 2,
+            proof: Bytes::from(vec![1u8; 32]),
+        };
+        let json = serde_json::to_value(&job).unwrap();
+        assert_eq!(json["status"], "reverted");
+        assert_eq!(json["reason"], "Travel History Proof is not valid");
+        assert_eq!(json["public_inputs"]["country_code"], 1999);
+        assert!(json.get("proof").is_none());
+        assert!(json.get("recorded_under").is_none());
+        assert!(job.status.is_retryable());
+
+        // @dev - A recorded proof tells the relayer wallet which owns its on-chain record
+        let recorded = RelayJob {
+            status: RelayStatus::Recorded { relayer: Address::repeat_byte(3), tx_hash: TxHash::repeat_byte(4), block_number: Some(5) },
+            recorded_under: Some(RecordedUnder::relayer(Address::repeat_byte(3))),
+            warnings: vec!["Failed to save the proof into the local proof store: disk full".to_string()],
+            ..job
+        };
+        let json = serde_json::to_value(&recorded).unwrap();
+        assert_eq!(json["recorded_under"]["wallet"], json["relayer"]);
+        assert!(json["recorded_under"]["notice"].as_str().unwrap().contains("not under the traveler"));
+        assert_eq!(json["warnings"][0], "Failed to save the proof into the local proof store: disk full");
+    }
+
+    #[test]
+    fn authenticates_the_traveler_of_a_relay_request() {
+        let traveler = PrivateKeySigner::random();
+        let public_inputs = TravelPublicInputs::from_inputs(&test_vectors::generate(1, 0).unwrap()[0].inputs).to_bytes32s();
+        let request = RelayRequest::signed(&traveler, Bytes::from(vec![7u8; 64]), public_inputs).unwrap();
+        assert_eq!(request.traveler, traveler.address());
+        request.authenticate().unwrap();
+
+        // @dev - The proof cannot be attributed to another traveler, nor be swapped under the signature
+        let mut impersonated = request.clone();
+        impersonated.traveler = Address::repeat_byte(0x22);
+        assert!(impersonated.authenticate().unwrap_err().to_string().contains("not by the traveler"));
+        let mut swapped = request.clone();
+        swapped.proof = Bytes::from(vec![8u8; 64]);
+        assert!(swapped.authenticate().is_err());
+        let mut unsigned = request;
+        unsigned.signature = Bytes::new();
+        assert!(unsigned.authenticate().unwrap_err().to_string().contains("Invalid signature"));
+    }
+
+    #[test]
+    fn finds_a_pending_request_of_the_same_proof_or_nullifier() {
+        let public_inputs = TravelPublicInputs::from_inputs(&test_vectors::generate(1, 0).unwrap()[0].inputs);
+        let nullifier = public_inputs.nullifier_hash;
+        let job = |id: u8, status: RelayStatus| RelayJob {
+            id: B256::repeat_byte(id),
+            traveler: Address::repeat_byte(2),
+            public_inputs,
+            simulation: None,
+            status,
+            recorded_under: None,
+            warnings: Vec::new(),
+            requested_at: 1,
+            updated_at: 1,
+            proof: Bytes::from(vec![id; 32]),
+        };
+        let failed = RelayStatus::Failed { relayer: Address::repeat_byte(3), error: "nonce too low".to_string() };
+        let mut jobs = HashMap::new();
+        assert!(matches!(pending_job(&jobs, B256::repeat_byte(1), nullifier), Ok(None)));
+
+        jobs.insert(B256::repeat_byte(1), job(1, RelayStatus::Queued));
+        assert_eq!(pending_job(&jobs, B256::repeat_byte(1), nullifier).unwrap().unwrap().id, B256::repeat_byte(1));
+        // @dev - Another proof w/ the same nullifier is a conflict while the first one is pending
+        assert!(matches!(pending_job(&jobs, B256::repeat_byte(2), nullifier), Err(RelayError::Conflict(_))));
+        assert!(matches!(pending_job(&jobs, B256::repeat_byte(2), FixedBytes::repeat_byte(9)), Ok(None)));
+
+        // @dev - ... but not once it has failed (= it can be retried)
+        jobs.insert(B256::repeat_byte(1), job(1, failed));
+        assert!(matches!(pending_job(&jobs, B256::repeat_byte(1), nullifier), Ok(None)));
+        assert!(matches!(pending_job(&jobs, B256::repeat_byte(2), nullifier), Ok(None)));
+    }
+
+    /**
+     * @dev - End-to-end: a TravelHistoryManager (w/ an always-true verifier) on Anvil, the relayer with 2 wallets, and `POST /relay` + `GET /relay/{id}` over HTTP
+     */
+    #[tokio::test]
+    #[ignore = "requires Anvil (install it via `foundryup`)"]
+    async fn relays_a_proof_end_to_end_on_anvil() {
+        let anvil = Anvil::new().try_spawn().unwrap();
+        let deployer: PrivateKeySigner = anvil.keys()[0].clone().into();
+        let provider = ProviderBuilder::new().wallet(deployer).connect_http(anvil.endpoint_url());
+
+        let verifier = Address::repeat_byte(0x11);
+        provider.raw_request::<_, ()>("anvil_setCode".into(), (verifier, MockVerifier::AlwaysTrue.runtime_code())).await.unwrap();
+        let travel_history_manager = TravelHistoryManager::deploy(&provider, verifier).await.unwrap();
+
+        let proof_store_dir = std::env::temp_dir().join(format!("travel_history_relayer_proofs_{}", std::process::id()));
+        let config = RelayerConfig {
+            chain: "local-anvil".to_string(),
+            rpc_url: anvil.endpoint_url(),
+            travel_history_manager: *travel_history_manager.address(),
+            wallets: vec![anvil.keys()[1].clone().into(), anvil.keys()[2].clone().into()],
+            proof_store: Some(proof_store_dir.clone()),
+        };
+        let artifact = ContractArtifact::load(TRAVEL_HISTORY_MANAGER_ARTIFACT_PATH).unwrap();
+        let relayer = Relayer::start(config, artifact, None).await.unwrap();
+        assert!(relayer.warnings.is_empty());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, router(relayer)).await });
+
+        let inputs = &test_vectors::generate(1, 0).unwrap()[0].inputs;
+        let traveler: PrivateKeySigner = anvil.keys()[3].clone().into();
+        let request = RelayRequest::signed(&traveler, Bytes::from(vec![7u8; 64]), TravelPublicInputs::from_inputs(inputs).to_bytes32s()).unwrap();
+        let client = reqwest::Client::new();
+
+        // @dev - A request whose traveler did not sign it is rejected
+        let mut impersonated = request.clone();
+        impersonated.traveler = Address::repeat_byte(0x22);
+        let response = client
+            .post(format!("{}/relay", endpoint))
+            .header("content-type", "application/json")
+            .body(serde_json::to_string(&impersonated).unwrap())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status().as_u16(), 401);
+
+        let response = client
+            .post(format!("{}/relay", endpoint))
+            .header("content-type", "application/json")
+            .body(serde_json::to_string(&request).unwrap())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status().as_u16(), 202);
+        let job: serde_json::Value = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+        let id = job["id"].as_str().unwrap().to_string();
+
+        let mut status = serde_json::Value::Null;
+        for _ in 0..50 {
+            let response = client.get(format!("{}/relay/{}", endpoint, id)).send().await.unwrap();
+            status = serde_json::from_str(&response.text().await.unwrap()).unwrap();
+            if status["status"] != "queued" && status["status"] != "submitting" {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        assert_eq!(status["status"], "recorded", "{}", status);
+
+        let relayer_wallet: Address = status["relayer"].as_str().unwrap().parse().unwrap();
+        assert_eq!(status["recorded_under"]["wallet"], status["relayer"]);
+        assert!(status.get("warnings").is_none(), "{}", status);
+        assert!(travel_history_manager.isTravelHistoryProofRecorded(relayer_wallet, request.proof.clone()).call().await.unwrap());
+
+        // @dev - The proof is stored under the traveler, w/ the relayer wallet which it is recorded under on-chain
+        let proof_store = ProofStore::open(&proof_store_dir).unwrap();
+        let stored_proofs = proof_store.proofs_of(request.traveler, Some("local-anvil")).unwrap();
+        let submission = stored_proofs[0].submissions_of(request.traveler).next().unwrap();
+        assert_eq!((submission.relayer, submission.sender()), (Some(relayer_wallet), relayer_wallet));
+        std::fs::remove_dir_all(&proof_store_dir).unwrap();
+
+        // @dev - The nullifier is now spent, so another proof with the same publicInputs is rejected
+        let replayed = RelayRequest::signed(&traveler, Bytes::from(vec![8u8; 64]), request.public_inputs.clone()).unwrap();
+        let response = client
+            .post(format!("{}/relay", endpoint))
+            .header("content-type", "application/json")
+            .body(serde_json::to_string(&replayed).unwrap())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status().as_u16(), 409);
+    }
+}
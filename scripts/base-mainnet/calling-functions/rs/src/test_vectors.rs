@@ -0,0 +1,254 @@
+// @dev - Noir / ACVM
+use acvm::{AcirField, FieldElement};
+
+use crate::country_codes::{CIRCUIT_COUNTRY_CODE_LIMIT, COUNTRIES};
+use crate::hash::note_commitment;
+use crate::inputs::TravelHistoryInputs;
+use crate::merkle::MerkleTree;
+use crate::mrz::{encode_document_number, DOCUMENT_NUMBER_LENGTH};
+
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::path::Path;
+
+// @notice - The generator of the test vectors of the travel_history circuit (= circuits/src/tests/generated.nr + a Prover.toml per test case)
+// @dev - The test cases are reproducible from a seed: the same seed always generates the same test cases (= SplitMix64, which does not depend on the version of a crate).
+
+/// @dev - The UNIX timestamps of 2000-01-01 and 2040-01-01, between which the random dates are generated
+const RANDOM_DATE_RANGE: (u64, u64) = (946_684_800, 2_208_988_800);
+
+/// @dev - The assertion message of the circuit for a country code >= 2000
+pub const INVALID_COUNTRY_CODE: &str = "Invalid country code";
+
+/**
+ * @notice - A test case of the travel_history circuit
+ * @dev - `should_fail_with` is the assertion message if the circuit must reject the inputs (i.e. country_code = 2000). Otherwise, the circuit returns `expected_nullifier`.
+ * @dev - `document_number` is the document number of the MRZ, whose encoding (= encode_document_number()) is the `passport_number` input.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestCase {
+    pub name: String,
+    pub document_number: String,
+    pub inputs: TravelHistoryInputs,
+    pub expected_nullifier: FieldElement,
+    pub should_fail_with: Option<&'static str>,
+}
+
+/**
+ * @dev - SplitMix64 (= a small, well-known PRNG), see: https://prng.di.unimi.it/splitmix64.c
+ */
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /**
+     * @dev - A random value in [low, high)
+     */
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        low + self.next_u64() % (high - low)
+    }
+
+    /**
+     * @dev - A random document number of a TD3 MRZ (= 9 characters of "0"-"9" and "A"-"Z", i.e. "L898902C3"), from the base-36 digits of a single u64
+     */
+    pub fn document_number(&mut self) -> String {
+        const ALPHABET: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        let mut value = self.next_u64();
+        let mut document_number = String::with_capacity(DOCUMENT_NUMBER_LENGTH);
+        for _ in 0..DOCUMENT_NUMBER_LENGTH {
+            document_number.push(ALPHABET[(value % 36) as usize] as char);
+            value /= 36;
+        }
+        document_number
+    }
+
+    /**
+     * @dev - A random field element of 248 bits (= always < the BN254 modulus)
+     */
+    pub fn field(&mut self) -> FieldElement {
+        let mut bytes = [0u8; 31];
+        for chunk in bytes.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next_u64().to_be_bytes()[..chunk.len()]);
+        }
+        FieldElement::from_be_bytes_reduce(&bytes)
+    }
+}
+
+/**
+ * @notice - Generate the edge cases followed by `count` random cases from a seed
+ * @dev - Each case has a valid Merkle tree: the note commitment of the secret is put at a random index among random leaves.
+ */
+pub fn generate(seed: u64, count: usize) -> eyre::Result<Vec<TestCase>> {
+    let mut rng = SplitMix64::new(seed);
+    let mut cases = Vec::new();
+
+    // @dev - The edge cases: the boundary country codes and the edge dates
+    let (enter_date, exit_date) = random_dates(&mut rng);
+    cases.push(test_case(&mut rng, "country_code_1999", CIRCUIT_COUNTRY_CODE_LIMIT - 1, enter_date, exit_date)?);
+    cases.push(test_case(&mut rng, "country_code_2000", CIRCUIT_COUNTRY_CODE_LIMIT, enter_date, exit_date)?);
+    cases.push(test_case(&mut rng, "country_code_0", 0, enter_date, exit_date)?);
+    cases.push(test_case(&mut rng, "enter_date_epoch", 1, 0, exit_date)?);
+    cases.push(test_case(&mut rng, "exit_date_u64_max", 1, enter_date, u64::MAX)?);
+    cases.push(test_case(&mut rng, "same_second_stay", 33, enter_date, enter_date)?);
+    cases.push(test_case(&mut rng, "leap_day_stay", 49, 951_782_400, 951_868_799)?); // @dev - 2000-02-29 00:00:00 .. 23:59:59 UTC
+
+    // @dev - The random cases
+    for i in 0..count {
+        let country = &COUNTRIES[rng.range(0, COUNTRIES.len() as u64) as usize];
+        let (enter_date, exit_date) = random_dates(&mut rng);
+        cases.push(test_case(&mut rng, &format!("random_{:03}_{}", i, country.alpha2.to_ascii_lowercase()), country.country_code, enter_date, exit_date)?);
+    }
+    Ok(cases)
+}
+
+fn random_dates(rng: &mut SplitMix64) -> (u64, u64) {
+    let enter_date = rng.range(RANDOM_DATE_RANGE.0, RANDOM_DATE_RANGE.1);
+    let exit_date = enter_date + rng.range(1, 365 * 86_400);
+    (enter_date, exit_date)
+}
+
+fn test_case(rng: &mut SplitMix64, name: &str, country_code: u64, enter_date: u64, exit_date: u64) -> eyre::Result<TestCase> {
+    let secret = rng.field();
+    let index = rng.range(0, MerkleTree::CAPACITY as u64) as usize;
+    let mut leaves: Vec<FieldElement> = (0..MerkleTree::CAPACITY).map(|_| rng.field()).collect();
+    leaves[index] = note_commitment(secret);
+    let tree = MerkleTree::new(&leaves)?;
+    let document_number = rng.document_number();
+
+    let inputs = TravelHistoryInputs {
+        root: tree.root(),
+        hash_path: tree.hash_path(index)?,
+        index: FieldElement::from(index as u128),
+        secret,
+        passport_number: encode_document_number(&document_number)?,
+        country_code,
+        enter_date,
+        exit_date,
+    };
+    Ok(TestCase {
+        name: name.to_string(),
+        document_number,
+        expected_nullifier: inputs.expected_nullifier(),
+        should_fail_with: (country_code >= CIRCUIT_COUNTRY_CODE_LIMIT).then_some(INVALID_COUNTRY_CODE),
+        inputs,
+    })
+}
+
+/**
+ * @notice - Render the test cases as a Noir test module (= circuits/src/tests/generated.nr)
+ */
+pub fn to_noir_module(cases: &[TestCase], seed: u64) -> String {
+    let mut noir = String::new();
+    let _ = writeln!(noir, "// @dev - This file is generated by the test-vector generator (scripts/base-mainnet/calling-functions/rs: `cargo run --bin generate_test_vectors -- --seed {} --count <N>`).", seed);
+    let _ = writeln!(noir, "//        Do not edit it by hand. The matching Prover.toml files and the expected nullifiers are in circuits/test-vectors.");
+    let _ = writeln!(noir, "use crate::main; // src/main.nr");
+    for case in cases.iter() {
+        let inputs = &case.inputs;
+        let _ = writeln!(noir);
+        match case.should_fail_with {
+            Some(message) => { let _ = writeln!(noir, "#[test(should_fail_with = \"{}\")]", message); }
+            None => { let _ = writeln!(noir, "#[test]"); }
+        }
+        let _ = writeln!(noir, "fn test_{}() {{", case.name);
+        let _ = writeln!(noir, "    let nullifier = main(");
+        let _ = writeln!(noir, "        0x{}, // root", inputs.root.to_hex());
+        let _ = writeln!(noir, "        [0x{}, 0x{}], // hash_path", inputs.hash_path[0].to_hex(), inputs.hash_path[1].to_hex());
+        let _ = writeln!(noir, "        {}, // index", inputs.index.to_short_hex());
+        let _ = writeln!(noir, "        0x{}, // secret", inputs.secret.to_hex());
+        let _ = writeln!(noir, "        {}, // passport_number (= \"{}\")", inputs.passport_number.to_short_hex(), case.document_number);
+        let _ = writeln!(noir, "        {}, // country_code", inputs.country_code);
+        let _ = writeln!(noir, "        {}, // enter_date", inputs.enter_date);
+        let _ = writeln!(noir, "        {}, // exit_date", inputs.exit_date);
+        let _ = writeln!(noir, "    );");
+        let _ = writeln!(noir, "    assert(nullifier == 0x{}, \"Invalid nullifier\");", case.expected_nullifier.to_hex());
+        let _ = writeln!(noir, "}}");
+    }
+    noir
+}
+
+/**
+ * @notice - An entry of the expected nullifiers (= <out_dir>/expected_nullifiers.json)
+ * @dev - `expected_nullifier` is null for a case that the circuit must reject.
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct ExpectedNullifier {
+    pub name: String,
+    pub prover_toml: String,
+    pub expected_nullifier: Option<String>,
+    pub should_fail_with: Option<&'static str>,
+}
+
+/**
+ * @notice - Write a Prover.toml per test case (= <out_dir>/<name>/Prover.toml) and the expected nullifiers (= <out_dir>/expected_nullifiers.json)
+ */
+pub fn write_prover_tomls<P: AsRef<Path>>(cases: &[TestCase], out_dir: P) -> eyre::Result<Vec<ExpectedNullifier>> {
+    let out_dir = out_dir.as_ref();
+    let mut expected_nullifiers = Vec::new();
+    for case in cases.iter() {
+        let case_dir = out_dir.join(&case.name);
+        std::fs::create_dir_all(&case_dir)?;
+        std::fs::write(case_dir.join("Prover.toml"), case.inputs.to_prover_toml())?;
+        expected_nullifiers.push(ExpectedNullifier {
+            name: case.name.clone(),
+            prover_toml: format!("{}/Prover.toml", case.name),
+            expected_nullifier: case.should_fail_with.is_none().then(|| format!("0x{}", case.expected_nullifier.to_hex())),
+            should_fail_with: case.should_fail_with,
+        });
+    }
+    std::fs::write(out_dir.join("expected_nullifiers.json"), serde_json::to_string_pretty(&expected_nullifiers)? + "\n")?;
+    Ok(expected_nullifiers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::compute_merkle_root;
+
+    #[test]
+    fn generates_reproducible_cases_from_a_seed() {
+        assert_eq!(generate(42, 3).unwrap(), generate(42, 3).unwrap());
+        assert_ne!(generate(42, 3).unwrap(), generate(43, 3).unwrap());
+    }
+
+    #[test]
+    fn generates_valid_merkle_trees() {
+        for case in generate(7, 5).unwrap() {
+            let inputs = &case.inputs;
+            let root = compute_merkle_root(note_commitment(inputs.secret), inputs.index, &inputs.hash_path);
+            assert_eq!(root, inputs.root, "{}", case.name);
+            assert_eq!(case.expected_nullifier, inputs.expected_nullifier());
+        }
+    }
+
+    #[test]
+    fn covers_the_boundary_country_codes() {
+        let cases = generate(1, 0).unwrap();
+        let case_1999 = cases.iter().find(|case| case.inputs.country_code == 1999).unwrap();
+        let case_2000 = cases.iter().find(|case| case.inputs.country_code == 2000).unwrap();
+        assert_eq!(case_1999.should_fail_with, None);
+        assert_eq!(case_2000.should_fail_with, Some(INVALID_COUNTRY_CODE));
+    }
+
+    #[test]
+    fn encodes_the_document_numbers_of_the_mrz() {
+        for case in generate(1, 8).unwrap() {
+            assert_eq!(case.document_number.len(), DOCUMENT_NUMBER_LENGTH);
+            assert_eq!(case.inputs.passport_number, encode_document_number(&case.document_number).unwrap());
+            assert_eq!(crate::mrz::decode_document_number(case.inputs.passport_number).unwrap(), case.document_number, "{}", case.name);
+        }
+    }
+}
@@ -0,0 +1,228 @@
+// @dev - Alloy
+use alloy::{
+    network::Network,
+    primitives::{Address, FixedBytes, TxHash, B256},
+    providers::Provider,
+};
+
+use crate::contracts::TravelHistoryManager::TravelHistoryManagerInstance;
+use crate::country_codes;
+use crate::policy::{format_day, Stay, StayPolicy, Verdict, SECONDS_PER_DAY};
+use crate::proof_store::ProofStore;
+use crate::public_inputs::TravelPublicInputs;
+
+use serde::Serialize;
+use std::fmt;
+
+/**
+ * @notice - The full travel status of a traveler on a chain, which is queried by a border authority
+ * @dev - The stays are decoded from the on-chain getPublicInputsOfTravelHistoryProof() of the proofs in the local proof store.
+ * @dev - `unrecorded` lists the stored proofs, which are not recorded on-chain (= getPublicInputsOfTravelHistoryProof() returns zeros).
+ * @dev - `invalid` lists the recorded proofs, whose publicInputs are not a valid stay (i.e. exit_date < enter_date). They are left out of the timeline and the verdict.
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct TravelStatus {
+    pub chain: String,
+    pub contract: Address,
+    pub traveler: Address,
+    pub is_registered: bool,
+    pub current_nullifier: FixedBytes<32>,
+    pub timeline: Vec<TimelineEntry>,
+    pub unrecorded: Vec<B256>,
+    pub invalid: Vec<InvalidProof>,
+    pub breaching: bool,
+    pub verdict: Verdict,
+}
+
+/**
+ * @notice - A stay in the timeline of a traveler (sorted by the entry date)
+ */
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineEntry {
+    pub country_code: u64,
+    pub country: String,
+    pub enter_date: u64, // @dev - UNIX timestamp
+    pub exit_date: u64,  // @dev - UNIX timestamp
+    pub days: u64,
+    pub nullifier: FixedBytes<32>,
+    pub proof_hash: B256,
+    pub tx_hash: Option<TxHash>,
+}
+
+impl TimelineEntry {
+    /**
+     * @dev - Decode the stay of a recorded proof. An error is returned if its publicInputs are not a valid stay (i.e. exit_date < enter_date).
+     */
+    pub fn from_public_inputs(public_inputs: &TravelPublicInputs, proof_hash: B256, tx_hash: Option<TxHash>) -> eyre::Result<Self> {
+        let stay = public_inputs.stay()?;
+        Ok(Self {
+            country_code: stay.country_code,
+            country: country_codes::lookup(stay.country_code).to_string(),
+            enter_date: stay.enter_date,
+            exit_date: stay.exit_date,
+            days: stay.days(),
+            nullifier: public_inputs.nullifier_hash,
+            proof_hash,
+            tx_hash,
+        })
+    }
+
+    pub fn stay(&self) -> Stay {
+        Stay { country_code: self.country_code, enter_date: self.enter_date, exit_date: self.exit_date }
+    }
+}
+
+/**
+ * @notice - A recorded proof, whose publicInputs can not be decoded into a stay
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct InvalidProof {
+    pub proof_hash: B256,
+    pub reason: String,
+}
+
+/**
+ * @notice - Query the travel status of a traveler: travelers(traveler), nullifiersByWalletAddresses(traveler),
+ *           and getPublicInputsOfTravelHistoryProof() of each proof of the traveler in the local proof store.
+ * @param visas - The visas that the traveler holds, which are passed to the stay-policy
+ */
+pub async fn query_travel_status<P: Provider<N>, N: Network>(
+    travel_history_manager: &TravelHistoryManagerInstance<P, N>,
+    proof_store: &ProofStore,
+    policy: &StayPolicy,
+    chain: &str,
+    traveler: Address,
+    visas: &[String],
+) -> eyre::Result<TravelStatus> {
+    let contract = *travel_history_manager.address();
+    let is_registered = travel_history_manager.travelers(traveler).call().await?;
+    let current_nullifier = travel_history_manager.nullifiersByWalletAddresses(traveler).call().await?;
+
+    let mut timeline = Vec::new();
+    let mut unrecorded = Vec::new();
+    let mut invalid = Vec::new();
+    for stored_proof in proof_store.proofs_of(traveler, Some(chain))? {
+        let Some(submission) = stored_proof.submissions_of(traveler).find(|submission| submission.chain == chain && submission.contract == contract) else {
+            continue;
+        };
+
+        // @dev - A relayed proof is recorded under the relayer wallet (= `msg.sender`), not the traveler
+        let public_input = travel_history_manager.getPublicInputsOfTravelHistoryProof(submission.sender(), stored_proof.proof.clone()).call().await?;
+        let public_inputs = TravelPublicInputs::try_from(public_input)
+            .map_err(|e| eyre::eyre!("Invalid publicInputs of the proof {}: {}", stored_proof.proof_hash, e))?;
+        if public_inputs.is_empty() {
+            unrecorded.push(stored_proof.proof_hash);
+            continue;
+        }
+
+        match TimelineEntry::from_public_inputs(&public_inputs, stored_proof.proof_hash, submission.tx_hash) {
+            Ok(entry) => timeline.push(entry),
+            Err(e) => invalid.push(InvalidProof { proof_hash: stored_proof.proof_hash, reason: e.to_string() }),
+        }
+    }
+    timeline.sort_by_key(|entry| (entry.enter_date, entry.exit_date));
+
+    let stays: Vec<Stay> = timeline.iter().map(TimelineEntry::stay).collect();
+    let verdict = policy.evaluate(&stays, visas);
+    Ok(TravelStatus {
+        chain: chain.to_string(),
+        contract,
+        traveler,
+        is_registered,
+        current_nullifier,
+        timeline,
+        unrecorded,
+        invalid,
+        breaching: verdict.is_breaching(),
+        verdict,
+    })
+}
+
+/**
+ * @dev - The human-readable table of the travel status. The stay numbers (#) are the indices which are referred by the verdict.
+ */
+impl fmt::Display for TravelStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Traveler: {:?} ({})", self.traveler, if self.is_registered { "registered" } else { "NOT registered" })?;
+        writeln!(f, "Chain: {} (TravelHistoryManager: {:?})", self.chain, self.contract)?;
+        writeln!(f, "Current nullifier: {}", self.current_nullifier)?;
+        writeln!(f)?;
+        writeln!(f, "{:<3} | {:<28} | {:<10} | {:<10} | {:>5} | Proof", "#", "Country", "Entry", "Exit", "Days")?;
+        writeln!(f, "{}", "-".repeat(3 + 28 + 10 + 10 + 5 + 66 + 15))?;
+        for (index, entry) in self.timeline.iter().enumerate() {
+            writeln!(
+                f,
+                "{:<3} | {:<28} | {:<10} | {:<10} | {:>5} | {}",
+                index,
+                entry.country,
+                format_day(entry.enter_date / SECONDS_PER_DAY),
+                format_day(entry.exit_date / SECONDS_PER_DAY),
+                entry.days,
+                entry.proof_hash
+            )?;
+        }
+        if self.timeline.is_empty() {
+            writeln!(f, "(no recorded stay in the local proof store)")?;
+        }
+        for proof_hash in self.unrecorded.iter() {
+            writeln!(f, "⚠️ The proof {} is in the local proof store, but not recorded on-chain", proof_hash)?;
+        }
+        for invalid in self.invalid.iter() {
+            writeln!(f, "⚠️ The proof {} is recorded on-chain, but its publicInputs are not a valid stay: {}", invalid.proof_hash, invalid.reason)?;
+        }
+        writeln!(f)?;
+        write!(f, "{}", self.verdict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::DEFAULT_STAY_POLICY_PATH;
+
+    fn public_inputs(country_code: u64, enter_date: u64, exit_date: u64) -> TravelPublicInputs {
+        TravelPublicInputs::new(FixedBytes::repeat_byte(1), country_code, enter_date, exit_date, FixedBytes::repeat_byte(2)).unwrap()
+    }
+
+    fn status(timeline: Vec<TimelineEntry>, invalid: Vec<InvalidProof>) -> TravelStatus {
+        let stays: Vec<Stay> = timeline.iter().map(TimelineEntry::stay).collect();
+        let verdict = StayPolicy::load(DEFAULT_STAY_POLICY_PATH).unwrap().evaluate(&stays, &[]);
+        TravelStatus {
+            chain: "base-mainnet".to_string(),
+            contract: Address::repeat_byte(0xcc),
+            traveler: Address::repeat_byte(0xa1),
+            is_registered: true,
+            current_nullifier: FixedBytes::repeat_byte(2),
+            timeline,
+            unrecorded: Vec::new(),
+            invalid,
+            breaching: verdict.is_breaching(),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn decodes_a_timeline_entry_from_the_public_inputs() {
+        let entry = TimelineEntry::from_public_inputs(&public_inputs(33, 0, 9 * SECONDS_PER_DAY), B256::repeat_byte(3), None).unwrap();
+        assert_eq!(entry.country, "33 (FR)");
+        assert_eq!(entry.days, 10);
+        assert_eq!(entry.nullifier, FixedBytes::repeat_byte(2));
+        assert_eq!(entry.stay(), Stay::new(33, 0, 9 * SECONDS_PER_DAY).unwrap());
+    }
+
+    #[test]
+    fn reports_a_proof_exiting_before_its_entry_as_invalid() {
+        let error = TimelineEntry::from_public_inputs(&public_inputs(33, SECONDS_PER_DAY, 0), B256::repeat_byte(4), None).unwrap_err();
+        let invalid = InvalidProof { proof_hash: B256::repeat_byte(4), reason: error.to_string() };
+
+        let valid = TimelineEntry::from_public_inputs(&public_inputs(33, 0, 9 * SECONDS_PER_DAY), B256::repeat_byte(3), None).unwrap();
+        let status = status(vec![valid], vec![invalid.clone()]);
+        assert!(!status.breaching);
+        assert_eq!(status.verdict.outcomes[0].days_used, 10);
+
+        let table = status.to_string();
+        assert!(table.contains(&format!("The proof {} is recorded on-chain, but its publicInputs are not a valid stay", invalid.proof_hash)), "{}", table);
+        assert!(table.contains("is before enter_date"), "{}", table);
+        assert_eq!(serde_json::to_value(&status).unwrap()["invalid"][0]["proof_hash"], invalid.proof_hash.to_string());
+    }
+}
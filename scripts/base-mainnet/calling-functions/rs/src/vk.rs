@@ -0,0 +1,504 @@
+// @dev - Alloy
+use alloy::{
+    network::Network,
+    primitives::{keccak256, Address, B256, U256},
+    providers::Provider,
+};
+
+use crate::artifact::ContractArtifact;
+use crate::contracts::{TravelHistoryManager::TravelHistoryManagerInstance, TravelHistoryProofVerifier};
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::Path;
+
+// @notice - The fingerprint of a verification key (vk), which is used to check that a proof is made with the same vk as the deployed verifier.
+// @dev - A vk of UltraHonk consists of a few header values (i.e. the circuit size) and the commitments (= G1 points) of the circuit.
+//        A vk of UltraPlonk (= older versions of bb) consists of a binary header and the named commitments (i.e. "Q_1", "ID_1").
+//        The generated HonkVerifier (= plonk_vk.sol) embeds the same commitments as `uint256` constants, so they can be extracted from its bytecode.
+// @dev - The fingerprint is keccak256 over the commitments (x || y), sorted, so it does not depend on the layout of the vk file nor the order of the constants in the bytecode.
+
+/// @dev - The default path of the vk of the travel_history circuit (= the output of `bb write_vk` in circuits/build.sh), relative to the root directory (= /rs)
+pub const DEFAULT_VK_PATH: &str = "../../../../circuits/target/vk";
+
+/// @dev - The modulus of the BN254 base field (= the field of the coordinates of a G1 point)
+pub const BN254_BASE_FIELD_MODULUS: U256 = U256::from_limbs([
+    0x3c208c16d87cfd47,
+    0x97816a916871ca8d,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+
+/// @dev - Newer versions of bb serialize a coordinate as 2 limbs (lo: 136 bits, hi: the rest)
+const LIMB_BITS: usize = 136;
+
+/// @dev - A coordinate below this value is considered as a header value (i.e. the circuit size), not as a part of a commitment
+const MIN_COORDINATE: U256 = U256::from_limbs([0, 1, 0, 0]); // @dev - 2^64
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct G1Point {
+    pub x: U256,
+    pub y: U256,
+}
+
+impl G1Point {
+    /**
+     * @dev - Whether or not (x, y) is on the BN254 curve (= y^2 = x^3 + 3), excluding the small points (i.e. the generator (1, 2))
+     */
+    pub fn is_commitment(x: U256, y: U256) -> bool {
+        let q = BN254_BASE_FIELD_MODULUS;
+        if x >= q || y >= q || x < MIN_COORDINATE {
+            return false;
+        }
+        let x3 = x.mul_mod(x, q).mul_mod(x, q);
+        y.mul_mod(y, q) == x3.add_mod(U256::from(3), q)
+    }
+}
+
+impl fmt::Display for G1Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:#x}, {:#x})", self.x, self.y)
+    }
+}
+
+/**
+ * @notice - The commitments of a vk, which are found in a vk file, a verifier's bytecode or a Verifier.sol
+ */
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VkFingerprint {
+    pub commitments: BTreeSet<G1Point>,
+}
+
+impl VkFingerprint {
+    /**
+     * @dev - Load the vk which is generated by `bb write_vk` (= a sequence of 32-byte big-endian values, or the binary format of UltraPlonk)
+     */
+    pub fn load_vk<P: AsRef<Path>>(path: P) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let vk = std::fs::read(path).map_err(|e| eyre::eyre!("Failed to read the vk {}: {}", path.display(), e))?;
+        Self::from_vk_bytes(&vk).map_err(|e| eyre::eyre!("{}: {}", path.display(), e))
+    }
+
+    /**
+     * @dev - Every commitment of the vk must be found (= the vk is parsed strictly, not searched for G1 points), so that the comparison w/ a verifier covers the whole vk
+     */
+    pub fn from_vk_bytes(vk: &[u8]) -> eyre::Result<Self> {
+        if let Some(commitments) = ultra_plonk_commitments(vk) {
+            return non_empty(commitments?, "vk");
+        }
+        if !vk.len().is_multiple_of(32) {
+            return Err(eyre::eyre!("The vk is neither a sequence of 32-byte values ({} bytes) nor a vk of UltraPlonk", vk.len()));
+        }
+        let words: Vec<U256> = vk.chunks_exact(32).map(U256::from_be_slice).collect();
+        non_empty(ultra_honk_commitments(&words)?, "vk")
+    }
+
+    /**
+     * @dev - Extract the commitments, which are pushed as constants (PUSH17 - PUSH32), from the runtime bytecode of a HonkVerifier
+     */
+    pub fn from_bytecode(bytecode: &[u8]) -> eyre::Result<Self> {
+        non_empty(find_commitments(&push_constants(bytecode)), "bytecode")
+    }
+
+    pub fn from_artifact(artifact: &ContractArtifact) -> eyre::Result<Self> {
+        Self::from_bytecode(&artifact.deployed_bytecode).map_err(|e| eyre::eyre!("{}: {}", artifact.path.display(), e))
+    }
+
+    /**
+     * @dev - Extract the commitments from the source of a generated verifier (= circuits/target/Verifier.sol or contracts/circuit/ultra-verifier/plonk_vk.sol)
+     */
+    pub fn from_solidity_source(source: &str) -> eyre::Result<Self> {
+        let constants: Vec<U256> = source
+            .split("0x")
+            .skip(1)
+            .filter_map(|s| {
+                let hex: String = s.chars().take_while(char::is_ascii_hexdigit).collect();
+                (hex.len() > 32 && hex.len() <= 64).then(|| U256::from_str_radix(&hex, 16).ok()).flatten()
+            })
+            .collect();
+        non_empty(find_commitments(&constants), "Solidity source")
+    }
+
+    /**
+     * @notice - The fingerprint (= keccak256 of the sorted commitments)
+     */
+    pub fn hash(&self) -> B256 {
+        let mut preimage = Vec::with_capacity(self.commitments.len() * 64);
+        for commitment in self.commitments.iter() {
+            preimage.extend_from_slice(&commitment.x.to_be_bytes::<32>());
+            preimage.extend_from_slice(&commitment.y.to_be_bytes::<32>());
+        }
+        keccak256(preimage)
+    }
+
+    /**
+     * @notice - Compare the vk of a circuit build (= self) with the constants embedded in a verifier
+     * @dev - The verifier matches if it embeds every commitment of the vk (= `missing` is empty), which is parsed strictly by `from_vk_bytes()`.
+     *        Its fingerprint may still differ from the one of the vk, if it embeds other G1 points as well.
+     */
+    pub fn compare(&self, verifier: &VkFingerprint) -> VkComparison {
+        let missing: Vec<G1Point> = self.commitments.difference(&verifier.commitments).copied().collect();
+        VkComparison { vk_fingerprint: self.hash(), verifier_fingerprint: verifier.hash(), commitments: self.commitments.len(), missing }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VkComparison {
+    pub vk_fingerprint: B256,
+    pub verifier_fingerprint: B256,
+    pub commitments: usize,
+    pub missing: Vec<G1Point>, // @dev - The commitments of the vk, which are not embedded in the verifier
+}
+
+impl VkComparison {
+    pub fn is_match(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    pub fn ensure_match(&self) -> eyre::Result<()> {
+        if !self.is_match() {
+            return Err(eyre::eyre!(
+                "The vk fingerprint does not match the deployed verifier: {} (vk) != {} (verifier), {} of {} commitments are missing",
+                self.vk_fingerprint,
+                self.verifier_fingerprint,
+                self.missing.len(),
+                self.commitments
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for VkComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_match() {
+            write!(f, "match ({})", self.vk_fingerprint)
+        } else {
+            write!(
+                f,
+                "mismatch ({} (vk) != {} (verifier), {}/{} commitments missing)",
+                self.vk_fingerprint,
+                self.verifier_fingerprint,
+                self.missing.len(),
+                self.commitments
+            )
+        }
+    }
+}
+
+/**
+ * @notice - The vk fingerprint of the HonkVerifier behind the TravelHistoryManager (= travelHistoryProofVerifier().verifier())
+ */
+pub async fn deployed_verifier_fingerprint<P: Provider<N>, N: Network>(
+    travel_history_manager: &TravelHistoryManagerInstance<P, N>,
+) -> eyre::Result<(Address, VkFingerprint)> {
+    let travel_history_proof_verifier_address = travel_history_manager.travelHistoryProofVerifier().call().await?;
+    let travel_history_proof_verifier =
+        TravelHistoryProofVerifier::new(travel_history_proof_verifier_address, travel_history_manager.provider());
+    let honk_verifier = travel_history_proof_verifier.verifier().call().await?;
+
+    let bytecode = travel_history_manager.provider().get_code_at(honk_verifier).await?;
+    let fingerprint = VkFingerprint::from_bytecode(&bytecode)
+        .map_err(|e| eyre::eyre!("HonkVerifier at {:?}: {}", honk_verifier, e))?;
+    Ok((honk_verifier, fingerprint))
+}
+
+/**
+ * @dev - Find the G1 points (= 2 consecutive values (x, y)) in the constants of a verifier, which may embed other values in between
+ */
+fn find_commitments(values: &[U256]) -> BTreeSet<G1Point> {
+    let mut commitments = BTreeSet::new();
+    let mut i = 0;
+    while i + 1 < values.len() {
+        if G1Point::is_commitment(values[i], values[i + 1]) {
+            commitments.insert(G1Point { x: values[i], y: values[i + 1] });
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+    commitments
+}
+
+/**
+ * @dev - The commitments of a vk of UltraHonk: the header values (< 2^64) are followed by the commitments, as (x, y) or as (x_lo, x_hi, y_lo, y_hi).
+ *        Every value after the header must be a part of a commitment (or a point at infinity = 0), otherwise the vk is rejected.
+ */
+fn ultra_honk_commitments(words: &[U256]) -> eyre::Result<BTreeSet<G1Point>> {
+    let mut commitments = BTreeSet::new();
+    let mut i = words.iter().take_while(|word| **word < MIN_COORDINATE).count();
+    while i < words.len() {
+        if i + 1 < words.len() && G1Point::is_commitment(words[i], words[i + 1]) {
+            commitments.insert(G1Point { x: words[i], y: words[i + 1] });
+            i += 2;
+        } else if let Some(commitment) = words.get(i..i + 4).and_then(|limbs| {
+            let (x, y) = (join_limbs(limbs[0], limbs[1])?, join_limbs(limbs[2], limbs[3])?);
+            G1Point::is_commitment(x, y).then_some(G1Point { x, y })
+        }) {
+            commitments.insert(commitment);
+            i += 4;
+        } else if words[i].is_zero() {
+            i += 1;
+        } else {
+            return Err(eyre::eyre!("The value #{} of the vk ({:#x}) is not a part of a commitment (= G1 point)", i, words[i]));
+        }
+    }
+    Ok(commitments)
+}
+
+/**
+ * @dev - The commitments of a vk of UltraPlonk: circuit_type (u32), circuit_size (u32), num_public_inputs (u32), the number of commitments (u32),
+ *        and each commitment as the length of its name (u32), its name and (x, y). The rest (= the recursive proof settings) is ignored.
+ * @dev - None if the vk is not in this format, and an error if one of its commitments is not a G1 point.
+ */
+fn ultra_plonk_commitments(vk: &[u8]) -> Option<eyre::Result<BTreeSet<G1Point>>> {
+    const ULTRA_PLONK_CIRCUIT_TYPE: u32 = 2;
+    let read_u32 = |offset: usize| vk.get(offset..offset + 4).map(|bytes| u32::from_be_bytes(bytes.try_into().unwrap()));
+
+    if read_u32(0)? != ULTRA_PLONK_CIRCUIT_TYPE {
+        return None;
+    }
+    let count = read_u32(12)? as usize;
+    let mut offset = 16;
+    let mut points = Vec::with_capacity(count);
+    for _ in 0..count {
+        let name_length = read_u32(offset)? as usize;
+        let name = vk.get(offset + 4..offset + 4 + name_length).filter(|name| name.iter().all(u8::is_ascii_graphic))?;
+        let point = vk.get(offset + 4 + name_length..offset + 4 + name_length + 64)?;
+        points.push((String::from_utf8_lossy(name).to_string(), U256::from_be_slice(&point[..32]), U256::from_be_slice(&point[32..])));
+        offset += 4 + name_length + 64;
+    }
+
+    Some(
+        points
+            .into_iter()
+            .map(|(name, x, y)| {
+                G1Point::is_commitment(x, y)
+                    .then_some(G1Point { x, y })
+                    .ok_or_else(|| eyre::eyre!("The commitment {} of the vk is not a G1 point", name))
+            })
+            .collect(),
+    )
+}
+
+fn join_limbs(lo: U256, hi: U256) -> Option<U256> {
+    (lo.bit_len() <= LIMB_BITS && hi.bit_len() <= 256 - LIMB_BITS).then(|| lo | (hi << LIMB_BITS))
+}
+
+/**
+ * @dev - The constants pushed by PUSH17 - PUSH32 in a bytecode (= the values which can be a coordinate), in order
+ */
+fn push_constants(bytecode: &[u8]) -> Vec<U256> {
+    const PUSH1: u8 = 0x60;
+    const PUSH17: u8 = 0x70;
+    const PUSH32: u8 = 0x7f;
+
+    let mut constants = Vec::new();
+    let mut pc = 0;
+    while pc < bytecode.len() {
+        let opcode = bytecode[pc];
+        if (PUSH1..=PUSH32).contains(&opcode) {
+            let length = (opcode - PUSH1 + 1) as usize;
+            let end = (pc + 1 + length).min(bytecode.len());
+            if opcode >= PUSH17 {
+                constants.push(U256::from_be_slice(&bytecode[pc + 1..end]));
+            }
+            pc = end;
+        } else {
+            pc += 1;
+        }
+    }
+    constants
+}
+
+fn non_empty(commitments: BTreeSet<G1Point>, source: &str) -> eyre::Result<VkFingerprint> {
+    if commitments.is_empty() {
+        return Err(eyre::eyre!("No vk commitment (= G1 point) is found in the {}", source));
+    }
+    Ok(VkFingerprint { commitments })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::b256;
+
+    /**
+     * @dev - Find `count` points on the curve, starting from x = q / 2 (= whose limbs are not small like the header values), as y = sqrt(x^3 + 3) (q = 3 mod 4)
+     */
+    fn points(count: usize) -> Vec<G1Point> {
+        let q = BN254_BASE_FIELD_MODULUS;
+        let exponent = (q + U256::from(1)) >> 2;
+        let mut points = Vec::new();
+        let mut x: U256 = q >> 1;
+        while points.len() < count {
+            let rhs = x.mul_mod(x, q).mul_mod(x, q).add_mod(U256::from(3), q);
+            let y = rhs.pow_mod(exponent, q);
+            if y.mul_mod(y, q) == rhs {
+                points.push(G1Point { x, y });
+            }
+            x += U256::from(1);
+        }
+        points
+    }
+
+    fn vk_bytes(points: &[G1Point], limbs: bool) -> Vec<u8> {
+        // @dev - The header: log circuit size, number of public inputs, public inputs offset
+        let mut vk: Vec<u8> = [12u64, 5, 1].iter().flat_map(|v| U256::from(*v).to_be_bytes::<32>()).collect();
+        for point in points {
+            for coordinate in [point.x, point.y] {
+                if limbs {
+                    let lo = coordinate & ((U256::from(1) << LIMB_BITS) - U256::from(1));
+                    vk.extend_from_slice(&lo.to_be_bytes::<32>());
+                    vk.extend_from_slice(&(coordinate >> LIMB_BITS).to_be_bytes::<32>());
+                } else {
+                    vk.extend_from_slice(&coordinate.to_be_bytes::<32>());
+                }
+            }
+        }
+        vk
+    }
+
+    fn bytecode(points: &[G1Point]) -> Vec<u8> {
+        // @dev - PUSH32 x, PUSH1 0x40, MSTORE, PUSH32 y, ... with a PUSH32 of the scalar field modulus in between
+        let mut bytecode = vec![0x7f];
+        bytecode.extend_from_slice(&crate::field::BN254_MODULUS.to_be_bytes::<32>());
+        for point in points.iter().rev() {
+            for coordinate in [point.x, point.y] {
+                bytecode.push(0x7f);
+                bytecode.extend_from_slice(&coordinate.to_be_bytes::<32>());
+                bytecode.extend_from_slice(&[0x60, 0x40, 0x52]);
+            }
+        }
+        bytecode
+    }
+
+    #[test]
+    fn finds_the_commitments_of_a_vk() {
+        let points = points(4);
+        for limbs in [false, true] {
+            let fingerprint = VkFingerprint::from_vk_bytes(&vk_bytes(&points, limbs)).unwrap();
+            assert_eq!(fingerprint.commitments, points.iter().copied().collect());
+        }
+    }
+
+    #[test]
+    fn matches_a_verifier_embedding_the_same_vk() {
+        let points = points(4);
+        let vk = VkFingerprint::from_vk_bytes(&vk_bytes(&points, false)).unwrap();
+        let verifier = VkFingerprint::from_bytecode(&bytecode(&points)).unwrap();
+        let comparison = vk.compare(&verifier);
+        assert!(comparison.is_match());
+        assert_eq!(comparison.vk_fingerprint, comparison.verifier_fingerprint);
+        assert!(comparison.ensure_match().is_ok());
+    }
+
+    #[test]
+    fn reports_the_own_fingerprint_of_a_verifier_embedding_other_points() {
+        let points = points(5);
+        let vk = VkFingerprint::from_vk_bytes(&vk_bytes(&points[..4], false)).unwrap();
+        let verifier = VkFingerprint::from_bytecode(&bytecode(&points)).unwrap();
+        let comparison = vk.compare(&verifier);
+        assert!(comparison.is_match());
+        assert_eq!(comparison.verifier_fingerprint, verifier.hash());
+        assert_ne!(comparison.verifier_fingerprint, comparison.vk_fingerprint);
+    }
+
+    #[test]
+    fn rejects_a_verifier_embedding_another_vk() {
+        let points = points(5);
+        let vk = VkFingerprint::from_vk_bytes(&vk_bytes(&points[..4], false)).unwrap();
+        let verifier = VkFingerprint::from_bytecode(&bytecode(&points[1..])).unwrap();
+        let comparison = vk.compare(&verifier);
+        assert!(!comparison.is_match());
+        assert_eq!(comparison.missing, vec![points[0]]);
+        assert!(comparison.ensure_match().is_err());
+    }
+
+    #[test]
+    fn finds_the_commitments_of_a_solidity_verifier() {
+        let points = points(2);
+        let source = points
+            .iter()
+            .map(|point| format!("Honk.G1Point({{ x: uint256({:#066x}), y: uint256({:#066x}) }}),", point.x, point.y))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let fingerprint = VkFingerprint::from_solidity_source(&source).unwrap();
+        assert_eq!(fingerprint.commitments, points.iter().copied().collect());
+    }
+
+    #[test]
+    fn skips_the_push_data_of_a_bytecode() {
+        // @dev - A PUSH2 whose data is 0x7f (= PUSH32) must not be read as an opcode
+        let points = points(1);
+        let mut code = vec![0x61, 0x7f, 0x7f];
+        code.extend_from_slice(&bytecode(&points));
+        assert_eq!(VkFingerprint::from_bytecode(&code).unwrap().commitments.len(), 1);
+        assert!(VkFingerprint::from_bytecode(&[0x60, 0x01]).is_err());
+    }
+
+    /// @dev - A real vk (= `bb write_vk`, UltraPlonk) and the verifier generated from it (= `bb contract`), which were committed w/ the old circuit
+    const REAL_VK_PATH: &str = "../../../../old/circuits/circuit-for-country/target/vk";
+    const REAL_VERIFIER_PATH: &str = "../../../../old/circuits/circuit-for-country/target/contract.sol";
+    const REAL_VK_FINGERPRINT: B256 = b256!("934df3a1c0d167d7d1a0757bd410fffa0212a263ffad8e595d4a47633ee36240");
+
+    /**
+     * @dev - The vk constants of the real verifier (= `mstore(add(_vk, <offset>), <constant>)` in `loadVerificationKey()`), lowered like solc does:
+     *        PUSH32 <constant>, PUSH2 <offset>, DUP3 (= _vk), ADD, MSTORE
+     */
+    fn real_verifier_bytecode() -> Vec<u8> {
+        let source = std::fs::read_to_string(REAL_VERIFIER_PATH).unwrap();
+        let mut bytecode = Vec::new();
+        for line in source.lines().filter_map(|line| line.trim().strip_prefix("mstore(add(_vk, ")) {
+            let (offset, rest) = line.split_once("), ").unwrap();
+            let constant = U256::from_str_radix(rest.split(')').next().unwrap().trim_start_matches("0x"), 16).unwrap();
+            let offset = u16::from_str_radix(offset.trim_start_matches("0x"), 16).unwrap();
+            bytecode.push(0x7f);
+            bytecode.extend_from_slice(&constant.to_be_bytes::<32>());
+            bytecode.push(0x61);
+            bytecode.extend_from_slice(&offset.to_be_bytes());
+            bytecode.extend_from_slice(&[0x82, 0x01, 0x52]);
+        }
+        bytecode
+    }
+
+    #[test]
+    fn matches_a_real_vk_with_its_verifier() {
+        let vk = VkFingerprint::load_vk(REAL_VK_PATH).unwrap();
+        assert_eq!(vk.commitments.len(), 23);
+        assert_eq!(vk.hash(), REAL_VK_FINGERPRINT);
+
+        let source = VkFingerprint::from_solidity_source(&std::fs::read_to_string(REAL_VERIFIER_PATH).unwrap()).unwrap();
+        let bytecode = VkFingerprint::from_bytecode(&real_verifier_bytecode()).unwrap();
+        for verifier in [source, bytecode] {
+            let comparison = vk.compare(&verifier);
+            assert!(comparison.is_match(), "{}", comparison);
+            assert_eq!(comparison.verifier_fingerprint, REAL_VK_FINGERPRINT);
+        }
+    }
+
+    #[test]
+    fn rejects_a_real_verifier_missing_a_commitment_of_the_vk() {
+        let vk = VkFingerprint::load_vk(REAL_VK_PATH).unwrap();
+        let mut verifier = VkFingerprint::from_bytecode(&real_verifier_bytecode()).unwrap();
+        let removed = *verifier.commitments.iter().next().unwrap();
+        verifier.commitments.remove(&removed);
+
+        let comparison = vk.compare(&verifier);
+        assert_eq!(comparison.missing, vec![removed]);
+        assert!(comparison.ensure_match().unwrap_err().to_string().contains("1 of 23 commitments are missing"));
+    }
+
+    #[test]
+    fn rejects_a_vk_with_a_value_outside_of_the_commitments() {
+        let points = points(2);
+        let mut vk = vk_bytes(&points, false);
+        vk.extend_from_slice(&(U256::from(1) << 200usize).to_be_bytes::<32>());
+        assert!(VkFingerprint::from_vk_bytes(&vk).unwrap_err().to_string().contains("is not a part of a commitment"));
+
+        // @dev - A commitment of a real vk, which is not a G1 point
+        let mut real_vk = std::fs::read(REAL_VK_PATH).unwrap();
+        real_vk[16 + 4 + 4 + 63] ^= 1;
+        assert!(VkFingerprint::from_vk_bytes(&real_vk).unwrap_err().to_string().contains("ID_1"));
+    }
+}